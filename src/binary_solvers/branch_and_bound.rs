@@ -0,0 +1,190 @@
+use crate::item::Item;
+use crate::knapsack::BinaryProblemKnapsacks;
+use crate::problem_type::{BinaryProblem, BinarySolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::Instant;
+
+struct ItemInfo {
+    pub j: usize, //index
+    pub p: f64,   //profit
+    pub w: f64,   //weight
+}
+
+//Dantzig bound on items j.. with capacity c, using prefix sums of the sorted items
+fn upper_bound(items: &[ItemInfo], prefix_w: &[f64], prefix_p: &[f64], j: usize, c: f64) -> f64 {
+    let n = items.len();
+    let s = j + prefix_w[j..=n].partition_point(|w| *w - prefix_w[j] <= c) - 1;
+    let bound = prefix_p[s] - prefix_p[j];
+    if s == n {
+        return bound;
+    }
+
+    bound + (c - (prefix_w[s] - prefix_w[j])) * items[s].p / items[s].w
+}
+
+//returns whether each item is in the optimal solution, or in the best solution found if the
+//control stops the search, along with whether it did
+fn branch_and_bound(
+    profits: &[f64],
+    weights: &[f64],
+    c: f64,
+    control: &SolveControl,
+) -> (Vec<bool>, bool) {
+    let mut solution = vec![false; profits.len()];
+
+    //sort items that may be part of a solution by decreasing efficiency
+    let mut items: Vec<ItemInfo> = Vec::with_capacity(profits.len());
+    for j in 0..profits.len() {
+        if profits[j] > 0.0 && weights[j] <= c {
+            items.push(ItemInfo {
+                j: j,
+                p: profits[j],
+                w: weights[j],
+            });
+        }
+    }
+
+    items.sort_by(|x, y| (y.p * x.w).partial_cmp(&(x.p * y.w)).unwrap());
+    let n = items.len();
+    let mut prefix_w = vec![0.0; n + 1];
+    let mut prefix_p = vec![0.0; n + 1];
+    for j in 0..n {
+        prefix_w[j + 1] = prefix_w[j] + items[j].w;
+        prefix_p[j + 1] = prefix_p[j] + items[j].p;
+    }
+
+    //depth first search of Horowitz and Sahni. forward moves insert as many consecutive
+    //items as possible, backtracking removes the last inserted item.
+    let mut x = vec![false; n];
+    let mut best_x = vec![false; n];
+    let mut z = 0.0; //incumbent value
+    let mut v = 0.0; //current value
+    let mut c_rem = c;
+    let mut j = 0;
+    let mut nodes = 0;
+    let mut stopped = false;
+    loop {
+        nodes += 1;
+        if control.should_stop(nodes) {
+            stopped = true;
+            break;
+        }
+
+        if v + upper_bound(&items, &prefix_w, &prefix_p, j, c_rem) > z {
+            if j == n {
+                z = v;
+                best_x.copy_from_slice(&x);
+            } else {
+                x[j] = items[j].w <= c_rem;
+                if x[j] {
+                    c_rem -= items[j].w;
+                    v += items[j].p;
+                }
+
+                j += 1;
+                continue;
+            }
+        }
+
+        //backtrack to the last inserted item and exclude it
+        match x[..j].iter().rposition(|x_k| *x_k) {
+            Some(k) => {
+                x[k] = false;
+                c_rem += items[k].w;
+                v -= items[k].p;
+                j = k + 1;
+            }
+
+            None => break,
+        }
+    }
+
+    for (k, item) in items.iter().enumerate() {
+        solution[item.j] = best_x[k];
+    }
+
+    (solution, stopped)
+}
+
+macro_rules! branch_and_bound_impl {
+    ( $( $type:ty ),* ) => {
+        $(
+impl BinarySolver<$type, 1> for BranchAndBound {
+    type Output = BinaryProblemKnapsacks<$type, 1>;
+
+    fn solve(self, problem: BinaryProblem<$type, 1>) -> BinaryProblemKnapsacks<$type, 1> {
+        self.solve_with(problem, &SolveControl::default())
+    }
+
+    fn solve_with(
+        self,
+        problem: BinaryProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> BinaryProblemKnapsacks<$type, 1> {
+        self.solve_stats_with(problem, control).0
+    }
+
+    fn solve_stats_with(
+        self,
+        mut problem: BinaryProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> (BinaryProblemKnapsacks<$type, 1>, SolveStats) {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let items = problem.items;
+        let knapsack = &mut problem.knapsacks[0];
+
+        let mut profits: Vec<f64> = Vec::with_capacity(items.len());
+        let mut weights: Vec<f64> = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            profits.push(item.value);
+            weights.push(item.weights[0] as f64);
+        }
+
+        let c = if knapsack.capacity[0] > knapsack.weights()[0] {
+            (knapsack.capacity[0] - knapsack.weights()[0]) as f64
+        } else {
+            0.0
+        };
+
+        let (solution, stopped) = branch_and_bound(&profits, &weights, c, control);
+        for (j, x) in solution.iter().enumerate() {
+            if *x {
+                knapsack.add(Item::<$type, 1> {
+                    value: items[j].value,
+                    weights: items[j].weights,
+                    quantity: 1 as $type,
+                    id: (),
+                });
+            }
+        }
+
+        if stopped {
+            stats.status = control.stopped_status();
+        } else if problem.knapsacks.len() <= 1 {
+            //only the first knapsack is filled
+            stats.status = Status::Optimal;
+        }
+
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+        (problem.knapsacks, stats)
+    }
+}
+        )*
+    }
+}
+
+//exact depth first branch-and-bound for single knapsacks with one dimension,
+//pruned using the Dantzig (LP relaxation) bound.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BranchAndBound;
+branch_and_bound_impl!(u32, f64);
+
+impl SolverInfo for BranchAndBound {
+    fn name(&self) -> &'static str {
+        "Branch and Bound"
+    }
+}
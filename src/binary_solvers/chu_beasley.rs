@@ -0,0 +1,399 @@
+use crate::error::KpError;
+use crate::item::Item;
+use crate::knapsack::BinaryProblemKnapsacks;
+use crate::observer::Observer;
+use crate::problem_type::{BinaryProblem, BinarySolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use minilp::{ComparisonOp, OptimizationDirection, Problem};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::time::Instant;
+
+//news of the search for the observer of the solve
+enum Report<'a> {
+    Bound(f64),
+    Incumbent(&'a [bool]),
+    Progress(usize),
+}
+
+struct Individual {
+    pub x: Vec<bool>, //whether each item is packed
+    pub fitness: f64,
+}
+
+struct Population<'a, const S: usize> {
+    profits: &'a [f64],
+    weights: &'a [[f64; S]],
+    c: [f64; S],
+    order: Vec<usize>, //items by decreasing pseudo-utility
+}
+
+impl<'a, const S: usize> Population<'a, S> {
+    //drop the least useful items until the solution is feasible, then add the most useful
+    //items that still fit
+    fn repair(&self, mut x: Vec<bool>) -> Individual {
+        let mut load = [0.0; S];
+        for j in 0..x.len() {
+            x[j] &= self.profits[j] > 0.0;
+            if x[j] {
+                for r in 0..S {
+                    load[r] += self.weights[j][r];
+                }
+            }
+        }
+
+        for j in self.order.iter().rev() {
+            if (0..S).all(|r| load[r] <= self.c[r]) {
+                break;
+            }
+
+            if x[*j] {
+                x[*j] = false;
+                for r in 0..S {
+                    load[r] -= self.weights[*j][r];
+                }
+            }
+        }
+
+        for j in self.order.iter() {
+            if !x[*j]
+                && self.profits[*j] > 0.0
+                && (0..S).all(|r| load[r] + self.weights[*j][r] <= self.c[r])
+            {
+                x[*j] = true;
+                for r in 0..S {
+                    load[r] += self.weights[*j][r];
+                }
+            }
+        }
+
+        let mut fitness = 0.0;
+        for j in 0..x.len() {
+            if x[j] {
+                fitness += self.profits[j];
+            }
+        }
+
+        Individual {
+            x: x,
+            fitness: fitness,
+        }
+    }
+}
+
+//pseudo-utility of each item: its profit over its weights scaled by the optimal surrogate
+//multipliers, which are the dual values of the linear relaxation. also returns the value of
+//the linear relaxation
+fn pseudo_utilities<const S: usize>(
+    profits: &[f64],
+    weights: &[[f64; S]],
+    c: &[f64; S],
+) -> Result<(Vec<f64>, f64), KpError> {
+    let mut dual_problem = Problem::new(OptimizationDirection::Minimize);
+    let mut variables: Vec<minilp::Variable> = Vec::with_capacity(S + profits.len());
+    for r in 0..S {
+        variables.push(dual_problem.add_var(c[r], (0.0, f64::INFINITY)));
+    }
+
+    for _ in 0..profits.len() {
+        variables.push(dual_problem.add_var(1.0, (0.0, f64::INFINITY)));
+    }
+
+    for j in 0..profits.len() {
+        let mut formula: Vec<(minilp::Variable, f64)> = Vec::with_capacity(S + 1);
+        for r in 0..S {
+            formula.push((variables[r], weights[j][r]));
+        }
+
+        formula.push((variables[S + j], 1.0));
+        dual_problem.add_constraint(&formula, ComparisonOp::Ge, profits[j]);
+    }
+
+    let solution = dual_problem
+        .solve()
+        .map_err(|x| KpError::Backend(x.to_string()))?;
+    let mut utilities: Vec<f64> = Vec::with_capacity(profits.len());
+    for j in 0..profits.len() {
+        let mut weight = 0.0;
+        for r in 0..S {
+            weight += solution[variables[r]] * weights[j][r];
+        }
+
+        utilities.push(if weight > 0.0 {
+            profits[j] / weight
+        } else {
+            f64::INFINITY
+        });
+    }
+
+    Ok((utilities, solution.objective()))
+}
+
+//index of the fitter of two random individuals
+fn tournament(rng: &mut StdRng, individuals: &[Individual]) -> usize {
+    let a = rng.gen_range(0..individuals.len());
+    let b = rng.gen_range(0..individuals.len());
+    if individuals[a].fitness >= individuals[b].fitness {
+        a
+    } else {
+        b
+    }
+}
+
+//returns whether each item is in the best solution found, and whether the control stopped
+//the search before every generation was bred
+fn chu_beasley<const S: usize>(
+    profits: &[f64],
+    weights: &[[f64; S]],
+    c: &[f64; S],
+    solver: &ChuBeasley,
+    control: &SolveControl,
+    report: &mut dyn FnMut(Report),
+) -> Result<(Vec<bool>, Status), KpError> {
+    let n = profits.len();
+    if n == 0 {
+        return Ok((Vec::new(), Status::Feasible));
+    }
+
+    let (utilities, bound) = pseudo_utilities(profits, weights, c)?;
+    report(Report::Bound(bound));
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|x, y| utilities[*y].partial_cmp(&utilities[*x]).unwrap());
+    let population = Population {
+        profits: profits,
+        weights: weights,
+        c: *c,
+        order: order,
+    };
+
+    //initial population packs items in random order while they fit
+    let mut rng = StdRng::seed_from_u64(solver.seed);
+    let mut individuals: Vec<Individual> = Vec::with_capacity(solver.population_size.max(1));
+    let mut shuffled: Vec<usize> = (0..n).collect();
+    for _ in 0..solver.population_size.max(1) {
+        shuffled.shuffle(&mut rng);
+        let mut x = vec![false; n];
+        let mut load = [0.0; S];
+        for j in shuffled.iter() {
+            if (0..S).all(|r| load[r] + weights[*j][r] <= c[r]) {
+                x[*j] = true;
+                for r in 0..S {
+                    load[r] += weights[*j][r];
+                }
+            }
+        }
+
+        individuals.push(population.repair(x));
+    }
+
+    let fittest = |individuals: &[Individual]| {
+        let mut best = 0;
+        for (k, individual) in individuals.iter().enumerate() {
+            if individual.fitness > individuals[best].fitness {
+                best = k;
+            }
+        }
+
+        best
+    };
+
+    let best = fittest(&individuals);
+    let mut best_fitness = individuals[best].fitness;
+    report(Report::Incumbent(&individuals[best].x));
+
+    //each generation breeds one child from two binary tournaments by uniform crossover and
+    //mutation, which replaces the worst individual unless it is already in the population
+    let mut status = Status::Feasible;
+    for generation in 0..solver.generations {
+        if control.should_stop(generation) {
+            status = control.stopped_status();
+            break;
+        }
+
+        report(Report::Progress(generation));
+
+        let a = tournament(&mut rng, &individuals);
+        let b = tournament(&mut rng, &individuals);
+        let mut x: Vec<bool> = (0..n)
+            .map(|j| {
+                if rng.gen_bool(0.5) {
+                    individuals[a].x[j]
+                } else {
+                    individuals[b].x[j]
+                }
+            })
+            .collect();
+
+        for _ in 0..2 {
+            let j = rng.gen_range(0..n);
+            x[j] = !x[j];
+        }
+
+        let child = population.repair(x);
+        if individuals.iter().any(|x| x.x == child.x) {
+            continue;
+        }
+
+        let mut worst = 0;
+        for (k, individual) in individuals.iter().enumerate() {
+            if individual.fitness < individuals[worst].fitness {
+                worst = k;
+            }
+        }
+
+        if child.fitness > best_fitness {
+            best_fitness = child.fitness;
+            report(Report::Incumbent(&child.x));
+        }
+
+        individuals[worst] = child;
+    }
+
+    let best = fittest(&individuals);
+    Ok((individuals.swap_remove(best).x, status))
+}
+
+impl<const S: usize> BinarySolver<f64, S> for ChuBeasley {
+    type Output = BinaryProblemKnapsacks<f64, S>;
+
+    fn solve(self, problem: BinaryProblem<f64, S>) -> BinaryProblemKnapsacks<f64, S> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_with(
+        self,
+        problem: BinaryProblem<f64, S>,
+        control: &SolveControl,
+    ) -> BinaryProblemKnapsacks<f64, S> {
+        self.try_solve_with(problem, control).unwrap()
+    }
+
+    fn solve_observed(
+        self,
+        problem: BinaryProblem<f64, S>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<BinaryProblemKnapsacks<f64, S>>,
+    ) -> BinaryProblemKnapsacks<f64, S> {
+        self.try_solve_observed(problem, control, observer, &mut SolveStats::default())
+            .unwrap()
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BinaryProblem<f64, S>,
+        control: &SolveControl,
+    ) -> (BinaryProblemKnapsacks<f64, S>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BinaryProblem<f64, S>,
+        control: &SolveControl,
+    ) -> Result<(BinaryProblemKnapsacks<f64, S>, SolveStats), KpError> {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let knapsacks = self.try_solve_observed(problem, control, &mut (), &mut stats)?;
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+        Ok((knapsacks, stats))
+    }
+}
+
+//genetic algorithm of Chu and Beasley for single knapsacks with many dimensions.
+//every child is repaired to be feasible and then improved, dropping and adding items by
+//their pseudo-utility from the surrogate dual. the result depends only on the seed.
+//if more than 1 knapsack is provided it will only modify the first.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ChuBeasley {
+    pub population_size: usize,
+    pub generations: usize, //number of children bred
+    pub seed: u64,
+}
+
+impl ChuBeasley {
+    pub fn new(population_size: usize, generations: usize, seed: u64) -> Self {
+        Self {
+            population_size: population_size,
+            generations: generations,
+            seed: seed,
+        }
+    }
+
+    fn try_solve_observed<const S: usize>(
+        self,
+        problem: BinaryProblem<f64, S>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<BinaryProblemKnapsacks<f64, S>>,
+        stats: &mut SolveStats,
+    ) -> Result<BinaryProblemKnapsacks<f64, S>, KpError> {
+        problem.check_finite()?;
+
+        let items = problem.items;
+        let knapsack = &problem.knapsacks[0];
+
+        let mut profits: Vec<f64> = Vec::with_capacity(items.len());
+        let mut weights: Vec<[f64; S]> = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            profits.push(item.value);
+            weights.push(item.weights);
+        }
+
+        let mut c = [0.0; S];
+        for r in 0..S {
+            c[r] = (knapsack.capacity[r] - knapsack.weights()[r]).max(0.0);
+        }
+
+        //the problem's knapsacks with the packed items added
+        let pack = |x: &[bool]| {
+            let mut knapsacks = problem.knapsacks.clone();
+            for (j, x_j) in x.iter().enumerate() {
+                if *x_j {
+                    knapsacks[0].add(Item::<f64, S> {
+                        value: items[j].value,
+                        weights: items[j].weights,
+                        quantity: 1.0,
+                        id: (),
+                    });
+                }
+            }
+
+            knapsacks
+        };
+
+        //the relaxation of the first knapsack only bounds the problem when it is the only one
+        let held = knapsack.value();
+        let single = problem.knapsacks.len() == 1;
+        let start = Instant::now();
+        let mut report = |report: Report| match report {
+            Report::Bound(bound) => {
+                if single {
+                    stats.bound = Some(held + bound);
+                    observer.on_bound(held + bound);
+                }
+            }
+
+            Report::Incumbent(x) => observer.on_incumbent(&pack(x)),
+            Report::Progress(generation) => observer.on_progress(generation, start.elapsed()),
+        };
+
+        let (solution, status) = chu_beasley(&profits, &weights, &c, &self, control, &mut report)?;
+        stats.status = status;
+        Ok(pack(&solution))
+    }
+}
+
+impl Default for ChuBeasley {
+    fn default() -> Self {
+        Self::new(100, 10000, 0)
+    }
+}
+
+impl SolverInfo for ChuBeasley {
+    fn name(&self) -> &'static str {
+        "Chu-Beasley"
+    }
+}
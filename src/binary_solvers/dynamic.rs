@@ -1,119 +1,385 @@
+use crate::dynamic_mode::{DynamicMode, DEFAULT_MEMORY_BUDGET};
+use crate::dynamic_tables::{check_values, dense_size, fill_profit, fill_sparse, profit_size};
+use crate::error::KpError;
 use crate::item::Item;
 use crate::knapsack::BinaryProblemKnapsacks;
 use crate::problem_type::{BinaryProblem, BinarySolver};
+use crate::profit_type::ProfitType;
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
 use ndarray::{ArrayD, IxDyn};
+use std::time::Instant;
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct Dynamic;
-impl<const S: usize> BinarySolver<u32, S> for Dynamic {
-    type Output = BinaryProblemKnapsacks<u32, S>;
-
-    fn solve(self, mut problem: BinaryProblem<u32, S>) -> BinaryProblemKnapsacks<u32, S> {
-        //find and create the dimensions of the memo matrix
-        let items = problem.items;
-        let knapsack = &mut problem.knapsacks[0];
-
-        let mut dim: Vec<usize> = (0..=S).collect();
-        dim[0] += items.len() + 1;
-        let mut capacity = vec![0_usize; S];
-        capacity.clone_from_slice(
-            knapsack
-                .capacity
-                .map(|x| usize::try_from(x).unwrap())
-                .as_slice(),
-        );
-        let weight = *knapsack.weights();
-        for i in 0..S {
-            if usize::try_from(weight[i]).unwrap() > capacity[i] {
-                capacity[i] = 0;
-            } else {
-                capacity[i] -= weight[i] as usize;
-            }
+//fill the knapsacks with the given remaining capacities at once, returning whether
+//each item is placed in each knapsack. the control is checked before each row of the memo, and
+//if it stops the fill the items of the rows filled so far are placed as well as they can be
+fn fill<V, const S: usize>(
+    items: &[Item<u32, S, u32, (), V>],
+    capacities: &[[usize; S]],
+    control: &SolveControl,
+    rows: &mut usize,
+) -> (Vec<Vec<bool>>, bool)
+where
+    V: ProfitType,
+{
+    //find and create the dimensions of the memo matrix.
+    //there is one axis per item followed by one per dimension of each knapsack
+    let mut dim: Vec<usize> = vec![items.len() + 1];
+    let mut capacity: Vec<usize> = Vec::with_capacity(capacities.len() * S);
+    for knapsack_capacity in capacities {
+        for cap in knapsack_capacity {
+            capacity.push(*cap);
+            dim.push(*cap + 1);
         }
+    }
 
-        for (i, cap) in capacity.iter().enumerate() {
-            dim[i + 1] = *cap + 1;
-        }
+    let mut memo = ArrayD::<V>::from_elem(IxDyn(&dim), V::default());
 
-        let mut memo = ArrayD::<f64>::zeros(IxDyn(&dim));
+    //fill the row of each item from the row before it, iterating over the capacity
+    //(cascadingly). allocate vectors before loop
+    let mut index = vec![0_usize; dim.len()];
+    let mut ref_index = vec![0_usize; dim.len()];
+    let mut filled = 0;
+    let mut stopped = false;
+    for (k, item) in items.iter().enumerate() {
+        if control.should_stop(*rows) {
+            stopped = true;
+            break;
+        }
 
-        //iterate over each item before iterating over the capacity (cascadingly).
-        //allocate vectors before loop
-        let mut index = vec![0_usize; dim.len()];
-        let mut prev_index = vec![0_usize; dim.len()];
-        let mut ref_index = vec![0_usize; dim.len()];
+        *rows += 1;
+        index.fill(0);
         loop {
-            index[0] += 1;
-            for item in items.iter() {
+            index[0] = k;
+            let mut value = memo[IxDyn(&index)];
+
+            //try placing the item in each knapsack
+            for t in 0..capacities.len() {
                 let mut excess_weight: bool = false;
+                ref_index.copy_from_slice(&index);
                 //find ref_index by decreasing corresponding elements of index with item weights
-                for i in 1..=S {
-                    if usize::try_from(item.weights[i - 1]).unwrap() > index[i] {
+                for r in 0..S {
+                    let axis = 1 + t * S + r;
+                    if item.weights[r] as usize > index[axis] {
                         excess_weight = true;
                         break;
                     } else {
-                        ref_index[i] = index[i] - item.weights[i - 1] as usize;
+                        ref_index[axis] -= item.weights[r] as usize;
                     }
                 }
 
                 //excess_weight similar to w_1 > c_1 V w_2 > c_2 V ... lazily evaluated
-                if excess_weight {
-                    memo[IxDyn(&index)] = memo[IxDyn(&prev_index)];
-                } else {
-                    let prev_value = memo[IxDyn(&prev_index)];
+                if !excess_weight {
                     let ref_value = memo[IxDyn(&ref_index)] + item.value;
-                    if ref_value > prev_value {
-                        memo[IxDyn(&index)] = ref_value;
-                    } else {
-                        memo[IxDyn(&index)] = prev_value;
+                    if ref_value > value {
+                        value = ref_value;
                     }
                 }
-
-                index[0] += 1;
-                prev_index[0] += 1;
-                ref_index[0] += 1;
             }
 
-            //if index has reached final memo spot (index[1..] == weights)
-            if index[1..] == capacity {
-                index[0] -= 1; //correct index array to match final spot in matrix
-                break;
-            }
+            index[0] = k + 1;
+            memo[IxDyn(&index)] = value;
 
-            //if index[i] == dim[i], 'increment' index
-            for i in 0..S {
+            //if index[i] == dim[i], 'increment' index, until it has passed the final spot
+            let mut last = true;
+            for i in 1..dim.len() {
+                index[i] += 1;
                 if index[i] == dim[i] {
                     index[i] = 0;
-                    index[i + 1] += 1;
-                    prev_index[i] = 0;
-                    prev_index[i + 1] += 1;
                 } else {
+                    last = false;
                     break;
                 }
             }
 
-            ref_index[0] = 0;
+            if last {
+                break;
+            }
+        }
+
+        filled = k + 1;
+    }
+
+    //now to backtrack the matrix from the last row filled, finding which knapsack each item
+    //was placed in
+    index[0] = filled;
+    index[1..].copy_from_slice(&capacity);
+    let mut item_placed = vec![vec![false; items.len()]; capacities.len()];
+    for (i, item) in items[..filled].iter().enumerate().rev() {
+        let current_val = memo[IxDyn(&index)];
+        index[0] -= 1;
+        if current_val == memo[IxDyn(&index)] {
+            continue;
         }
 
-        //now to backtrack the matrix
-        let mut current_val = memo[IxDyn(&index)];
-        for item in items.iter().rev() {
-            index[0] -= 1;
-            if current_val != memo[IxDyn(&index)] {
-                for j in 1..=S {
-                    index[j] -= item.weights[j - 1] as usize;
+        'knapsack: for t in 0..capacities.len() {
+            ref_index.copy_from_slice(&index);
+            for r in 0..S {
+                let axis = 1 + t * S + r;
+                if item.weights[r] as usize > index[axis] {
+                    continue 'knapsack;
                 }
 
-                knapsack.add(Item::<u32, S> {
-                    value: item.value,
-                    weights: item.weights,
-                    quantity: 1,
-                });
+                ref_index[axis] -= item.weights[r] as usize;
             }
 
-            current_val = memo[IxDyn(&index)];
+            if memo[IxDyn(&ref_index)] + item.value == current_val {
+                item_placed[t][i] = true;
+                index.copy_from_slice(&ref_index);
+                break;
+            }
         }
+    }
+
+    (item_placed, stopped)
+}
+
+//choose the memo by what fits in the memory budget: dense, then indexed by profit, then sparse.
+//rows counts the rows filled by every table, which the control is checked against
+fn fill_within<V, const S: usize>(
+    items: &[Item<u32, S, u32, (), V>],
+    capacities: &[[usize; S]],
+    memory_budget: usize,
+    control: &SolveControl,
+    rows: &mut usize,
+    states: &mut usize,
+) -> Result<(Vec<Vec<bool>>, bool), KpError>
+where
+    V: ProfitType,
+{
+    if let Some(size) = dense_size(items, capacities).filter(|x| *x <= memory_budget) {
+        *states += size / std::mem::size_of::<V>();
+        return Ok(fill(items, capacities, control, rows));
+    }
+
+    let (item_quantity, stopped) =
+        if let Some(size) = profit_size(items, capacities).filter(|x| *x <= memory_budget) {
+            *states += size / std::mem::size_of::<u64>();
+            let (item_quantity, stopped) = fill_profit(items, capacities[0][0], control, rows);
+            (vec![item_quantity], stopped)
+        } else {
+            fill_sparse(items, capacities, memory_budget, control, rows, states)?
+        };
+
+    let item_placed = item_quantity
+        .iter()
+        .map(|quantities| quantities.iter().map(|x| *x > 0).collect())
+        .collect();
+
+    Ok((item_placed, stopped))
+}
+
+//algorithm implemented for types that can be cast into usize (indexing).
+//memory_budget is the number of bytes the memo may use before a smaller table is used instead,
+//and that the sparse table may use before KpError::MemoryBudget is returned
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Dynamic {
+    pub mode: DynamicMode,
+    pub memory_budget: usize,
+}
+
+impl Dynamic {
+    //the solver with the default mode and memory budget, for where a constant is needed
+    pub const DEFAULT: Self = Self::new(DynamicMode::Auto);
+
+    pub const fn new(mode: DynamicMode) -> Self {
+        Self {
+            mode: mode,
+            memory_budget: DEFAULT_MEMORY_BUDGET,
+        }
+    }
+
+    fn try_solve_stats<V, const S: usize>(
+        self,
+        problem: BinaryProblem<u32, S, (), V>,
+        control: &SolveControl,
+        stats: &mut SolveStats,
+    ) -> Result<BinaryProblemKnapsacks<u32, S, (), V>, KpError>
+    where
+        V: ProfitType,
+    {
+        problem.check_finite()?;
+
+        let start = Instant::now();
+
+        //each item is a single copy
+        let mut items: Vec<Item<u32, S, u32, (), V>> = Vec::with_capacity(problem.items.len());
+        for item in problem.items {
+            items.push(Item::<u32, S, u32, (), V>::binary(item.value, item.weights));
+        }
+
+        let mut knapsacks = problem.knapsacks;
+        check_values(
+            items
+                .iter()
+                .map(|item| Some(item.value))
+                .chain(knapsacks.iter().map(|knapsack| Some(knapsack.value()))),
+        )?;
+
+        //remaining capacity of each knapsack
+        let mut capacities: Vec<[usize; S]> = Vec::with_capacity(knapsacks.len());
+        for knapsack in knapsacks.iter() {
+            let mut capacity = [0_usize; S];
+            for r in 0..S {
+                capacity[r] = usize::try_from(knapsack.capacity[r])
+                    .map_err(|_| KpError::Overflow)?
+                    .saturating_sub(
+                        usize::try_from(knapsack.weights()[r]).map_err(|_| KpError::Overflow)?,
+                    );
+            }
+
+            capacities.push(capacity);
+        }
+
+        stats.phase("setup", start);
+        let start = Instant::now();
+
+        //each knapsack filled on its own may leave out items a later knapsack needed, so Auto
+        //fills them jointly unless neither the dense memo nor the sparse table fits in the
+        //memory budget.
+        //the control is checked before each row of the tables, and the knapsacks a stopped
+        //fill did not reach are left as they were
+        let mut rows = 0;
+        let mut stopped = false;
+        let mut joint = self.mode != DynamicMode::Sequential;
+        if joint {
+            match fill_within(
+                &items,
+                &capacities,
+                self.memory_budget,
+                control,
+                &mut rows,
+                &mut stats.states,
+            ) {
+                Ok((item_placed, joint_stopped)) => {
+                    for (t, placed) in item_placed.iter().enumerate() {
+                        for (i, x) in placed.iter().enumerate() {
+                            if *x {
+                                knapsacks[t].add(Item::<u32, S, u32, (), V> {
+                                    value: items[i].value,
+                                    weights: items[i].weights,
+                                    quantity: 1,
+                                    id: (),
+                                });
+                            }
+                        }
+                    }
+
+                    stopped = joint_stopped;
+                }
+
+                //the knapsacks are filled one at a time below instead
+                Err(KpError::MemoryBudget)
+                    if self.mode == DynamicMode::Auto && knapsacks.len() > 1 =>
+                {
+                    joint = false;
+                }
+
+                Err(error) => return Err(error),
+            }
+        }
+
+        if !joint {
+            for t in 0..knapsacks.len() {
+                let (item_placed, knapsack_stopped) = fill_within(
+                    &items,
+                    &capacities[t..=t],
+                    self.memory_budget,
+                    control,
+                    &mut rows,
+                    &mut stats.states,
+                )?;
+                let mut remaining: Vec<Item<u32, S, u32, (), V>> = Vec::with_capacity(items.len());
+                for (item, x) in items.into_iter().zip(item_placed[0].iter()) {
+                    if *x {
+                        knapsacks[t].add(Item::<u32, S, u32, (), V> {
+                            value: item.value,
+                            weights: item.weights,
+                            quantity: 1,
+                            id: (),
+                        });
+                    } else {
+                        remaining.push(item);
+                    }
+                }
+
+                items = remaining;
+                if knapsack_stopped {
+                    stopped = true;
+                    break;
+                }
+            }
+        }
+
+        if knapsacks.len() <= 1 || joint {
+            stats.status = Status::Optimal;
+        }
+
+        if stopped {
+            stats.status = control.stopped_status();
+        }
+
+        stats.phase("memo", start);
+        Ok(knapsacks)
+    }
+}
+
+impl Default for Dynamic {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+macro_rules! impl_BinarySolver_for_Dynamic {
+    ( $( $value:ty ),* ) => {
+        $(
+            impl<const S: usize> BinarySolver<u32, S, $value> for Dynamic {
+                type Output = BinaryProblemKnapsacks<u32, S, (), $value>;
+
+                fn solve(
+                    self,
+                    problem: BinaryProblem<u32, S, (), $value>,
+                ) -> BinaryProblemKnapsacks<u32, S, (), $value> {
+                    self.try_solve(problem).unwrap()
+                }
+
+                fn solve_with(
+                    self,
+                    problem: BinaryProblem<u32, S, (), $value>,
+                    control: &SolveControl,
+                ) -> BinaryProblemKnapsacks<u32, S, (), $value> {
+                    self.try_solve_with(problem, control).unwrap()
+                }
+
+                fn solve_stats_with(
+                    self,
+                    problem: BinaryProblem<u32, S, (), $value>,
+                    control: &SolveControl,
+                ) -> (BinaryProblemKnapsacks<u32, S, (), $value>, SolveStats) {
+                    self.try_solve_stats_with(problem, control).unwrap()
+                }
+
+                fn try_solve_stats_with(
+                    self,
+                    problem: BinaryProblem<u32, S, (), $value>,
+                    control: &SolveControl,
+                ) -> Result<(BinaryProblemKnapsacks<u32, S, (), $value>, SolveStats), KpError> {
+                    let start = Instant::now();
+                    let mut stats = SolveStats::default();
+                    let output = self.try_solve_stats(problem, control, &mut stats)?;
+                    stats.time = start.elapsed();
+                    Ok((output, stats))
+                }
+            }
+        )*
+    };
+}
+
+impl_BinarySolver_for_Dynamic!(f64, f32, u64, u32, i64, i32);
 
-        problem.knapsacks
+impl SolverInfo for Dynamic {
+    fn name(&self) -> &'static str {
+        "Dynamic"
     }
 }
@@ -0,0 +1,383 @@
+use crate::compatible_problem_type_trait::CompatibleProblemType;
+use crate::error::KpError;
+use crate::item::Item;
+use crate::knapsack::BinaryProblemKnapsacks;
+use crate::problem_type::{BinaryProblem, BinarySolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::Instant;
+
+struct Subset<const S: usize> {
+    pub w: [f64; S], //weights
+    pub p: f64,      //profit
+    pub x: u64,      //items of the half in the subset
+}
+
+//largest number of items split between the two halves. each half of 25 items already has up
+//to 2^25 subsets to keep in memory
+const MAX_ITEMS: usize = 50;
+
+//every subset of the items that fits, built one item at a time. stops adding items once the
+//control says so, counting each subset made as an iteration
+fn enumerate<const S: usize>(
+    profits: &[f64],
+    weights: &[[f64; S]],
+    c: &[f64; S],
+    control: &SolveControl,
+    iterations: &mut usize,
+) -> (Vec<Subset<S>>, bool) {
+    let mut subsets = vec![Subset {
+        w: [0.0; S],
+        p: 0.0,
+        x: 0,
+    }];
+
+    for j in 0..profits.len() {
+        if control.should_stop(*iterations) {
+            return (subsets, true);
+        }
+
+        for k in 0..subsets.len() {
+            let mut w = subsets[k].w;
+            let mut fits = true;
+            for r in 0..S {
+                w[r] += weights[j][r];
+                fits &= w[r] <= c[r];
+            }
+
+            if fits {
+                subsets.push(Subset {
+                    w: w,
+                    p: subsets[k].p + profits[j],
+                    x: subsets[k].x | (1 << j),
+                });
+            }
+        }
+
+        *iterations += subsets.len();
+    }
+
+    (subsets, false)
+}
+
+//k-d tree over the weights of every subset, splitting on each dimension in turn, where each
+//node keeps the least weight in every dimension of the subsets added below it
+struct KdTree<const S: usize> {
+    order: Vec<usize>, //subsets in the order of the tree, each node at the middle of its range
+    position: Vec<usize>, //position of each subset in order
+    min: Vec<[f64; S]>, //least weights of the subsets added under the node at each position
+    added: Vec<bool>,
+}
+
+impl<const S: usize> KdTree<S> {
+    fn new(subsets: &[Subset<S>]) -> Self {
+        let mut order: Vec<usize> = (0..subsets.len()).collect();
+        let mut ranges = vec![(0, order.len(), 0)];
+        while let Some((lo, hi, r)) = ranges.pop() {
+            if hi - lo <= 1 {
+                continue;
+            }
+
+            let mid = (lo + hi) / 2;
+            order[lo..hi].select_nth_unstable_by(mid - lo, |x, y| {
+                subsets[*x].w[r].total_cmp(&subsets[*y].w[r])
+            });
+            ranges.push((lo, mid, (r + 1) % S));
+            ranges.push((mid + 1, hi, (r + 1) % S));
+        }
+
+        let mut position = vec![0; order.len()];
+        for (k, s) in order.iter().enumerate() {
+            position[*s] = k;
+        }
+
+        Self {
+            min: vec![[f64::INFINITY; S]; order.len()],
+            added: vec![false; order.len()],
+            order: order,
+            position: position,
+        }
+    }
+
+    fn add(&mut self, s: usize, w: &[f64; S]) {
+        let target = self.position[s];
+        let (mut lo, mut hi) = (0, self.order.len());
+        loop {
+            let mid = (lo + hi) / 2;
+            for r in 0..S {
+                self.min[mid][r] = self.min[mid][r].min(w[r]);
+            }
+
+            if mid == target {
+                break;
+            } else if target < mid {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+
+        self.added[target] = true;
+    }
+
+    //whether a subset added weighs at most w in every dimension. subtrees whose least
+    //weights are over w in some dimension are skipped
+    fn any_within(&self, subsets: &[Subset<S>], w: &[f64; S]) -> bool {
+        let mut ranges = vec![(0, self.order.len())];
+        while let Some((lo, hi)) = ranges.pop() {
+            if lo >= hi {
+                continue;
+            }
+
+            let mid = (lo + hi) / 2;
+            if (0..S).any(|r| self.min[mid][r] > w[r]) {
+                continue;
+            }
+
+            if self.added[mid] && (0..S).all(|r| subsets[self.order[mid]].w[r] <= w[r]) {
+                return true;
+            }
+
+            ranges.push((lo, mid));
+            ranges.push((mid + 1, hi));
+        }
+
+        false
+    }
+}
+
+//drop the subsets that weigh at least as much as another in every dimension without being
+//worth more, leaving them sorted by decreasing profit. the subsets are swept by decreasing
+//profit, and each is kept unless the k-d tree of the subsets kept so far holds a lighter one
+fn undominated<const S: usize>(mut subsets: Vec<Subset<S>>) -> Vec<Subset<S>> {
+    subsets.sort_by(|x, y| {
+        y.p.total_cmp(&x.p)
+            .then(x.w.iter().sum::<f64>().total_cmp(&y.w.iter().sum::<f64>()))
+    });
+
+    let mut tree = KdTree::new(&subsets);
+    let mut kept = vec![false; subsets.len()];
+    for (s, subset) in subsets.iter().enumerate() {
+        if !tree.any_within(&subsets, &subset.w) {
+            tree.add(s, &subset.w);
+            kept[s] = true;
+        }
+    }
+
+    subsets
+        .into_iter()
+        .zip(kept)
+        .filter(|(_, x)| *x)
+        .map(|(subset, _)| subset)
+        .collect()
+}
+
+//returns whether each item is in the optimal solution, or in the best solution found if the
+//control stops the search, along with whether it did
+fn meet_in_the_middle<const S: usize>(
+    profits: &[f64],
+    weights: &[[f64; S]],
+    c: &[f64; S],
+    control: &SolveControl,
+) -> Result<(Vec<bool>, bool), KpError> {
+    let mut solution = vec![false; profits.len()];
+
+    //items that never fit or add nothing are left out
+    let mut items: Vec<usize> = Vec::with_capacity(profits.len());
+    for j in 0..profits.len() {
+        if profits[j] > 0.0 && (0..S).all(|r| weights[j][r] <= c[r]) {
+            items.push(j);
+        }
+    }
+
+    if items.len() > MAX_ITEMS {
+        return Err(KpError::TooManyItems(MAX_ITEMS));
+    }
+
+    let (a, b) = items.split_at(items.len() / 2);
+    let mut iterations = 0;
+    let mut half = |half: &[usize]| {
+        let p: Vec<f64> = half.iter().map(|j| profits[*j]).collect();
+        let w: Vec<[f64; S]> = half.iter().map(|j| weights[*j]).collect();
+        enumerate(&p, &w, c, control, &mut iterations)
+    };
+
+    let (subsets_a, stopped_a) = half(a);
+    let (mut subsets_b, stopped_b) = half(b);
+    let mut stopped = stopped_a || stopped_b;
+
+    //match every subset of the first half with the best subset of the second that fits
+    //in the remaining capacity
+    let mut z = -1.0;
+    let mut best = (0, 0);
+    if S == 1 {
+        //sort by weight and drop subsets no more valuable than a lighter one, so profit
+        //increases with weight and the best match is the heaviest that fits
+        subsets_b.sort_by(|x, y| x.w[0].total_cmp(&y.w[0]));
+        let mut kept: Vec<Subset<S>> = Vec::with_capacity(subsets_b.len());
+        for subset in subsets_b {
+            if kept.last().is_none_or(|x| subset.p > x.p) {
+                kept.push(subset);
+            }
+        }
+
+        subsets_b = kept;
+        for (k, subset) in subsets_a.iter().enumerate() {
+            let c_rem = c[0] - subset.w[0];
+            let l = subsets_b.partition_point(|x| x.w[0] <= c_rem);
+            if l > 0 && subset.p + subsets_b[l - 1].p > z {
+                z = subset.p + subsets_b[l - 1].p;
+                best = (k, l - 1);
+            }
+        }
+    } else {
+        //by decreasing profit the first subset that fits is the best match. the subsets of
+        //the second half are no longer ordered by weight, so in the worst case each subset
+        //of the first half is compared with every one of them
+        subsets_b = undominated(subsets_b);
+        for (k, subset) in subsets_a.iter().enumerate() {
+            if !stopped && control.should_stop(iterations + k) {
+                stopped = true;
+            }
+
+            if stopped {
+                break;
+            }
+
+            for (l, other) in subsets_b.iter().enumerate() {
+                if subset.p + other.p <= z {
+                    break;
+                }
+
+                if (0..S).all(|r| subset.w[r] + other.w[r] <= c[r]) {
+                    z = subset.p + other.p;
+                    best = (k, l);
+                    break;
+                }
+            }
+        }
+    }
+
+    for (i, j) in a.iter().enumerate() {
+        solution[*j] = subsets_a[best.0].x & (1 << i) != 0;
+    }
+
+    for (i, j) in b.iter().enumerate() {
+        solution[*j] = subsets_b[best.1].x & (1 << i) != 0;
+    }
+
+    Ok((solution, stopped))
+}
+
+macro_rules! meet_in_the_middle_impl {
+    ( $( $type:ty ),* ) => {
+        $(
+impl<const S: usize> BinarySolver<$type, S> for MeetInTheMiddle {
+    type Output = BinaryProblemKnapsacks<$type, S>;
+
+    fn solve(self, problem: BinaryProblem<$type, S>) -> BinaryProblemKnapsacks<$type, S> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_with(
+        self,
+        problem: BinaryProblem<$type, S>,
+        control: &SolveControl,
+    ) -> BinaryProblemKnapsacks<$type, S> {
+        self.try_solve_with(problem, control).unwrap()
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BinaryProblem<$type, S>,
+        control: &SolveControl,
+    ) -> (BinaryProblemKnapsacks<$type, S>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BinaryProblem<$type, S>,
+        control: &SolveControl,
+    ) -> Result<(BinaryProblemKnapsacks<$type, S>, SolveStats), KpError> {
+        self.try_solve_stats(problem, control)
+    }
+}
+        )*
+    }
+}
+
+//exact meet-in-the-middle algorithm of Horowitz and Sahni for single knapsacks with any
+//number of dimensions. the items are split in half and every subset of each half that fits
+//is enumerated, then each subset of one half is matched with the best of the other.
+//time and memory grow with 2^(n/2) but not with the capacity, so it suits few items with
+//large capacities. with more than one dimension the matching may take the product of the
+//number of subsets of each half. at most 50 items that fit can be solved.
+//if more than 1 knapsack is provided it will only modify the first.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MeetInTheMiddle;
+meet_in_the_middle_impl!(u32, f64);
+
+impl MeetInTheMiddle {
+    fn try_solve_stats<T, const S: usize>(
+        self,
+        mut problem: BinaryProblem<T, S>,
+        control: &SolveControl,
+    ) -> Result<(BinaryProblemKnapsacks<T, S>, SolveStats), KpError>
+    where
+        T: CompatibleProblemType,
+    {
+        problem.check_finite()?;
+
+        let start = Instant::now();
+        let items = problem.items;
+        let knapsacks = problem.knapsacks.len();
+        let knapsack = &mut problem.knapsacks[0];
+
+        let mut profits: Vec<f64> = Vec::with_capacity(items.len());
+        let mut weights: Vec<[f64; S]> = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            profits.push(item.value);
+            weights.push(item.weights.map(|x| x.into()));
+        }
+
+        let mut c = [0.0; S];
+        for r in 0..S {
+            if knapsack.capacity[r] > knapsack.weights()[r] {
+                c[r] = (knapsack.capacity[r] - knapsack.weights()[r]).into();
+            }
+        }
+
+        let (solution, stopped) = meet_in_the_middle(&profits, &weights, &c, control)?;
+        for (j, x) in solution.iter().enumerate() {
+            if *x {
+                knapsack.add(Item::<T, S> {
+                    value: items[j].value,
+                    weights: items[j].weights,
+                    quantity: T::identity(),
+                    id: (),
+                });
+            }
+        }
+
+        let mut stats = SolveStats::default();
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+
+        //only the first knapsack is filled, so its solution is only optimal for one
+        if stopped {
+            stats.status = control.stopped_status();
+        } else if knapsacks <= 1 {
+            stats.status = Status::Optimal;
+        }
+
+        Ok((problem.knapsacks, stats))
+    }
+}
+
+impl SolverInfo for MeetInTheMiddle {
+    fn name(&self) -> &'static str {
+        "Meet in the Middle"
+    }
+}
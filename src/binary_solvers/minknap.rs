@@ -0,0 +1,271 @@
+use crate::item::Item;
+use crate::knapsack::BinaryProblemKnapsacks;
+use crate::problem_type::{BinaryProblem, BinarySolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::Instant;
+
+struct ItemInfo {
+    pub j: usize, //index
+    pub p: f64,   //profit
+    pub w: f64,   //weight
+}
+
+#[derive(Clone, Copy)]
+struct State {
+    pub w: f64,      //weight
+    pub p: f64,      //profit
+    pub node: usize, //last change made to the break solution
+}
+
+//changes made to the break solution are stored as a tree so states can share them
+struct Change {
+    pub i: usize,      //sorted item index
+    pub parent: usize, //previous change
+}
+
+const ROOT: usize = usize::MAX;
+
+//merge the states with a copy shifted by (w, p), removing dominated states.
+//states are sorted by increasing weight and profit, a negative shift removes an item.
+fn merge(states: &[State], w: f64, p: f64, i: usize, changes: &mut Vec<Change>) -> Vec<State> {
+    let mut merged: Vec<State> = Vec::with_capacity(2 * states.len());
+    let mut push = |state: State, shifted: bool, merged: &mut Vec<State>| {
+        if let Some(last) = merged.last() {
+            if state.p <= last.p {
+                return;
+            }
+
+            if state.w <= last.w {
+                merged.pop();
+            }
+        }
+
+        let mut state = state;
+        if shifted {
+            changes.push(Change {
+                i: i,
+                parent: state.node,
+            });
+
+            state.node = changes.len() - 1;
+        }
+
+        merged.push(state);
+    };
+
+    let (mut a, mut b) = (0, 0);
+    while a < states.len() || b < states.len() {
+        if b == states.len() || (a < states.len() && states[a].w <= states[b].w + w) {
+            push(states[a], false, &mut merged);
+            a += 1;
+        } else {
+            let state = State {
+                w: states[b].w + w,
+                p: states[b].p + p,
+                node: states[b].node,
+            };
+
+            push(state, true, &mut merged);
+            b += 1;
+        }
+    }
+
+    merged
+}
+
+//returns whether each item is in the optimal solution
+pub(crate) fn minknap(profits: &[f64], weights: &[f64], c: f64) -> Vec<bool> {
+    minknap_with(profits, weights, c, &SolveControl::default()).0
+}
+
+//returns whether each item is in the best solution found and whether the control stopped the
+//search. the control is checked before each expansion of the core, so a stopped search
+//returns the best feasible state found so far
+fn minknap_with(
+    profits: &[f64],
+    weights: &[f64],
+    c: f64,
+    control: &SolveControl,
+) -> (Vec<bool>, bool) {
+    let mut solution = vec![false; profits.len()];
+
+    //items without weight are always packed, items that never fit are never packed
+    let mut items: Vec<ItemInfo> = Vec::with_capacity(profits.len());
+    for j in 0..profits.len() {
+        if profits[j] > 0.0 && weights[j] <= c {
+            if weights[j] <= 0.0 {
+                solution[j] = true;
+            } else {
+                items.push(ItemInfo {
+                    j: j,
+                    p: profits[j],
+                    w: weights[j],
+                });
+            }
+        }
+    }
+
+    //find the break item; the break solution packs every item before it
+    items.sort_by(|x, y| (y.p * x.w).partial_cmp(&(x.p * y.w)).unwrap());
+    let n = items.len();
+    let mut b = 0;
+    let mut w_b = 0.0;
+    let mut p_b = 0.0;
+    while b < n && w_b + items[b].w <= c {
+        w_b += items[b].w;
+        p_b += items[b].p;
+        b += 1;
+    }
+
+    //expand the core [s, t) around the break item, alternately adding the next item after
+    //the core and removing the next item before it from every state
+    let mut changes: Vec<Change> = Vec::new();
+    let mut states = vec![State {
+        w: w_b,
+        p: p_b,
+        node: ROOT,
+    }];
+
+    let mut z = p_b;
+    let mut best = ROOT;
+    let (mut s, mut t) = (b, b);
+    let mut expansions = 0;
+    let mut stopped = false;
+    while !states.is_empty() && (s > 0 || t < n) {
+        if control.should_stop(expansions) {
+            stopped = true;
+            break;
+        }
+
+        expansions += 1;
+        if t < n {
+            states = merge(&states, items[t].w, items[t].p, t, &mut changes);
+            t += 1;
+        }
+
+        if s > 0 {
+            s -= 1;
+            states = merge(&states, -items[s].w, -items[s].p, s, &mut changes);
+        }
+
+        //keep the best feasible state and drop any state whose bound does not exceed it
+        let e_t = if t < n { items[t].p / items[t].w } else { 0.0 };
+        let e_s = if s > 0 {
+            items[s - 1].p / items[s - 1].w
+        } else {
+            f64::INFINITY
+        };
+
+        for state in states.iter() {
+            if state.w <= c && state.p > z {
+                z = state.p;
+                best = state.node;
+            }
+        }
+
+        states.retain(|state| {
+            if state.w <= c {
+                state.p + (c - state.w) * e_t > z
+            } else {
+                state.p - (state.w - c) * e_s > z
+            }
+        });
+    }
+
+    for i in 0..b {
+        solution[items[i].j] = true;
+    }
+
+    let mut node = best;
+    while node != ROOT {
+        let i = changes[node].i;
+        solution[items[i].j] = i >= b;
+        node = changes[node].parent;
+    }
+
+    (solution, stopped)
+}
+
+macro_rules! minknap_impl {
+    ( $( $type:ty ),* ) => {
+        $(
+impl BinarySolver<$type, 1> for Minknap {
+    type Output = BinaryProblemKnapsacks<$type, 1>;
+
+    fn solve(self, problem: BinaryProblem<$type, 1>) -> BinaryProblemKnapsacks<$type, 1> {
+        self.solve_with(problem, &SolveControl::default())
+    }
+
+    fn solve_with(
+        self,
+        problem: BinaryProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> BinaryProblemKnapsacks<$type, 1> {
+        self.solve_stats_with(problem, control).0
+    }
+
+    //only the first knapsack is filled, which is optimal unless the control stopped the search
+    fn solve_stats_with(
+        self,
+        mut problem: BinaryProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> (BinaryProblemKnapsacks<$type, 1>, SolveStats) {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let items = problem.items;
+        let knapsack = &mut problem.knapsacks[0];
+
+        let mut profits: Vec<f64> = Vec::with_capacity(items.len());
+        let mut weights: Vec<f64> = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            profits.push(item.value);
+            weights.push(item.weights[0] as f64);
+        }
+
+        let c = if knapsack.capacity[0] > knapsack.weights()[0] {
+            (knapsack.capacity[0] - knapsack.weights()[0]) as f64
+        } else {
+            0.0
+        };
+
+        let (solution, stopped) = minknap_with(&profits, &weights, c, control);
+        for (j, x) in solution.iter().enumerate() {
+            if *x {
+                knapsack.add(Item::<$type, 1> {
+                    value: items[j].value,
+                    weights: items[j].weights,
+                    quantity: 1 as $type,
+                    id: (),
+                });
+            }
+        }
+
+        if stopped {
+            stats.status = control.stopped_status();
+        } else if problem.knapsacks.len() <= 1 {
+            stats.status = Status::Optimal;
+        }
+
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+        (problem.knapsacks, stats)
+    }
+}
+        )*
+    }
+}
+
+//exact core based dynamic programming solver of Pisinger for single knapsacks with one
+//dimension. only items close to the break item are enumerated, using bounds to discard
+//states, so it copes with many items and large capacities.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Minknap;
+minknap_impl!(u32, f64);
+
+impl SolverInfo for Minknap {
+    fn name(&self) -> &'static str {
+        "Minknap"
+    }
+}
@@ -1,15 +1,28 @@
+mod branch_and_bound;
+#[cfg(feature = "metaheuristics")]
+mod chu_beasley;
 mod dynamic;
 mod generalized_greedy;
 #[cfg(any(feature = "cbc", feature = "highs", feature = "cplex",))]
 mod good_lp_wrapper;
+mod meet_in_the_middle;
+mod minknap;
+mod mtm;
 mod theoretical_greedy;
 
+pub use branch_and_bound::BranchAndBound;
+#[cfg(feature = "metaheuristics")]
+pub use chu_beasley::ChuBeasley;
 pub use dynamic::Dynamic;
 pub use generalized_greedy::GeneralizedGreedy;
 #[cfg(feature = "highs")]
 pub use good_lp_wrapper::HiGHS;
 #[cfg(feature = "cbc")]
 pub use good_lp_wrapper::CBC;
+pub use meet_in_the_middle::MeetInTheMiddle;
+pub(crate) use minknap::minknap;
+pub use minknap::Minknap;
+pub use mtm::MTM;
 pub use theoretical_greedy::TheoreticalGreedy;
 //pub use good_lp_wrapper::scip;
 #[cfg(feature = "cplex")]
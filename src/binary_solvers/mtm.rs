@@ -0,0 +1,274 @@
+use super::minknap::minknap;
+use crate::item::Item;
+use crate::knapsack::BinaryProblemKnapsacks;
+use crate::problem_type::{BinaryProblem, BinarySolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::Instant;
+
+struct ItemInfo {
+    pub j: usize, //index
+    pub p: f64,   //profit
+    pub w: f64,   //weight
+}
+
+const NONE: usize = usize::MAX;
+
+struct BoundAndBound<'a> {
+    items: &'a [ItemInfo],
+    residual: Vec<f64>, //remaining capacity of each knapsack
+    x: Vec<usize>,      //knapsack each item is assigned to
+    best_x: Vec<usize>,
+    z: f64, //incumbent value
+    control: &'a SolveControl,
+    nodes: usize,
+    stopped: bool,
+}
+
+impl<'a> BoundAndBound<'a> {
+    //surrogate relaxation: all knapsacks merged into one with their total capacity,
+    //solved exactly over the items that still fit somewhere
+    fn surrogate_bound(&self, j: usize) -> f64 {
+        let mut c = 0.0;
+        let mut max_c = 0.0;
+        for r in self.residual.iter() {
+            c += *r;
+            if *r > max_c {
+                max_c = *r;
+            }
+        }
+
+        let mut profits: Vec<f64> = Vec::with_capacity(self.items.len() - j);
+        let mut weights: Vec<f64> = Vec::with_capacity(self.items.len() - j);
+        for item in self.items[j..].iter() {
+            if item.w <= max_c {
+                profits.push(item.p);
+                weights.push(item.w);
+            }
+        }
+
+        //Dantzig bound first since it is much cheaper to compute
+        let mut bound = 0.0;
+        let mut c_rem = c;
+        for k in 0..profits.len() {
+            if weights[k] > c_rem {
+                bound += c_rem * profits[k] / weights[k];
+                break;
+            }
+
+            bound += profits[k];
+            c_rem -= weights[k];
+        }
+
+        if bound <= self.z {
+            return bound;
+        }
+
+        let solution = minknap(&profits, &weights, c);
+        let mut bound = 0.0;
+        for k in 0..profits.len() {
+            if solution[k] {
+                bound += profits[k];
+            }
+        }
+
+        bound
+    }
+
+    fn branch(&mut self, j: usize, v: f64) {
+        self.nodes += 1;
+        if self.stopped || self.control.should_stop(self.nodes) {
+            self.stopped = true;
+            return;
+        }
+
+        if j == self.items.len() {
+            if v > self.z {
+                self.z = v;
+                self.best_x.copy_from_slice(&self.x);
+            }
+
+            return;
+        }
+
+        if v + self.surrogate_bound(j) <= self.z {
+            return;
+        }
+
+        //assign the item to each knapsack it fits in. knapsacks with the same remaining
+        //capacity lead to symmetric solutions so only the first is tried.
+        let item = &self.items[j];
+        let mut tried: Vec<f64> = Vec::with_capacity(self.residual.len());
+        for i in 0..self.residual.len() {
+            if item.w <= self.residual[i] && !tried.contains(&self.residual[i]) {
+                tried.push(self.residual[i]);
+                self.residual[i] -= item.w;
+                self.x[j] = i;
+                self.branch(j + 1, v + item.p);
+                self.residual[i] += item.w;
+            }
+        }
+
+        self.x[j] = NONE;
+        self.branch(j + 1, v);
+    }
+}
+
+//returns the knapsack each item is assigned to, or NONE. if the control stops the search
+//the best assignment found is returned, along with whether it did
+fn mtm(
+    profits: &[f64],
+    weights: &[f64],
+    capacities: &[f64],
+    control: &SolveControl,
+) -> (Vec<usize>, bool) {
+    let mut solution = vec![NONE; profits.len()];
+    let mut max_c = 0.0;
+    for c in capacities {
+        if *c > max_c {
+            max_c = *c;
+        }
+    }
+
+    let mut items: Vec<ItemInfo> = Vec::with_capacity(profits.len());
+    for j in 0..profits.len() {
+        if profits[j] > 0.0 && weights[j] <= max_c {
+            items.push(ItemInfo {
+                j: j,
+                p: profits[j],
+                w: weights[j],
+            });
+        }
+    }
+
+    items.sort_by(|x, y| (y.p * x.w).partial_cmp(&(x.p * y.w)).unwrap());
+
+    //initial solution by filling the knapsacks one at a time, smallest first
+    let mut order: Vec<usize> = (0..capacities.len()).collect();
+    order.sort_by(|x, y| capacities[*x].partial_cmp(&capacities[*y]).unwrap());
+    let mut x = vec![NONE; items.len()];
+    let mut z = 0.0;
+    for i in order {
+        let mut free: Vec<usize> = Vec::with_capacity(items.len());
+        let mut free_p: Vec<f64> = Vec::with_capacity(items.len());
+        let mut free_w: Vec<f64> = Vec::with_capacity(items.len());
+        for k in 0..items.len() {
+            if x[k] == NONE {
+                free.push(k);
+                free_p.push(items[k].p);
+                free_w.push(items[k].w);
+            }
+        }
+
+        let packed = minknap(&free_p, &free_w, capacities[i]);
+        for (k, in_knapsack) in packed.iter().enumerate() {
+            if *in_knapsack {
+                x[free[k]] = i;
+                z += free_p[k];
+            }
+        }
+    }
+
+    let mut search = BoundAndBound {
+        items: &items,
+        residual: capacities.to_vec(),
+        x: vec![NONE; items.len()],
+        best_x: x,
+        z: z,
+        control: control,
+        nodes: 0,
+        stopped: false,
+    };
+
+    search.branch(0, 0.0);
+    for (k, item) in items.iter().enumerate() {
+        solution[item.j] = search.best_x[k];
+    }
+
+    (solution, search.stopped)
+}
+
+macro_rules! mtm_impl {
+    ( $( $type:ty ),* ) => {
+        $(
+impl BinarySolver<$type, 1> for MTM {
+    type Output = BinaryProblemKnapsacks<$type, 1>;
+
+    fn solve(self, problem: BinaryProblem<$type, 1>) -> BinaryProblemKnapsacks<$type, 1> {
+        self.solve_with(problem, &SolveControl::default())
+    }
+
+    fn solve_with(
+        self,
+        problem: BinaryProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> BinaryProblemKnapsacks<$type, 1> {
+        self.solve_stats_with(problem, control).0
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BinaryProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> (BinaryProblemKnapsacks<$type, 1>, SolveStats) {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let items = problem.items;
+        let mut knapsacks = problem.knapsacks;
+
+        let mut profits: Vec<f64> = Vec::with_capacity(items.len());
+        let mut weights: Vec<f64> = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            profits.push(item.value);
+            weights.push(item.weights[0] as f64);
+        }
+
+        let mut capacities: Vec<f64> = Vec::with_capacity(knapsacks.len());
+        for knapsack in knapsacks.iter() {
+            if knapsack.capacity[0] > knapsack.weights()[0] {
+                capacities.push((knapsack.capacity[0] - knapsack.weights()[0]) as f64);
+            } else {
+                capacities.push(0.0);
+            }
+        }
+
+        let (solution, stopped) = mtm(&profits, &weights, &capacities, control);
+        for (j, i) in solution.iter().enumerate() {
+            if *i != NONE {
+                knapsacks[*i].add(Item::<$type, 1> {
+                    value: items[j].value,
+                    weights: items[j].weights,
+                    quantity: 1 as $type,
+                    id: (),
+                });
+            }
+        }
+
+        stats.status = if stopped {
+            control.stopped_status()
+        } else {
+            Status::Optimal
+        };
+
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+        (knapsacks, stats)
+    }
+}
+        )*
+    }
+}
+
+//exact bound-and-bound algorithm of Martello and Toth for multiple knapsacks with one
+//dimension. nodes are bounded by the surrogate relaxation, which merges every knapsack
+//into a single one.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MTM;
+mtm_impl!(u32, f64);
+
+impl SolverInfo for MTM {
+    fn name(&self) -> &'static str {
+        "MTM"
+    }
+}
@@ -1,7 +1,12 @@
+use crate::error::KpError;
 use crate::item::Item;
 use crate::knapsack::BinaryProblemKnapsacks;
 use crate::problem_type::{BinaryProblem, BinarySolver};
+use crate::solution::SolverInfo;
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
 use minilp::{ComparisonOp, OptimizationDirection, Problem};
+use std::time::Instant;
 
 struct ItemPos<const S: usize> {
     pub j: usize,        //index
@@ -14,7 +19,40 @@ pub struct TheoreticalGreedy;
 impl<const S: usize> BinarySolver<f64, S> for TheoreticalGreedy {
     type Output = BinaryProblemKnapsacks<f64, S>;
 
-    fn solve(self, mut problem: BinaryProblem<f64, S>) -> BinaryProblemKnapsacks<f64, S> {
+    fn solve(self, problem: BinaryProblem<f64, S>) -> BinaryProblemKnapsacks<f64, S> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BinaryProblem<f64, S>,
+        control: &SolveControl,
+    ) -> (BinaryProblemKnapsacks<f64, S>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BinaryProblem<f64, S>,
+        _control: &SolveControl,
+    ) -> Result<(BinaryProblemKnapsacks<f64, S>, SolveStats), KpError> {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let output = self.try_solve_stats(problem, &mut stats)?;
+        stats.time = start.elapsed();
+        Ok((output, stats))
+    }
+}
+
+impl TheoreticalGreedy {
+    fn try_solve_stats<const S: usize>(
+        self,
+        mut problem: BinaryProblem<f64, S>,
+        stats: &mut SolveStats,
+    ) -> Result<BinaryProblemKnapsacks<f64, S>, KpError> {
+        problem.check_finite()?;
+
+        let start = Instant::now();
         let items = problem.items;
         let knapsack = &mut problem.knapsacks[0];
 
@@ -50,8 +88,7 @@ impl<const S: usize> BinarySolver<f64, S> for TheoreticalGreedy {
         }
 
         //set up constraints
-        let mut item_count = 0;
-        for item in items.iter() {
+        for (item_count, item) in items.iter().enumerate() {
             let mut w_formula: Vec<(minilp::Variable, f64)> = Vec::with_capacity(S);
             for r in 0..S {
                 w_formula.push((variables[r], item.weights[r]));
@@ -69,11 +106,16 @@ impl<const S: usize> BinarySolver<f64, S> for TheoreticalGreedy {
             }
 
             dual_problem.add_constraint(&full_formula, ComparisonOp::Ge, item.value);
-            item_count += 1;
         }
 
         //solve dual problem using simplex algorithm
-        let solution = dual_problem.solve().unwrap();
+        let solution = dual_problem
+            .solve()
+            .map_err(|x| KpError::Backend(x.to_string()))?;
+        stats.variables = variables.len();
+        stats.constraints = items.len();
+        stats.phase("linear program", start);
+        let start = Instant::now();
 
         //set up normal vector of hyperplane using optimal relevance values found above
         let mut hyperplane_norm: Vec<f64> = Vec::with_capacity(S);
@@ -98,7 +140,13 @@ impl<const S: usize> BinarySolver<f64, S> for TheoreticalGreedy {
         }
 
         //now sort objects in increasing order based on distance
+        if let Some(x) = item_positions.iter().find(|x| x.dist.is_nan()) {
+            return Err(KpError::IncomparableItem(x.j));
+        }
+
         item_positions.sort_by(|x, y| x.dist.partial_cmp(&y.dist).unwrap());
+        stats.phase("ordering", start);
+        let start = Instant::now();
 
         //now add objects to knapsack
         for item_pos in item_positions {
@@ -106,11 +154,19 @@ impl<const S: usize> BinarySolver<f64, S> for TheoreticalGreedy {
                 value: items[item_pos.j].value,
                 weights: items[item_pos.j].weights,
                 quantity: 1.,
+                id: (),
             }) {
                 break;
             }
         }
 
-        problem.knapsacks
+        stats.phase("packing", start);
+        Ok(problem.knapsacks)
+    }
+}
+
+impl SolverInfo for TheoreticalGreedy {
+    fn name(&self) -> &'static str {
+        "Theoretical Greedy"
     }
 }
@@ -0,0 +1,649 @@
+use super::{Dynamic, GeneralizedGreedy, TheoreticalGreedy};
+use crate::binary_solvers::{Minknap, MTM};
+use crate::compatible_problem_type_trait::CompatibleProblemType;
+use crate::dynamic_mode::{DynamicMode, DEFAULT_MEMORY_BUDGET};
+use crate::dynamic_tables::dense_size;
+use crate::error::KpError;
+use crate::item::{BinaryProblemItems, Item, ProblemItems, UnboundedItem, UnboundedProblemItems};
+use crate::knapsack::{BinaryKnapsack, BinaryProblemKnapsacks, Knapsack, ProblemKnapsacks};
+use crate::problem_type::{BinaryProblem, BoundedProblem, BoundedSolver, UnboundedProblem};
+use crate::solution::SolverInfo;
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use crate::unbounded_solvers::MTU2;
+use crate::unbounded_struct::unbounded;
+use std::fmt;
+use std::time::Instant;
+
+//largest number of copies of items Theoretical Greedy is given, as its linear program has a
+//constraint spanning every copy
+const THEORETICAL_GREEDY_COPIES: usize = 1000;
+
+//largest number of items MTM is given, as its search grows exponentially with them
+const MTM_ITEMS: usize = 100;
+
+//the MIP backend Auto uses when one is enabled, preferring HiGHS, then CBC, then CPLEX
+#[cfg(feature = "highs")]
+const MIP: Option<&str> = Some("HiGHS");
+#[cfg(all(feature = "cbc", not(feature = "highs")))]
+const MIP: Option<&str> = Some("CBC");
+#[cfg(all(feature = "cplex", not(any(feature = "highs", feature = "cbc"))))]
+const MIP: Option<&str> = Some("CPLEX");
+#[cfg(not(any(feature = "highs", feature = "cbc", feature = "cplex")))]
+const MIP: Option<&str> = None;
+
+#[allow(unused_variables)]
+fn solve_mip<const S: usize>(
+    problem: BoundedProblem<f64, S>,
+    control: &SolveControl,
+    stats: &mut SolveStats,
+) -> Result<ProblemKnapsacks<f64, S>, KpError> {
+    #[cfg(feature = "highs")]
+    return super::HiGHS.try_solve_stats(problem, control, stats);
+    #[cfg(all(feature = "cbc", not(feature = "highs")))]
+    return super::CBC.try_solve_stats(problem, control, stats);
+    #[cfg(all(feature = "cplex", not(any(feature = "highs", feature = "cbc"))))]
+    return super::CPLEX.try_solve_stats(problem, control, stats);
+    #[cfg(not(any(feature = "highs", feature = "cbc", feature = "cplex")))]
+    unreachable!()
+}
+
+//solvers Auto dispatches to
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Pick {
+    Dynamic(DynamicMode),
+    Minknap,
+    Mtm,
+    Mtu2,
+    Mip,
+    TheoreticalGreedy,
+    GeneralizedGreedy,
+}
+
+//the solver Auto picked for a problem and why
+#[derive(Clone, PartialEq, Debug)]
+pub struct Choice {
+    pub solver: &'static str, //name of the solver, as given by SolverInfo
+    pub reason: String,
+}
+
+impl fmt::Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.solver, self.reason)
+    }
+}
+
+//inspects the problem and dispatches to the most suitable available solver:
+//- Dynamic when its table fits in the memory budget, as it is exact.
+//- Minknap, MTM or MTU2 for problems with one dimension that are binary or unbounded ones.
+//- a MIP backend, if enabled, when the model has at most mip_variables variables.
+//- Theoretical Greedy for a single knapsack with few enough copies of items.
+//- Generalized Greedy otherwise, as it is the only heuristic that fills every knapsack.
+//problems of f64 are only given to Dynamic if every number in them is a whole number.
+//Branch and Bound is never picked, as Minknap solves the same problems exactly from fewer
+//states. the status and counts of the solve are those of the solver picked
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Auto {
+    pub memory_budget: usize,
+    pub mip_variables: usize,
+}
+
+impl Auto {
+    pub fn new(memory_budget: usize, mip_variables: usize) -> Self {
+        Self {
+            memory_budget: memory_budget,
+            mip_variables: mip_variables,
+        }
+    }
+
+    //the solver picked for a problem of u32 and why
+    pub fn explain_u32<const S: usize>(&self, problem: &BoundedProblem<u32, S>) -> Choice {
+        let (pick, reason) = self.pick_u32(problem);
+        choice(pick, reason)
+    }
+
+    //the solver picked for a problem of f64 and why
+    pub fn explain_f64<const S: usize>(&self, problem: &BoundedProblem<f64, S>) -> Choice {
+        let (pick, reason) = self.pick_f64(problem);
+        choice(pick, reason)
+    }
+
+    fn pick_u32<const S: usize>(&self, problem: &BoundedProblem<u32, S>) -> (Pick, String) {
+        let items: Vec<Item<u32, S>> = problem.items.iter().cloned().collect();
+        let mut capacities: Vec<[usize; S]> = Vec::with_capacity(problem.knapsacks.len());
+        for knapsack in problem.knapsacks.iter() {
+            let mut capacity = [0_usize; S];
+            for r in 0..S {
+                capacity[r] = knapsack.capacity[r].saturating_sub(knapsack.weights()[r]) as usize;
+            }
+
+            capacities.push(capacity);
+        }
+
+        let m = capacities.len();
+        if let Some(size) = dense_size(&items, &capacities).filter(|x| *x <= self.memory_budget) {
+            return (
+                Pick::Dynamic(DynamicMode::Joint),
+                format!(
+                    "the table for all {m} knapsacks takes {size} bytes, within the memory budget, so the problem is solved exactly"
+                ),
+            );
+        }
+
+        if let Some((pick, reason)) = pick_exact(problem) {
+            return (
+                pick,
+                format!("the table of Dynamic is over the memory budget but {reason}"),
+            );
+        }
+
+        let variables = items.len().saturating_mul(m);
+        if let Some(mip) = MIP.filter(|_| variables <= self.mip_variables) {
+            return (
+                Pick::Mip,
+                format!(
+                    "the table of Dynamic is over the memory budget but the model has {variables} variables, so {mip} solves the problem exactly"
+                ),
+            );
+        }
+
+        if m == 1 {
+            return (
+                Pick::Dynamic(DynamicMode::Auto),
+                "the table of Dynamic is over the memory budget, but with a single knapsack it can index its table by profit or keep only undominated states and still solve the problem exactly. if even the undominated states are over the budget, Generalized Greedy fills the knapsack instead".to_string(),
+            );
+        }
+
+        if capacities.iter().all(|x| {
+            dense_size(&items, std::slice::from_ref(x)).is_some_and(|x| x <= self.memory_budget)
+        }) {
+            return (
+                Pick::Dynamic(DynamicMode::Sequential),
+                format!(
+                    "the table for all {m} knapsacks is over the memory budget but the table for each fits, so Dynamic fills them one at a time"
+                ),
+            );
+        }
+
+        (
+            Pick::GeneralizedGreedy,
+            format!(
+                "the tables of Dynamic are over the memory budget and no MIP backend can take the model, so the heuristic fills all {m} knapsacks"
+            ),
+        )
+    }
+
+    fn pick_f64<const S: usize>(&self, problem: &BoundedProblem<f64, S>) -> (Pick, String) {
+        if let Some(problem) = to_u32(problem) {
+            let (pick, reason) = self.pick_u32(&problem);
+            if pick != Pick::GeneralizedGreedy {
+                return (pick, format!("every number is a whole number and {reason}"));
+            }
+        }
+
+        if let Some((pick, reason)) = pick_exact(problem) {
+            return (pick, reason);
+        }
+
+        let m = problem.knapsacks.len();
+        let variables = problem.items.len().saturating_mul(m);
+        if let Some(mip) = MIP.filter(|_| variables <= self.mip_variables) {
+            return (
+                Pick::Mip,
+                format!(
+                    "{mip} is enabled and the model has {variables} variables, within the limit, so the problem is solved exactly"
+                ),
+            );
+        }
+
+        let mut copies = 0.0;
+        for item in problem.items.iter() {
+            copies += item.quantity;
+        }
+
+        if m == 1 && copies <= THEORETICAL_GREEDY_COPIES as f64 {
+            return (
+                Pick::TheoreticalGreedy,
+                format!(
+                    "there is a single knapsack and {copies} copies of items, so the ranking from the linear relaxation is worth its cost"
+                ),
+            );
+        }
+
+        let reason = if m == 1 {
+            format!(
+                "there are {copies} copies of items, too many for the linear program of Theoretical Greedy"
+            )
+        } else {
+            format!("there are {m} knapsacks and Theoretical Greedy only fills the first")
+        };
+
+        (Pick::GeneralizedGreedy, reason)
+    }
+}
+
+impl Default for Auto {
+    fn default() -> Self {
+        Self::new(DEFAULT_MEMORY_BUDGET, 10000)
+    }
+}
+
+fn choice(pick: Pick, reason: String) -> Choice {
+    Choice {
+        solver: match pick {
+            Pick::Dynamic(_) => "Dynamic",
+            Pick::Minknap => "Minknap",
+            Pick::Mtm => "MTM",
+            Pick::Mtu2 => "MTU2",
+            Pick::Mip => MIP.unwrap_or_default(),
+            Pick::TheoreticalGreedy => "Theoretical Greedy",
+            Pick::GeneralizedGreedy => "Generalized Greedy",
+        },
+        reason: reason,
+    }
+}
+
+//capacity left in the first dimension of a knapsack
+fn remaining<T, const S: usize>(knapsack: &Knapsack<T, S>) -> T
+where
+    T: CompatibleProblemType,
+{
+    if knapsack.capacity[0] > knapsack.weights()[0] {
+        knapsack.capacity[0] - knapsack.weights()[0]
+    } else {
+        T::default()
+    }
+}
+
+//problems with one dimension are binary ones if every item is a single copy, and unbounded
+//ones if there is a single knapsack and every item has as many copies as fit in it. both
+//have exact solvers that do not need a table over the capacities
+fn pick_exact<T, const S: usize>(problem: &BoundedProblem<T, S>) -> Option<(Pick, String)>
+where
+    T: CompatibleProblemType,
+{
+    if S != 1 {
+        return None;
+    }
+
+    let n = problem.items.len();
+    let m = problem.knapsacks.len();
+    if problem.items.iter().all(|x| x.quantity == T::identity()) {
+        if m == 1 {
+            return Some((
+                Pick::Minknap,
+                "every item is a single copy and there is a single knapsack with one dimension, so Minknap solves the problem exactly".to_string(),
+            ));
+        }
+
+        if n <= MTM_ITEMS {
+            return Some((
+                Pick::Mtm,
+                format!(
+                    "every item is a single copy and there are {n} items for {m} knapsacks with one dimension, so MTM solves the problem exactly"
+                ),
+            ));
+        }
+
+        return None;
+    }
+
+    if m == 1 {
+        let capacity: f64 = remaining(&problem.knapsacks[0]).into();
+        let fits_unbounded = problem.items.iter().all(|x| {
+            let weight: f64 = x.weights[0].into();
+            let quantity: f64 = x.quantity.into();
+            weight > 0.0 && quantity >= (capacity / weight).floor()
+        });
+
+        if fits_unbounded {
+            return Some((
+                Pick::Mtu2,
+                "there is a single knapsack with one dimension and every item has as many copies as fit in it, so MTU2 solves the problem exactly as an unbounded one".to_string(),
+            ));
+        }
+    }
+
+    None
+}
+
+//solves a problem picked by pick_exact whose items are all single copies as a binary problem,
+//adding what the solver packs to the knapsacks of the problem
+fn solve_binary<T, N, const S: usize>(
+    solver: N,
+    problem: BoundedProblem<T, S>,
+    control: &SolveControl,
+    stats: &mut SolveStats,
+) -> Result<ProblemKnapsacks<T, S>, KpError>
+where
+    T: CompatibleProblemType,
+    N: crate::problem_type::BinarySolver<T, 1, Output = BinaryProblemKnapsacks<T, 1>>,
+{
+    let mut items = BinaryProblemItems::<T, 1>::new();
+    for item in problem.items.iter() {
+        items.add(Item::<T, 1>::binary(item.value, [item.weights[0]]));
+    }
+
+    let mut knapsacks = BinaryProblemKnapsacks::<T, 1>::new();
+    for knapsack in problem.knapsacks.iter() {
+        knapsacks.add(BinaryKnapsack::<T, 1>::new([remaining(knapsack)]));
+    }
+
+    let (packed, solver_stats) = solver.try_solve_stats_with(
+        BinaryProblem::<T, 1> {
+            items: items,
+            knapsacks: knapsacks,
+        },
+        control,
+    )?;
+
+    *stats = solver_stats;
+    let mut knapsacks = problem.knapsacks;
+    for (i, knapsack) in packed.into_iter().enumerate() {
+        for item in knapsack {
+            knapsacks[i].add(Item::<T, S> {
+                value: item.value,
+                weights: [item.weights[0]; S],
+                quantity: item.quantity,
+                id: (),
+            });
+        }
+    }
+
+    Ok(knapsacks)
+}
+
+//solves a problem picked by pick_exact whose items have as many copies as fit as an unbounded
+//problem, adding what the solver packs to the knapsacks of the problem
+fn solve_unbounded<T, N, const S: usize>(
+    solver: N,
+    problem: BoundedProblem<T, S>,
+    control: &SolveControl,
+    stats: &mut SolveStats,
+) -> Result<ProblemKnapsacks<T, S>, KpError>
+where
+    T: CompatibleProblemType,
+    N: crate::problem_type::UnboundedSolver<T, 1, Output = ProblemKnapsacks<T, 1>>,
+{
+    let mut items = UnboundedProblemItems::<T, 1>::new();
+    for item in problem.items.iter() {
+        items.add(UnboundedItem::<T, 1> {
+            value: item.value,
+            weights: [item.weights[0]],
+            quantity: unbounded,
+            id: (),
+        });
+    }
+
+    let mut knapsacks = ProblemKnapsacks::<T, 1>::new();
+    for knapsack in problem.knapsacks.iter() {
+        knapsacks.add(Knapsack::<T, 1>::new([remaining(knapsack)]));
+    }
+
+    let (packed, solver_stats) = solver.try_solve_stats_with(
+        UnboundedProblem::<T, 1> {
+            items: items,
+            knapsacks: knapsacks,
+        },
+        control,
+    )?;
+
+    *stats = solver_stats;
+    let mut knapsacks = problem.knapsacks;
+    for (i, knapsack) in packed.into_iter().enumerate() {
+        for item in knapsack {
+            knapsacks[i].add(Item::<T, S> {
+                value: item.value,
+                weights: [item.weights[0]; S],
+                quantity: item.quantity,
+                id: (),
+            });
+        }
+    }
+
+    Ok(knapsacks)
+}
+
+//the problem with every number as a u32, if every number is a whole number that fits
+fn to_u32<const S: usize>(problem: &BoundedProblem<f64, S>) -> Option<BoundedProblem<u32, S>> {
+    let whole = |x: f64| x >= 0.0 && x.fract() == 0.0 && x <= u32::MAX as f64;
+    let convert = |item: &Item<f64, S>| -> Option<Item<u32, S>> {
+        if !whole(item.quantity) || !item.weights.iter().all(|x| whole(*x)) {
+            return None;
+        }
+
+        Some(Item::<u32, S> {
+            value: item.value,
+            weights: item.weights.map(|x| x as u32),
+            quantity: item.quantity as u32,
+            id: (),
+        })
+    };
+
+    let mut items = ProblemItems::<u32, S>::new();
+    for item in problem.items.iter() {
+        items.add(convert(item)?);
+    }
+
+    let mut knapsacks = ProblemKnapsacks::<u32, S>::new();
+    for knapsack in problem.knapsacks.iter() {
+        if !knapsack.capacity.iter().all(|x| whole(*x)) {
+            return None;
+        }
+
+        let mut converted = Knapsack::<u32, S>::new(knapsack.capacity.map(|x| x as u32));
+        for item in knapsack.iter() {
+            converted.add(convert(item)?);
+        }
+
+        knapsacks.add(converted);
+    }
+
+    Some(BoundedProblem::<u32, S> {
+        items: items,
+        knapsacks: knapsacks,
+    })
+}
+
+impl Auto {
+    //solves with Dynamic, falling back to Generalized Greedy if even the sparse table of a
+    //single knapsack is over the memory budget
+    fn solve_dynamic<const S: usize>(
+        self,
+        mode: DynamicMode,
+        problem: BoundedProblem<u32, S>,
+        control: &SolveControl,
+        stats: &mut SolveStats,
+    ) -> Result<ProblemKnapsacks<u32, S>, KpError> {
+        //only the sparse table can be over the budget once the problem was picked
+        let fallback = (mode == DynamicMode::Auto).then(|| BoundedProblem::<u32, S> {
+            items: problem.items.clone(),
+            knapsacks: problem.knapsacks.clone(),
+        });
+
+        let dynamic = Dynamic {
+            mode: mode,
+            memory_budget: self.memory_budget,
+        };
+
+        match (dynamic.try_solve_stats(problem, control, stats), fallback) {
+            (Err(KpError::MemoryBudget), Some(problem)) => {
+                *stats = SolveStats::default();
+                solve_as_f64(Pick::GeneralizedGreedy, problem, control, stats)
+            }
+            (result, _) => result,
+        }
+    }
+
+    fn try_solve_u32<const S: usize>(
+        self,
+        problem: BoundedProblem<u32, S>,
+        control: &SolveControl,
+        stats: &mut SolveStats,
+    ) -> Result<ProblemKnapsacks<u32, S>, KpError> {
+        problem.check_finite()?;
+
+        let (pick, _) = self.pick_u32(&problem);
+        match pick {
+            Pick::Dynamic(mode) => self.solve_dynamic(mode, problem, control, stats),
+            Pick::Minknap => solve_binary(Minknap, problem, control, stats),
+            Pick::Mtm => solve_binary(MTM, problem, control, stats),
+            Pick::Mtu2 => solve_unbounded(MTU2, problem, control, stats),
+            _ => solve_as_f64(pick, problem, control, stats),
+        }
+    }
+
+    fn try_solve_f64<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+        stats: &mut SolveStats,
+    ) -> Result<ProblemKnapsacks<f64, S>, KpError> {
+        problem.check_finite()?;
+
+        let (pick, _) = self.pick_f64(&problem);
+        let start = Instant::now();
+        match pick {
+            Pick::Dynamic(mode) => {
+                //every number was checked to be whole when picking
+                let problem = to_u32(&problem).unwrap();
+                let knapsacks = self.solve_dynamic(mode, problem, control, stats)?;
+                Ok(knapsacks.to_generic::<f64>())
+            }
+
+            Pick::Minknap => solve_binary(Minknap, problem, control, stats),
+            Pick::Mtm => solve_binary(MTM, problem, control, stats),
+            Pick::Mtu2 => solve_unbounded(MTU2, problem, control, stats),
+            Pick::Mip => solve_mip(problem, control, stats),
+            Pick::TheoreticalGreedy => {
+                let knapsacks = TheoreticalGreedy.try_solve(problem)?;
+                stats.phase("solve", start);
+                Ok(knapsacks)
+            }
+
+            Pick::GeneralizedGreedy => {
+                let knapsacks = GeneralizedGreedy.try_solve(problem)?;
+                stats.phase("solve", start);
+                Ok(knapsacks)
+            }
+        }
+    }
+}
+
+//solves a problem of u32 with a solver that takes f64, converting the knapsacks back
+fn solve_as_f64<const S: usize>(
+    pick: Pick,
+    problem: BoundedProblem<u32, S>,
+    control: &SolveControl,
+    stats: &mut SolveStats,
+) -> Result<ProblemKnapsacks<u32, S>, KpError> {
+    let problem = BoundedProblem::<f64, S> {
+        items: problem.items.to_generic::<f64>(),
+        knapsacks: problem.knapsacks.to_generic::<f64>(),
+    };
+
+    let start = Instant::now();
+    let knapsacks = match pick {
+        Pick::Mip => solve_mip(problem, control, stats)?,
+        _ => {
+            let knapsacks = GeneralizedGreedy.try_solve(problem)?;
+            stats.phase("solve", start);
+            knapsacks
+        }
+    };
+
+    let mut converted = ProblemKnapsacks::<u32, S>::new();
+    for knapsack in knapsacks {
+        let mut knapsack_u32 = Knapsack::<u32, S>::new(knapsack.capacity.map(|x| x as u32));
+        for item in knapsack {
+            knapsack_u32.add(Item::<u32, S> {
+                value: item.value,
+                weights: item.weights.map(|x| x as u32),
+                quantity: item.quantity as u32,
+                id: (),
+            });
+        }
+
+        converted.add(knapsack_u32);
+    }
+
+    Ok(converted)
+}
+
+impl<const S: usize> BoundedSolver<u32, S> for Auto {
+    type Output = ProblemKnapsacks<u32, S>;
+
+    fn solve(self, problem: BoundedProblem<u32, S>) -> ProblemKnapsacks<u32, S> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_with(
+        self,
+        problem: BoundedProblem<u32, S>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<u32, S> {
+        self.try_solve_with(problem, control).unwrap()
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BoundedProblem<u32, S>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<u32, S>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BoundedProblem<u32, S>,
+        control: &SolveControl,
+    ) -> Result<(ProblemKnapsacks<u32, S>, SolveStats), KpError> {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let output = self.try_solve_u32(problem, control, &mut stats)?;
+        stats.time = start.elapsed();
+        Ok((output, stats))
+    }
+}
+
+impl<const S: usize> BoundedSolver<f64, S> for Auto {
+    type Output = ProblemKnapsacks<f64, S>;
+
+    fn solve(self, problem: BoundedProblem<f64, S>) -> ProblemKnapsacks<f64, S> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<f64, S> {
+        self.try_solve_with(problem, control).unwrap()
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<f64, S>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+    ) -> Result<(ProblemKnapsacks<f64, S>, SolveStats), KpError> {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let output = self.try_solve_f64(problem, control, &mut stats)?;
+        stats.time = start.elapsed();
+        Ok((output, stats))
+    }
+}
+
+impl SolverInfo for Auto {
+    fn name(&self) -> &'static str {
+        "Auto"
+    }
+}
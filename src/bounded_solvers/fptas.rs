@@ -0,0 +1,265 @@
+use crate::error::KpError;
+use crate::item::Item;
+use crate::knapsack::ProblemKnapsacks;
+use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solution::SolverInfo;
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::Instant;
+
+struct Bundle {
+    pub j: usize,     //index
+    pub count: usize, //copies of the item in the bundle
+    pub p: f64,       //profit
+    pub w: f64,       //weight
+}
+
+//returns the number of copies of each item to pack, along with an upper bound on the
+//optimal value and whether the control stopped the dynamic program, in which case the
+//greedy solution is returned instead
+fn fptas(
+    profits: &[f64],
+    weights: &[f64],
+    quantities: &[f64],
+    c: f64,
+    epsilon: f64,
+    control: &SolveControl,
+) -> (Vec<usize>, f64, bool) {
+    let mut solution = vec![0_usize; profits.len()];
+
+    //copies without weight are always packed. the copies that fit of every other item are
+    //split into bundles of 1, 2, 4, ... copies so each item becomes a few binary items
+    let mut free = 0.0;
+    let mut bundles: Vec<Bundle> = Vec::new();
+    let mut order: Vec<usize> = Vec::with_capacity(profits.len());
+    for j in 0..profits.len() {
+        if profits[j] <= 0.0 || quantities[j] < 1.0 || weights[j] > c {
+            continue;
+        }
+
+        if weights[j] <= 0.0 {
+            solution[j] = quantities[j] as usize;
+            free += profits[j] * solution[j] as f64;
+            continue;
+        }
+
+        order.push(j);
+        let mut remaining = quantities[j].min((c / weights[j]).floor()) as usize;
+        let mut count = 1;
+        while remaining > 0 {
+            let count_min = count.min(remaining);
+            bundles.push(Bundle {
+                j: j,
+                count: count_min,
+                p: profits[j] * count_min as f64,
+                w: weights[j] * count_min as f64,
+            });
+
+            remaining -= count_min;
+            count *= 2;
+        }
+    }
+
+    //greedy lower bound and Dantzig upper bound over the items by decreasing efficiency.
+    //the lower bound also considers the best single copy, so the upper bound is at most twice it
+    order.sort_by(|x, y| {
+        (profits[*y] * weights[*x])
+            .partial_cmp(&(profits[*x] * weights[*y]))
+            .unwrap()
+    });
+    let mut lower = 0.0;
+    let mut upper = 0.0;
+    let mut c_greedy = c;
+    let mut c_dantzig = c;
+    for j in order.iter() {
+        lower = f64::max(lower, profits[*j]);
+        if c_dantzig > 0.0 {
+            let fraction = (c_dantzig / weights[*j]).min(quantities[*j]);
+            upper += profits[*j] * fraction;
+            c_dantzig -= weights[*j] * fraction;
+        }
+    }
+
+    let mut greedy = 0.0;
+    let mut greedy_solution = solution.clone();
+    for j in order.iter() {
+        let count = (c_greedy / weights[*j]).floor().min(quantities[*j]);
+        greedy += profits[*j] * count;
+        c_greedy -= weights[*j] * count;
+        greedy_solution[*j] += count as usize;
+    }
+
+    lower = f64::max(lower, greedy);
+    if bundles.is_empty() {
+        return (solution, free, false);
+    }
+
+    //scale profits so rounding each bundle down loses at most epsilon * lower in total.
+    //no feasible solution exceeds upper, so neither can its scaled profit
+    let k = epsilon * lower / bundles.len() as f64;
+    let scaled: Vec<usize> = bundles.iter().map(|x| (x.p / k).floor() as usize).collect();
+    let width = (upper / k).floor() as usize + 1;
+
+    //memo of the smallest weight needed to reach each scaled profit, rolled over the bundles,
+    //with a bit for each bundle and scaled profit set when the bundle was taken to reach it
+    let mut memo = vec![f64::INFINITY; width];
+    let mut taken = vec![0_u64; (bundles.len() * width).div_ceil(64)];
+    memo[0] = 0.0;
+    for (i, bundle) in bundles.iter().enumerate() {
+        if control.should_stop(i) {
+            return (greedy_solution, free + upper, true);
+        }
+
+        for q in (scaled[i]..width).rev() {
+            let ref_w = memo[q - scaled[i]] + bundle.w;
+            if ref_w <= c && ref_w < memo[q] {
+                memo[q] = ref_w;
+                let bit = i * width + q;
+                taken[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+    }
+
+    //largest scaled profit that fits, then backtrack
+    let mut q = 0;
+    for r in 0..width {
+        if memo[r] <= c {
+            q = r;
+        }
+    }
+
+    let mut value = 0.0;
+    for i in (0..bundles.len()).rev() {
+        let bit = i * width + q;
+        if taken[bit / 64] & (1 << (bit % 64)) != 0 {
+            solution[bundles[i].j] += bundles[i].count;
+            value += bundles[i].p;
+            q -= scaled[i];
+        }
+    }
+
+    //the optimal solution loses less than k per bundle it uses when scaled
+    let bound = f64::min(upper, value + k * bundles.len() as f64);
+    (solution, free + f64::max(bound, value), false)
+}
+
+macro_rules! fptas_impl {
+    ( $( $type:ty ),* ) => {
+        $(
+impl BoundedSolver<$type, 1> for Fptas {
+    type Output = ProblemKnapsacks<$type, 1>;
+
+    fn solve(self, problem: BoundedProblem<$type, 1>) -> ProblemKnapsacks<$type, 1> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_with(
+        self,
+        problem: BoundedProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<$type, 1> {
+        self.solve_stats_with(problem, control).0
+    }
+
+    fn solve_stats_with(
+        self,
+        mut problem: BoundedProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<$type, 1>, SolveStats) {
+        self.check().unwrap();
+        let start = Instant::now();
+        let items: Vec<Item<$type, 1>> = problem.items.into_iter().collect();
+        let value = problem.knapsacks.value();
+        let knapsacks = problem.knapsacks.len();
+        let knapsack = &mut problem.knapsacks[0];
+
+        let mut profits: Vec<f64> = Vec::with_capacity(items.len());
+        let mut weights: Vec<f64> = Vec::with_capacity(items.len());
+        let mut quantities: Vec<f64> = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            profits.push(item.value);
+            weights.push(item.weights[0] as f64);
+            quantities.push(item.quantity as f64);
+        }
+
+        let c = if knapsack.capacity[0] > knapsack.weights()[0] {
+            (knapsack.capacity[0] - knapsack.weights()[0]) as f64
+        } else {
+            0.0
+        };
+
+        let (quantity, bound, stopped) =
+            fptas(&profits, &weights, &quantities, c, self.epsilon, control);
+        for (j, count) in quantity.iter().enumerate() {
+            if *count > 0 {
+                knapsack.add(Item::<$type, 1> {
+                    value: items[j].value,
+                    weights: items[j].weights,
+                    quantity: *count as $type,
+                    id: (),
+                });
+            }
+        }
+
+        let mut stats = SolveStats::default();
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+
+        //the bound only covers the first knapsack, the only one filled
+        if knapsacks <= 1 {
+            stats.bound = Some(bound + value);
+        }
+
+        if stopped {
+            stats.status = control.stopped_status();
+        }
+
+        (problem.knapsacks, stats)
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BoundedProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> Result<(ProblemKnapsacks<$type, 1>, SolveStats), KpError> {
+        problem.check_finite()?;
+        self.check()?;
+        Ok(self.solve_stats_with(problem, control))
+    }
+}
+        )*
+    }
+}
+
+//fully polynomial time approximation scheme for single knapsacks with one dimension.
+//profits are scaled down so the dynamic program is polynomial in the number of items and
+//1/epsilon, and the solution is worth at least (1 - epsilon) of the optimum.
+//a proven upper bound on the optimal value is reported in the stats of the solve.
+//if more than 1 knapsack is provided it will only modify the first and give no bound.
+//if the control stops the solve, the greedy solution is returned instead
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Fptas {
+    pub epsilon: f64,
+}
+
+impl Fptas {
+    pub fn new(epsilon: f64) -> Self {
+        Self { epsilon: epsilon }
+    }
+
+    fn check(self) -> Result<(), KpError> {
+        if self.epsilon > 0.0 && self.epsilon.is_finite() {
+            Ok(())
+        } else {
+            Err(KpError::InvalidParameter("epsilon must be positive"))
+        }
+    }
+}
+
+fptas_impl!(u32, f64);
+
+impl SolverInfo for Fptas {
+    fn name(&self) -> &'static str {
+        "FPTAS"
+    }
+}
@@ -1,6 +1,11 @@
+use crate::error::KpError;
 use crate::item::Item;
 use crate::knapsack::ProblemKnapsacks;
 use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solution::SolverInfo;
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::Instant;
 
 struct ItemInfo {
     pub j: usize, //index
@@ -14,6 +19,20 @@ struct KnapsackInfo {
     pub s: f64,   //score
 }
 
+//value per unit of penalty. items without penalty, such as those without weight, rank
+//above or below every other item by the sign of their value, or as worthless without one
+fn efficiency(value: f64, penalty: f64) -> f64 {
+    if penalty > 0.0 {
+        value / penalty
+    } else if value > 0.0 {
+        f64::INFINITY
+    } else if value < 0.0 {
+        f64::NEG_INFINITY
+    } else {
+        0.0
+    }
+}
+
 //algorithm implemented for types that can be cast into f64 (efficiency calculation)
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct GeneralizedGreedy;
@@ -21,6 +40,39 @@ impl<const S: usize> BoundedSolver<f64, S> for GeneralizedGreedy {
     type Output = ProblemKnapsacks<f64, S>;
 
     fn solve(self, problem: BoundedProblem<f64, S>) -> ProblemKnapsacks<f64, S> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<f64, S>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        _control: &SolveControl,
+    ) -> Result<(ProblemKnapsacks<f64, S>, SolveStats), KpError> {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let output = self.try_solve_stats(problem, &mut stats)?;
+        stats.time = start.elapsed();
+        Ok((output, stats))
+    }
+}
+
+impl GeneralizedGreedy {
+    fn try_solve_stats<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        stats: &mut SolveStats,
+    ) -> Result<ProblemKnapsacks<f64, S>, KpError> {
+        problem.check_finite()?;
+
+        let start = Instant::now();
         let items = problem.items;
         let mut knapsacks = problem.knapsacks;
         let mut knapsack_order: Vec<KnapsackInfo> = Vec::with_capacity(knapsacks.len());
@@ -63,7 +115,7 @@ impl<const S: usize> BoundedSolver<f64, S> for GeneralizedGreedy {
                         sum += item.weights[r] * (d_diff[r] - largest_neg);
                     }
 
-                    item.value / sum
+                    efficiency(item.value, sum)
                 },
 
                 x: false,
@@ -86,6 +138,14 @@ impl<const S: usize> BoundedSolver<f64, S> for GeneralizedGreedy {
         }
 
         //sort item_order and knapsack_order by increasing efficiency and score respectively
+        if let Some(x) = items_toyoda.iter().find(|x| x.e.is_nan()) {
+            return Err(KpError::IncomparableItem(x.j));
+        }
+
+        if let Some(x) = knapsack_order.iter().find(|x| x.s.is_nan()) {
+            return Err(KpError::IncomparableKnapsack(x.j));
+        }
+
         items_toyoda.sort_by(|x, y| x.e.partial_cmp(&y.e).unwrap());
         knapsack_order.sort_by(|x, y| x.s.partial_cmp(&y.s).unwrap());
 
@@ -176,14 +236,13 @@ impl<const S: usize> BoundedSolver<f64, S> for GeneralizedGreedy {
         //if split items are worth more flip items_loulou x variable
         if split_value > value {
             for item_info in items_loulou.iter_mut() {
-                if item_info.x {
-                    item_info.x = false;
-                } else {
-                    item_info.x = true;
-                }
+                item_info.x = !item_info.x;
             }
         }
 
+        stats.phase("senju-toyoda", start);
+        let start = Instant::now();
+
         //now calculate Loulou-Michaelides efficiency values on items_loulou.
         let mut cumulative_weights = [0.0; S];
         d_rating = [1.0; S];
@@ -215,7 +274,7 @@ impl<const S: usize> BoundedSolver<f64, S> for GeneralizedGreedy {
                 }
             }
 
-            item_info.e = items[item_info.j].value / v;
+            item_info.e = efficiency(items[item_info.j].value, v);
         }
 
         //rescore knapsack items
@@ -230,6 +289,14 @@ impl<const S: usize> BoundedSolver<f64, S> for GeneralizedGreedy {
         }
 
         //sort items and knapsacks into decreasing efficiency and and increasing score respectively.
+        if let Some(x) = items_loulou.iter().find(|x| x.e.is_nan()) {
+            return Err(KpError::IncomparableItem(x.j));
+        }
+
+        if let Some(x) = knapsack_order.iter().find(|x| x.s.is_nan()) {
+            return Err(KpError::IncomparableKnapsack(x.j));
+        }
+
         items_loulou.sort_by(|x, y| y.e.partial_cmp(&x.e).unwrap());
         knapsack_order.sort_by(|x, y| x.s.partial_cmp(&y.s).unwrap());
 
@@ -265,7 +332,11 @@ impl<const S: usize> BoundedSolver<f64, S> for GeneralizedGreedy {
             }
         }
 
+        stats.phase("loulou-michaelides", start);
+        let start = Instant::now();
+
         //now insert items_loulou objects into the knapsack.
+        let mut placed = vec![0.0; items.len()]; //copies of each item inserted
         k_i = 0;
         'item: for item_info in items_loulou {
             if value > split_value {
@@ -274,6 +345,7 @@ impl<const S: usize> BoundedSolver<f64, S> for GeneralizedGreedy {
                         value: items[item_info.j].value,
                         weights: items[item_info.j].weights,
                         quantity: 1.0,
+                        id: (),
                     }) {
                         k_i += 1;
 
@@ -281,19 +353,43 @@ impl<const S: usize> BoundedSolver<f64, S> for GeneralizedGreedy {
                             break 'item;
                         }
                     }
+
+                    placed[item_info.j] += 1.0;
                 }
             } else {
                 if !item_info.x {
-                    knapsacks[knapsack_order[k_i].j].add(Item::<f64, S> {
+                    if knapsacks[knapsack_order[k_i].j].add(Item::<f64, S> {
                         value: items[item_info.j].value,
                         weights: items[item_info.j].weights,
                         quantity: 1.0,
-                    });
+                        id: (),
+                    }) {
+                        placed[item_info.j] += 1.0;
+                    }
+
                     k_i += 1;
                 }
             }
         }
 
-        knapsacks
+        //copies left out that are too heavy for what remains of every knapsack
+        for (j, item) in items.iter().enumerate() {
+            let fits = knapsacks.iter().any(|knapsack| {
+                (0..S).all(|r| item.weights[r] <= knapsack.capacity[r] - knapsack.weights()[r])
+            });
+
+            if !fits {
+                stats.rejected += (item.quantity - placed[j]) as usize;
+            }
+        }
+
+        stats.phase("packing", start);
+        Ok(knapsacks)
+    }
+}
+
+impl SolverInfo for GeneralizedGreedy {
+    fn name(&self) -> &'static str {
+        "Generalized Greedy"
     }
 }
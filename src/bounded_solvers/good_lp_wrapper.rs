@@ -1,10 +1,43 @@
+use crate::error::KpError;
 use crate::item::Item;
 use crate::knapsack::ProblemKnapsacks;
+use crate::observer::Observer;
 use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
 use good_lp::{constraint, variable, variables, Expression, Solution, SolverModel, Variable};
+use std::time::Instant;
+
+#[cfg(feature = "cbc")]
+fn cbc_time_limit(
+    mut model: good_lp::solvers::coin_cbc::CoinCbcProblem,
+    seconds: f64,
+) -> good_lp::solvers::coin_cbc::CoinCbcProblem {
+    model.set_parameter("seconds", &seconds.to_string());
+    model
+}
+
+#[cfg(feature = "highs")]
+fn highs_time_limit(
+    model: good_lp::solvers::highs::HighsProblem,
+    seconds: f64,
+) -> good_lp::solvers::highs::HighsProblem {
+    model.set_time_limit(seconds)
+}
+
+//CPLEX takes its limits from the environment good_lp creates for it, which cannot be reached
+//from the model, so the deadline is ignored and a solve that runs past it reports the limit
+#[cfg(feature = "cplex")]
+fn cplex_time_limit(
+    model: good_lp::solvers::cplex::CPLEXProblem,
+    _seconds: f64,
+) -> good_lp::solvers::cplex::CPLEXProblem {
+    model
+}
 
 macro_rules! good_lp_wrapper {
-    ( $( [$solver_name:ident, $solver:expr] ),* ) => {
+    ( $( [$solver_name:ident, $solver:expr, $time_limit:path] ),* $(,)?) => {
         $(
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct $solver_name;
@@ -14,8 +47,63 @@ impl<const S: usize> BoundedSolver<f64, S> for $solver_name
 
     fn solve(self, problem: BoundedProblem<f64, S>)
     -> ProblemKnapsacks<f64, S> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_with(self, problem: BoundedProblem<f64, S>, control: &SolveControl)
+    -> ProblemKnapsacks<f64, S> {
+        self.try_solve_with(problem, control).unwrap()
+    }
+
+    //good_lp does not pass on the callbacks of its backends, so only the solution is
+    //reported. without a deadline or cancellation the backend solves to optimality, so its
+    //value is also the bound
+    fn solve_observed(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<ProblemKnapsacks<f64, S>>,
+    ) -> ProblemKnapsacks<f64, S> {
+        let knapsacks = self.try_solve_with(problem, control).unwrap();
+        observer.on_incumbent(&knapsacks);
+        if control.deadline.is_none() && !control.is_cancelled() {
+            observer.on_bound(knapsacks.value());
+        }
+
+        knapsacks
+    }
+
+    fn solve_stats_with(self, problem: BoundedProblem<f64, S>, control: &SolveControl)
+    -> (ProblemKnapsacks<f64, S>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(self, problem: BoundedProblem<f64, S>, control: &SolveControl)
+    -> Result<(ProblemKnapsacks<f64, S>, SolveStats), KpError> {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let knapsacks = self.try_solve_stats(problem, control, &mut stats)?;
+        stats.time = start.elapsed();
+        Ok((knapsacks, stats))
+    }
+}
+
+impl $solver_name {
+    pub(crate) fn try_solve_stats<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+        stats: &mut SolveStats,
+    ) -> Result<ProblemKnapsacks<f64, S>, KpError> {
+        problem.check_finite()?;
+
         let items = problem.items;
         let mut knapsacks = problem.knapsacks;
+        if control.is_cancelled() {
+            return Ok(knapsacks);
+        }
+
+        let start = Instant::now();
         let m = knapsacks.len();
         let n = items.len();
         let d = S;
@@ -61,7 +149,29 @@ impl<const S: usize> BoundedSolver<f64, S> for $solver_name
             model = model.with(constraint!(item_sum <= items[j].quantity));
         }
 
-        let solution = model.solve().unwrap();
+        stats.variables = m * n;
+        stats.constraints = m * d + n;
+        stats.phase("model", start);
+        let start = Instant::now();
+
+        //the backend stops at the deadline of the control with the best solution it found
+        if let Some(remaining) = control.remaining() {
+            model = $time_limit(model, remaining.as_secs_f64());
+        }
+
+        let solution = model
+            .solve()
+            .map_err(|x| KpError::Backend(x.to_string()))?;
+        stats.phase("solve", start);
+
+        //the backend solves to optimality unless it was stopped at the deadline
+        stats.status = if control.remaining().is_some_and(|x| x.is_zero()) {
+            Status::TimeLimit
+        } else {
+            Status::Optimal
+        };
+
+        let start = Instant::now();
         for i in 0..m {
             for j in 0..n {
                 let x_ij = solution.value(decision_var[i][j]).round();
@@ -70,20 +180,31 @@ impl<const S: usize> BoundedSolver<f64, S> for $solver_name
                         value: items[j].value,
                         weights: items[j].weights,
                         quantity: x_ij,
+                        id: (),
                     }
                 );
             }
         }
 
-        knapsacks
+        stats.phase("packing", start);
+        Ok(knapsacks)
+    }
+}
+
+impl SolverInfo for $solver_name {
+    fn name(&self) -> &'static str {
+        stringify!($solver_name)
     }
 }
         )*
     }
 }
 
-good_lp_wrapper!(
-    [CBC, good_lp::coin_cbc],
-    [HiGHS, good_lp::highs],
-    [CPLEX, good_lp::solvers::cplex::cplex]
-);
+#[cfg(feature = "cbc")]
+good_lp_wrapper!([CBC, good_lp::coin_cbc, cbc_time_limit],);
+
+#[cfg(feature = "highs")]
+good_lp_wrapper!([HiGHS, good_lp::highs, highs_time_limit],);
+
+#[cfg(feature = "cplex")]
+good_lp_wrapper!([CPLEX, good_lp::solvers::cplex::cplex, cplex_time_limit]);
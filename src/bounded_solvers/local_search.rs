@@ -0,0 +1,206 @@
+use crate::item::{Item, ProblemItems};
+use crate::knapsack::ProblemKnapsacks;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+//changes the local search solvers make to a solution, one copy of an item at a time
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Move {
+    Add { j: usize, k: usize },
+    Drop { j: usize, k: usize },
+    Swap { out: usize, into: usize, k: usize }, //drop a copy of out and add a copy of into
+    Shift { j: usize, from: usize, to: usize },
+}
+
+//copies of each item placed in each knapsack, on top of what the knapsacks held beforehand
+pub(crate) struct Assignment<const S: usize> {
+    pub items: Vec<Item<f64, S>>,
+    pub x: Vec<Vec<f64>>,     //copies of each item in each knapsack
+    pub free: Vec<f64>,       //copies of each item not placed
+    pub loads: Vec<[f64; S]>, //weight of each knapsack
+    pub capacities: Vec<[f64; S]>,
+    pub value: f64,
+}
+
+impl<const S: usize> Assignment<S> {
+    //copies of an item in the solution beyond those the problem's knapsacks already held are
+    //the ones the search may move
+    pub fn new(
+        items: ProblemItems<f64, S>,
+        knapsacks: &ProblemKnapsacks<f64, S>,
+        solution: &ProblemKnapsacks<f64, S>,
+    ) -> Self {
+        let items: Vec<Item<f64, S>> = items.into_iter().collect();
+        let mut x = vec![vec![0.0; items.len()]; solution.len()];
+        let mut free: Vec<f64> = items.iter().map(|item| item.quantity).collect();
+        let mut loads: Vec<[f64; S]> = Vec::with_capacity(solution.len());
+        let mut capacities: Vec<[f64; S]> = Vec::with_capacity(solution.len());
+        for (k, knapsack) in solution.iter().enumerate() {
+            loads.push(*knapsack.weights());
+            capacities.push(knapsack.capacity);
+            for (j, item) in items.iter().enumerate() {
+                let key = (item.value, item.weights);
+                let placed = knapsack.get_item(key).map_or(0.0, |x| x.quantity);
+                let held = knapsacks[k].get_item(key).map_or(0.0, |x| x.quantity);
+                x[k][j] = (placed - held).max(0.0);
+                free[j] -= x[k][j];
+            }
+        }
+
+        Self {
+            items: items,
+            x: x,
+            free: free,
+            loads: loads,
+            capacities: capacities,
+            value: solution.value(),
+        }
+    }
+
+    //the problem's knapsacks with the placed copies added
+    pub fn knapsacks(
+        &self,
+        x: &[Vec<f64>],
+        mut knapsacks: ProblemKnapsacks<f64, S>,
+    ) -> ProblemKnapsacks<f64, S> {
+        for (k, knapsack) in knapsacks.iter_mut().enumerate() {
+            for (j, item) in self.items.iter().enumerate() {
+                if x[k][j] > 0.0 {
+                    knapsack.add(Item::<f64, S> {
+                        value: item.value,
+                        weights: item.weights,
+                        quantity: x[k][j],
+                        id: (),
+                    });
+                }
+            }
+        }
+
+        knapsacks
+    }
+
+    fn fits(&self, k: usize, add: usize, remove: Option<usize>) -> bool {
+        (0..S).all(|r| {
+            let removed = remove.map_or(0.0, |j| self.items[j].weights[r]);
+            self.loads[k][r] - removed + self.items[add].weights[r] <= self.capacities[k][r]
+        })
+    }
+
+    pub fn is_feasible(&self, m: &Move) -> bool {
+        match *m {
+            Move::Add { j, k } => self.free[j] >= 1.0 && self.fits(k, j, None),
+            Move::Drop { j, k } => self.x[k][j] >= 1.0,
+            Move::Swap { out, into, k } => {
+                out != into
+                    && self.x[k][out] >= 1.0
+                    && self.free[into] >= 1.0
+                    && self.fits(k, into, Some(out))
+            }
+            Move::Shift { j, from, to } => {
+                from != to && self.x[from][j] >= 1.0 && self.fits(to, j, None)
+            }
+        }
+    }
+
+    pub fn delta(&self, m: &Move) -> f64 {
+        match *m {
+            Move::Add { j, .. } => self.items[j].value,
+            Move::Drop { j, .. } => -self.items[j].value,
+            Move::Swap { out, into, .. } => self.items[into].value - self.items[out].value,
+            Move::Shift { .. } => 0.0,
+        }
+    }
+
+    fn place(&mut self, j: usize, k: usize, copies: f64) {
+        self.x[k][j] += copies;
+        for r in 0..S {
+            self.loads[k][r] += self.items[j].weights[r] * copies;
+        }
+    }
+
+    pub fn apply(&mut self, m: &Move) {
+        self.value += self.delta(m);
+        match *m {
+            Move::Add { j, k } => {
+                self.place(j, k, 1.0);
+                self.free[j] -= 1.0;
+            }
+            Move::Drop { j, k } => {
+                self.place(j, k, -1.0);
+                self.free[j] += 1.0;
+            }
+            Move::Swap { out, into, k } => {
+                self.place(out, k, -1.0);
+                self.place(into, k, 1.0);
+                self.free[out] += 1.0;
+                self.free[into] -= 1.0;
+            }
+            Move::Shift { j, from, to } => {
+                self.place(j, from, -1.0);
+                self.place(j, to, 1.0);
+            }
+        }
+    }
+
+    //every feasible move, except that swaps only add one of the given number of most valuable
+    //items with copies left
+    pub fn neighbourhood(&self, candidates: usize) -> Vec<Move> {
+        let n = self.items.len();
+        let m = self.x.len();
+        let mut free: Vec<usize> = (0..n).filter(|j| self.free[*j] >= 1.0).collect();
+        free.sort_by(|x, y| self.items[*y].value.total_cmp(&self.items[*x].value));
+        free.truncate(candidates);
+
+        let mut moves: Vec<Move> = Vec::new();
+        for k in 0..m {
+            for j in 0..n {
+                moves.push(Move::Add { j: j, k: k });
+                if self.x[k][j] < 1.0 {
+                    continue;
+                }
+
+                moves.push(Move::Drop { j: j, k: k });
+                for i in free.iter() {
+                    moves.push(Move::Swap {
+                        out: j,
+                        into: *i,
+                        k: k,
+                    });
+                }
+
+                for to in 0..m {
+                    moves.push(Move::Shift {
+                        j: j,
+                        from: k,
+                        to: to,
+                    });
+                }
+            }
+        }
+
+        moves.retain(|m| self.is_feasible(m));
+        moves
+    }
+
+    //a random move, which may not be feasible
+    pub fn random_move(&self, rng: &mut StdRng) -> Move {
+        let n = self.items.len();
+        let m = self.x.len();
+        let j = rng.gen_range(0..n);
+        let k = rng.gen_range(0..m);
+        match rng.gen_range(0..4) {
+            0 => Move::Add { j: j, k: k },
+            1 => Move::Drop { j: j, k: k },
+            2 => Move::Swap {
+                out: j,
+                into: rng.gen_range(0..n),
+                k: k,
+            },
+            _ => Move::Shift {
+                j: j,
+                from: k,
+                to: rng.gen_range(0..m),
+            },
+        }
+    }
+}
@@ -1,15 +1,31 @@
+mod auto;
 mod dynamic;
+mod fptas;
 mod generalized_greedy;
 #[cfg(any(feature = "cbc", feature = "highs", feature = "cplex",))]
 mod good_lp_wrapper;
+#[cfg(feature = "metaheuristics")]
+mod local_search;
+mod nemhauser_ullmann;
+#[cfg(feature = "metaheuristics")]
+mod simulated_annealing;
+#[cfg(feature = "metaheuristics")]
+mod tabu_search;
 mod theoretical_greedy;
 
+pub use auto::{Auto, Choice};
 pub use dynamic::Dynamic;
+pub use fptas::Fptas;
 pub use generalized_greedy::GeneralizedGreedy;
 #[cfg(feature = "highs")]
 pub use good_lp_wrapper::HiGHS;
 #[cfg(feature = "cbc")]
 pub use good_lp_wrapper::CBC;
+pub use nemhauser_ullmann::{NemhauserUllmann, ParetoList};
+#[cfg(feature = "metaheuristics")]
+pub use simulated_annealing::SimulatedAnnealing;
+#[cfg(feature = "metaheuristics")]
+pub use tabu_search::TabuSearch;
 pub use theoretical_greedy::TheoreticalGreedy;
 //pub use good_lp_wrapper::scip;
 #[cfg(feature = "cplex")]
@@ -0,0 +1,271 @@
+use crate::compatible_problem_type_trait::CompatibleProblemType;
+use crate::item::Item;
+use crate::knapsack::{Knapsack, ProblemKnapsacks};
+use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solution::{Solution, SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::Instant;
+
+struct State<T, const S: usize> {
+    pub weights: [T; S],
+    pub value: f64,
+    pub item: usize,   //item whose copy was added to the parent
+    pub parent: usize, //state the copy was added to
+}
+
+const ROOT: usize = usize::MAX;
+
+//every non-dominated (weights, value) pair of a problem, sorted by increasing value.
+//a pair is dominated if another weighs no more in every dimension and is worth at least as much
+pub struct ParetoList<T, const S: usize>
+where
+    T: CompatibleProblemType,
+{
+    items: Vec<Item<T, S>>,
+    states: Vec<State<T, S>>, //every state created, so the items of a pair can be found
+    front: Vec<usize>,
+    stats: SolveStats, //of building the list
+}
+
+impl<T, const S: usize> ParetoList<T, S>
+where
+    T: CompatibleProblemType,
+{
+    pub fn len(&self) -> usize {
+        self.front.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty()
+    }
+
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = ([T; S], f64)> + 'a {
+        self.front
+            .iter()
+            .map(|s| (self.states[*s].weights, self.states[*s].value))
+    }
+
+    //most valuable pair that fits in the capacity
+    pub fn best(&self, capacity: [T; S]) -> Option<([T; S], f64)> {
+        self.best_index(capacity)
+            .map(|s| (self.states[s].weights, self.states[s].value))
+    }
+
+    //a knapsack with the capacity holding the items of the most valuable pair that fits
+    pub fn knapsacks(&self, capacity: [T; S]) -> ProblemKnapsacks<T, S> {
+        let mut knapsack = Knapsack::<T, S>::new(capacity);
+        let mut quantity = vec![T::default(); self.items.len()];
+        let mut s = self.best_index(capacity).unwrap_or(ROOT);
+        while s != ROOT && self.states[s].item != ROOT {
+            quantity[self.states[s].item] += T::identity();
+            s = self.states[s].parent;
+        }
+
+        for (item, quantity) in self.items.iter().zip(quantity) {
+            if quantity > T::default() {
+                knapsack.add(Item::<T, S> {
+                    value: item.value,
+                    weights: item.weights,
+                    quantity: quantity,
+                    id: (),
+                });
+            }
+        }
+
+        let mut problem_knapsacks = ProblemKnapsacks::<T, S>::new();
+        problem_knapsacks.add(knapsack);
+
+        problem_knapsacks
+    }
+
+    //the knapsack of the capacity as an optimal solution, found in the time the list took
+    //to build
+    pub fn solution(&self, capacity: [T; S]) -> Solution<ProblemKnapsacks<T, S>> {
+        Solution::from_stats(
+            self.knapsacks(capacity),
+            self.stats.clone(),
+            NemhauserUllmann.name(),
+        )
+    }
+
+    fn best_index(&self, capacity: [T; S]) -> Option<usize> {
+        self.front
+            .iter()
+            .rev()
+            .find(|s| (0..S).all(|r| self.states[**s].weights[r] <= capacity[r]))
+            .copied()
+    }
+}
+
+//stops adding copies once the control says so, returning whether it did
+fn pareto_list<T, const S: usize>(
+    items: Vec<Item<T, S>>,
+    control: &SolveControl,
+) -> (ParetoList<T, S>, bool)
+where
+    T: CompatibleProblemType,
+{
+    let mut states: Vec<State<T, S>> = vec![State {
+        weights: [T::default(); S],
+        value: 0.0,
+        item: ROOT,
+        parent: ROOT,
+    }];
+
+    //add one copy at a time, merging the front with a copy of itself shifted by the item.
+    //the shifted states are only kept in states if they survive, so every parent link
+    //leads to a state that was once on the front
+    let mut front: Vec<usize> = vec![0];
+    let mut copies = 0;
+    let mut stopped = false;
+    'items: for (j, item) in items.iter().enumerate() {
+        if item.value <= 0.0 {
+            continue;
+        }
+
+        let mut copy = T::default();
+        while copy < item.quantity {
+            if control.should_stop(copies) {
+                stopped = true;
+                break 'items;
+            }
+
+            copy += T::identity();
+            copies += 1;
+
+            //candidates at or past the end of states are shifted, indexing into shifted
+            let mut shifted: Vec<State<T, S>> = Vec::with_capacity(front.len());
+            for s in front.iter() {
+                let mut weights = states[*s].weights;
+                for r in 0..S {
+                    weights[r] += item.weights[r];
+                }
+
+                shifted.push(State {
+                    weights: weights,
+                    value: states[*s].value + item.value,
+                    item: j,
+                    parent: *s,
+                });
+            }
+
+            let end = states.len();
+            let state = |c: usize| {
+                if c < end {
+                    &states[c]
+                } else {
+                    &shifted[c - end]
+                }
+            };
+
+            //most valuable first, so a candidate is dominated iff a kept state weighs
+            //no more than it in every dimension
+            let mut candidates: Vec<usize> = front.clone();
+            candidates.extend(end..end + shifted.len());
+            candidates.sort_by(|x, y| {
+                let (x, y) = (state(*x), state(*y));
+                y.value.partial_cmp(&x.value).unwrap().then_with(|| {
+                    x.weights
+                        .partial_cmp(&y.weights)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+            });
+
+            let mut kept: Vec<usize> = Vec::with_capacity(candidates.len());
+            for c in candidates {
+                let dominated = if S == 1 {
+                    //kept weights strictly decrease so the last is the lightest
+                    kept.last()
+                        .is_some_and(|k| state(*k).weights[0] <= state(c).weights[0])
+                } else {
+                    kept.iter()
+                        .any(|k| (0..S).all(|r| state(*k).weights[r] <= state(c).weights[r]))
+                };
+
+                if !dominated {
+                    kept.push(c);
+                }
+            }
+
+            //move the surviving shifted states into states
+            let mut shifted: Vec<Option<State<T, S>>> = shifted.into_iter().map(Some).collect();
+            for k in kept.iter_mut() {
+                if *k >= end {
+                    states.push(shifted[*k - end].take().unwrap());
+                    *k = states.len() - 1;
+                }
+            }
+
+            kept.reverse();
+            front = kept;
+        }
+    }
+
+    let list = ParetoList {
+        items: items,
+        states: states,
+        front: front,
+        stats: SolveStats::default(),
+    };
+
+    (list, stopped)
+}
+
+macro_rules! nemhauser_ullmann_impl {
+    ( $( $type:ty ),* ) => {
+        $(
+impl<const S: usize> BoundedSolver<$type, S> for NemhauserUllmann {
+    type Output = ParetoList<$type, S>;
+
+    fn solve(self, problem: BoundedProblem<$type, S>) -> ParetoList<$type, S> {
+        self.solve_stats(problem).0
+    }
+
+    fn solve_with(
+        self,
+        problem: BoundedProblem<$type, S>,
+        control: &SolveControl,
+    ) -> ParetoList<$type, S> {
+        self.solve_stats_with(problem, control).0
+    }
+
+    //every knapsack filled from the list is optimal for its capacity, unless the control
+    //stopped the list before every copy was added
+    fn solve_stats_with(
+        self,
+        problem: BoundedProblem<$type, S>,
+        control: &SolveControl,
+    ) -> (ParetoList<$type, S>, SolveStats) {
+        let start = Instant::now();
+        let (mut list, stopped) = pareto_list(problem.items.into_iter().collect(), control);
+        let mut stats = SolveStats::default();
+        stats.states = list.states.len();
+        stats.status = if stopped {
+            control.stopped_status()
+        } else {
+            Status::Optimal
+        };
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+        list.stats = stats.clone();
+        (list, stats)
+    }
+}
+        )*
+    }
+}
+
+//builds the Pareto list of Nemhauser and Ullmann for a single knapsack, keeping every
+//non-dominated state rather than one per capacity. the problem's knapsacks are ignored;
+//the returned list can fill a knapsack of any capacity without solving again.
+//the control is checked before each copy is added
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NemhauserUllmann;
+nemhauser_ullmann_impl!(u32, f64);
+
+impl SolverInfo for NemhauserUllmann {
+    fn name(&self) -> &'static str {
+        "Nemhauser-Ullmann"
+    }
+}
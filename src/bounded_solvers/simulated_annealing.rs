@@ -0,0 +1,203 @@
+use super::generalized_greedy::GeneralizedGreedy;
+use super::local_search::Assignment;
+use crate::knapsack::ProblemKnapsacks;
+use crate::observer::Observer;
+use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::time::{Duration, Instant};
+
+//local search that accepts worse solutions with a probability that falls as the
+//temperature cools. each iteration tries a random move: adding, dropping or swapping a copy
+//of an item, or moving a copy to another knapsack.
+//stops after the number of iterations or once the time limit has passed, whichever is first.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SimulatedAnnealing {
+    pub iterations: usize,
+    pub time_limit: Option<Duration>,
+    pub seed: u64,
+}
+
+impl SimulatedAnnealing {
+    pub fn new(iterations: usize, time_limit: Option<Duration>, seed: u64) -> Self {
+        Self {
+            iterations: iterations,
+            time_limit: time_limit,
+            seed: seed,
+        }
+    }
+
+    //improve a feasible solution of the problem
+    pub fn improve<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        initial_solution: ProblemKnapsacks<f64, S>,
+    ) -> ProblemKnapsacks<f64, S> {
+        self.improve_with(problem, initial_solution, &SolveControl::default())
+    }
+
+    //improve a feasible solution of the problem, stopping early once a limit of the control
+    //is reached
+    pub fn improve_with<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        initial_solution: ProblemKnapsacks<f64, S>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<f64, S> {
+        self.improve_observed(problem, initial_solution, control, &mut ())
+    }
+
+    //improve a feasible solution of the problem, reporting to the observer every iteration
+    //and every solution better than those before it, starting with the initial solution
+    pub fn improve_observed<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        initial_solution: ProblemKnapsacks<f64, S>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<ProblemKnapsacks<f64, S>>,
+    ) -> ProblemKnapsacks<f64, S> {
+        self.search(problem, initial_solution, control, observer).0
+    }
+
+    //the search of improve_observed, also returning whether it stopped at a time limit
+    fn search<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        initial_solution: ProblemKnapsacks<f64, S>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<ProblemKnapsacks<f64, S>>,
+    ) -> (ProblemKnapsacks<f64, S>, Status) {
+        observer.on_incumbent(&initial_solution);
+        let mut assignment = Assignment::new(problem.items, &problem.knapsacks, &initial_solution);
+        if assignment.items.is_empty() || assignment.x.is_empty() {
+            return (initial_solution, Status::Feasible);
+        }
+
+        //start at the mean profit of an item and cool to a thousandth of it
+        let mut t_0 = 0.0;
+        for item in assignment.items.iter() {
+            t_0 += item.value.abs();
+        }
+
+        t_0 /= assignment.items.len() as f64;
+
+        //limits of the control shorten the schedule so the temperature still cools
+        let iterations = control
+            .iteration_limit
+            .map_or(self.iterations, |x| x.min(self.iterations));
+        let time_limit = match (self.time_limit, control.remaining()) {
+            (Some(x), Some(y)) => Some(x.min(y)),
+            (x, y) => x.or(y),
+        };
+
+        let start = Instant::now();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut best_x = assignment.x.clone();
+        let mut best_value = assignment.value;
+        let mut status = Status::Feasible;
+        for iteration in 0..iterations {
+            if control.is_cancelled() {
+                status = control.stopped_status();
+                break;
+            }
+
+            let mut progress = iteration as f64 / iterations as f64;
+            if let Some(time_limit) = time_limit {
+                let elapsed = start.elapsed();
+                if elapsed >= time_limit {
+                    status = Status::TimeLimit;
+                    break;
+                }
+
+                progress = progress.max(elapsed.as_secs_f64() / time_limit.as_secs_f64());
+            }
+
+            observer.on_progress(iteration, start.elapsed());
+            let m = assignment.random_move(&mut rng);
+            if !assignment.is_feasible(&m) {
+                continue;
+            }
+
+            let t = t_0 * 0.001_f64.powf(progress);
+            let delta = assignment.delta(&m);
+            if delta >= 0.0 || rng.gen::<f64>() < (delta / t).exp() {
+                assignment.apply(&m);
+                if assignment.value > best_value {
+                    best_value = assignment.value;
+                    best_x.clone_from(&assignment.x);
+                    observer
+                        .on_incumbent(&assignment.knapsacks(&best_x, problem.knapsacks.clone()));
+                }
+            }
+        }
+
+        (assignment.knapsacks(&best_x, problem.knapsacks), status)
+    }
+}
+
+impl Default for SimulatedAnnealing {
+    fn default() -> Self {
+        Self::new(100000, None, 0)
+    }
+}
+
+//starts from the solution of GeneralizedGreedy
+impl<const S: usize> BoundedSolver<f64, S> for SimulatedAnnealing {
+    type Output = ProblemKnapsacks<f64, S>;
+
+    fn solve(self, problem: BoundedProblem<f64, S>) -> ProblemKnapsacks<f64, S> {
+        self.solve_with(problem, &SolveControl::default())
+    }
+
+    fn solve_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<f64, S> {
+        self.solve_observed(problem, control, &mut ())
+    }
+
+    fn solve_observed(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<ProblemKnapsacks<f64, S>>,
+    ) -> ProblemKnapsacks<f64, S> {
+        let initial_solution = GeneralizedGreedy.solve(BoundedProblem::<f64, S> {
+            items: problem.items.clone(),
+            knapsacks: problem.knapsacks.clone(),
+        });
+
+        self.improve_observed(problem, initial_solution, control, observer)
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<f64, S>, SolveStats) {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let initial_solution = GeneralizedGreedy.solve(BoundedProblem::<f64, S> {
+            items: problem.items.clone(),
+            knapsacks: problem.knapsacks.clone(),
+        });
+
+        stats.phase("greedy", start);
+        let search_start = Instant::now();
+        let (knapsacks, status) = self.search(problem, initial_solution, control, &mut ());
+        stats.phase("search", search_start);
+        stats.status = status;
+        stats.time = start.elapsed();
+        (knapsacks, stats)
+    }
+}
+
+impl SolverInfo for SimulatedAnnealing {
+    fn name(&self) -> &'static str {
+        "Simulated Annealing"
+    }
+}
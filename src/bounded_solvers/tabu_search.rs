@@ -0,0 +1,209 @@
+use super::generalized_greedy::GeneralizedGreedy;
+use super::local_search::{Assignment, Move};
+use crate::knapsack::ProblemKnapsacks;
+use crate::observer::Observer;
+use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::{Duration, Instant};
+
+//number of the most valuable items left that swaps may add
+const CANDIDATES: usize = 16;
+
+//local search that takes the best move of the neighbourhood each iteration, even if it makes
+//the solution worse. swaps only add one of the CANDIDATES most valuable items left, so an
+//iteration is not quadratic in the number of items. an item whose copies were moved in or out of a knapsack may
+//not be moved there again for tenure iterations unless that finds a new best solution.
+//stops after the number of iterations or once the time limit has passed, whichever is first.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TabuSearch {
+    pub iterations: usize,
+    pub time_limit: Option<Duration>,
+    pub tenure: usize,
+}
+
+impl TabuSearch {
+    pub fn new(iterations: usize, time_limit: Option<Duration>, tenure: usize) -> Self {
+        Self {
+            iterations: iterations,
+            time_limit: time_limit,
+            tenure: tenure,
+        }
+    }
+
+    //improve a feasible solution of the problem
+    pub fn improve<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        initial_solution: ProblemKnapsacks<f64, S>,
+    ) -> ProblemKnapsacks<f64, S> {
+        self.improve_with(problem, initial_solution, &SolveControl::default())
+    }
+
+    //improve a feasible solution of the problem, stopping early once a limit of the control
+    //is reached
+    pub fn improve_with<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        initial_solution: ProblemKnapsacks<f64, S>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<f64, S> {
+        self.improve_observed(problem, initial_solution, control, &mut ())
+    }
+
+    //improve a feasible solution of the problem, reporting to the observer every iteration
+    //and every solution better than those before it, starting with the initial solution
+    pub fn improve_observed<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        initial_solution: ProblemKnapsacks<f64, S>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<ProblemKnapsacks<f64, S>>,
+    ) -> ProblemKnapsacks<f64, S> {
+        self.search(problem, initial_solution, control, observer).0
+    }
+
+    //the search of improve_observed, also returning whether it stopped at a time limit
+    fn search<const S: usize>(
+        self,
+        problem: BoundedProblem<f64, S>,
+        initial_solution: ProblemKnapsacks<f64, S>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<ProblemKnapsacks<f64, S>>,
+    ) -> (ProblemKnapsacks<f64, S>, Status) {
+        observer.on_incumbent(&initial_solution);
+        let mut assignment = Assignment::new(problem.items, &problem.knapsacks, &initial_solution);
+        if assignment.items.is_empty() || assignment.x.is_empty() {
+            return (initial_solution, Status::Feasible);
+        }
+
+        //iteration until which each item may not be moved in or out of each knapsack
+        let mut tabu = vec![vec![0_usize; assignment.items.len()]; assignment.x.len()];
+        let is_tabu = |tabu: &[Vec<usize>], m: &Move, iteration: usize| match *m {
+            Move::Add { j, k } | Move::Drop { j, k } => tabu[k][j] > iteration,
+            Move::Swap { out, into, k } => tabu[k][out] > iteration || tabu[k][into] > iteration,
+            Move::Shift { j, from, to } => tabu[from][j] > iteration || tabu[to][j] > iteration,
+        };
+
+        let start = Instant::now();
+        let mut best_x = assignment.x.clone();
+        let mut best_value = assignment.value;
+        let mut status = Status::Feasible;
+        for iteration in 0..self.iterations {
+            if self.time_limit.is_some_and(|x| start.elapsed() >= x) {
+                status = Status::TimeLimit;
+                break;
+            }
+
+            if control.should_stop(iteration) {
+                status = control.stopped_status();
+                break;
+            }
+
+            let mut chosen: Option<(Move, f64)> = None;
+            for m in assignment.neighbourhood(CANDIDATES) {
+                let delta = assignment.delta(&m);
+                if is_tabu(&tabu, &m, iteration) && assignment.value + delta <= best_value {
+                    continue;
+                }
+
+                if chosen.is_none_or(|(_, x)| delta > x) {
+                    chosen = Some((m, delta));
+                }
+            }
+
+            let Some((m, _)) = chosen else {
+                break;
+            };
+
+            assignment.apply(&m);
+            let until = iteration + 1 + self.tenure;
+            match m {
+                Move::Add { j, k } | Move::Drop { j, k } => tabu[k][j] = until,
+                Move::Swap { out, into, k } => {
+                    tabu[k][out] = until;
+                    tabu[k][into] = until;
+                }
+                Move::Shift { j, from, to } => {
+                    tabu[from][j] = until;
+                    tabu[to][j] = until;
+                }
+            }
+
+            if assignment.value > best_value {
+                best_value = assignment.value;
+                best_x.clone_from(&assignment.x);
+                observer.on_incumbent(&assignment.knapsacks(&best_x, problem.knapsacks.clone()));
+            }
+
+            observer.on_progress(iteration + 1, start.elapsed());
+        }
+
+        (assignment.knapsacks(&best_x, problem.knapsacks), status)
+    }
+}
+
+impl Default for TabuSearch {
+    fn default() -> Self {
+        Self::new(1000, None, 7)
+    }
+}
+
+//starts from the solution of GeneralizedGreedy
+impl<const S: usize> BoundedSolver<f64, S> for TabuSearch {
+    type Output = ProblemKnapsacks<f64, S>;
+
+    fn solve(self, problem: BoundedProblem<f64, S>) -> ProblemKnapsacks<f64, S> {
+        self.solve_with(problem, &SolveControl::default())
+    }
+
+    fn solve_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<f64, S> {
+        self.solve_observed(problem, control, &mut ())
+    }
+
+    fn solve_observed(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<ProblemKnapsacks<f64, S>>,
+    ) -> ProblemKnapsacks<f64, S> {
+        let initial_solution = GeneralizedGreedy.solve(BoundedProblem::<f64, S> {
+            items: problem.items.clone(),
+            knapsacks: problem.knapsacks.clone(),
+        });
+
+        self.improve_observed(problem, initial_solution, control, observer)
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<f64, S>, SolveStats) {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let initial_solution = GeneralizedGreedy.solve(BoundedProblem::<f64, S> {
+            items: problem.items.clone(),
+            knapsacks: problem.knapsacks.clone(),
+        });
+
+        stats.phase("greedy", start);
+        let search_start = Instant::now();
+        let (knapsacks, status) = self.search(problem, initial_solution, control, &mut ());
+        stats.phase("search", search_start);
+        stats.status = status;
+        stats.time = start.elapsed();
+        (knapsacks, stats)
+    }
+}
+
+impl SolverInfo for TabuSearch {
+    fn name(&self) -> &'static str {
+        "Tabu Search"
+    }
+}
@@ -1,7 +1,12 @@
+use crate::error::KpError;
 use crate::item::Item;
 use crate::knapsack::ProblemKnapsacks;
 use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solution::SolverInfo;
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
 use minilp::{ComparisonOp, OptimizationDirection, Problem};
+use std::time::Instant;
 
 struct ItemPos<const S: usize> {
     pub j: usize,        //index
@@ -14,7 +19,40 @@ pub struct TheoreticalGreedy;
 impl<const S: usize> BoundedSolver<f64, S> for TheoreticalGreedy {
     type Output = ProblemKnapsacks<f64, S>;
 
-    fn solve(self, mut problem: BoundedProblem<f64, S>) -> ProblemKnapsacks<f64, S> {
+    fn solve(self, problem: BoundedProblem<f64, S>) -> ProblemKnapsacks<f64, S> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<f64, S>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BoundedProblem<f64, S>,
+        _control: &SolveControl,
+    ) -> Result<(ProblemKnapsacks<f64, S>, SolveStats), KpError> {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let output = self.try_solve_stats(problem, &mut stats)?;
+        stats.time = start.elapsed();
+        Ok((output, stats))
+    }
+}
+
+impl TheoreticalGreedy {
+    fn try_solve_stats<const S: usize>(
+        self,
+        mut problem: BoundedProblem<f64, S>,
+        stats: &mut SolveStats,
+    ) -> Result<ProblemKnapsacks<f64, S>, KpError> {
+        problem.check_finite()?;
+
+        let start = Instant::now();
         let items = problem.items;
         let knapsack = &mut problem.knapsacks[0];
 
@@ -78,7 +116,13 @@ impl<const S: usize> BoundedSolver<f64, S> for TheoreticalGreedy {
         }
 
         //solve dual problem using simplex algorithm
-        let solution = dual_problem.solve().unwrap();
+        let solution = dual_problem
+            .solve()
+            .map_err(|x| KpError::Backend(x.to_string()))?;
+        stats.variables = variables.len();
+        stats.constraints = total_items;
+        stats.phase("linear program", start);
+        let start = Instant::now();
 
         //set up normal vector of hyperplane using optimal relevance values found above
         let mut hyperplane_norm: Vec<f64> = Vec::with_capacity(S);
@@ -103,7 +147,13 @@ impl<const S: usize> BoundedSolver<f64, S> for TheoreticalGreedy {
         }
 
         //now sort objects in increasing order based on distance
+        if let Some(x) = item_positions.iter().find(|x| x.dist.is_nan()) {
+            return Err(KpError::IncomparableItem(x.j));
+        }
+
         item_positions.sort_by(|x, y| x.dist.partial_cmp(&y.dist).unwrap());
+        stats.phase("ordering", start);
+        let start = Instant::now();
 
         //now add objects to knapsack
         for item_pos in item_positions {
@@ -120,9 +170,17 @@ impl<const S: usize> BoundedSolver<f64, S> for TheoreticalGreedy {
                 value: items[item_pos.j].value,
                 weights: items[item_pos.j].weights,
                 quantity: can_fit as f64,
+                id: (),
             });
         }
 
-        problem.knapsacks
+        stats.phase("packing", start);
+        Ok(problem.knapsacks)
+    }
+}
+
+impl SolverInfo for TheoreticalGreedy {
+    fn name(&self) -> &'static str {
+        "Theoretical Greedy"
     }
 }
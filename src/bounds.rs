@@ -0,0 +1,320 @@
+use crate::binary_solvers::minknap;
+use crate::error::KpError;
+use crate::problem_type::BoundedProblem;
+use crate::solution::Objective;
+use minilp::{ComparisonOp, OptimizationDirection, Problem};
+
+//upper bounds on the optimal value of a problem, including the value the knapsacks already
+//hold, so the optimality gap of any solution can be reported. each returns an error if the
+//linear relaxation they start from cannot be solved
+
+//optimal value of the dual along with its multipliers
+type Dual<const S: usize> = (f64, Vec<[f64; S]>, Vec<f64>);
+
+struct Relaxation<const S: usize> {
+    pub profits: Vec<f64>,
+    pub weights: Vec<[f64; S]>,
+    pub quantities: Vec<f64>,
+    pub capacities: Vec<[f64; S]>, //remaining capacity of each knapsack
+    pub value: f64,                //value already in the knapsacks
+}
+
+impl<const S: usize> Relaxation<S> {
+    fn new(problem: &BoundedProblem<f64, S>) -> Self {
+        let mut relaxation = Self {
+            profits: Vec::with_capacity(problem.items.len()),
+            weights: Vec::with_capacity(problem.items.len()),
+            quantities: Vec::with_capacity(problem.items.len()),
+            capacities: Vec::with_capacity(problem.knapsacks.len()),
+            value: problem.knapsacks.value(),
+        };
+
+        //items that add nothing are never part of an optimal solution
+        for item in problem.items.iter() {
+            if item.value > 0.0 && item.quantity > 0.0 {
+                relaxation.profits.push(item.value);
+                relaxation.weights.push(item.weights);
+                relaxation.quantities.push(item.quantity.floor());
+            }
+        }
+
+        for knapsack in problem.knapsacks.iter() {
+            let mut capacity = [0.0; S];
+            for r in 0..S {
+                capacity[r] = (knapsack.capacity[r] - knapsack.weights()[r]).max(0.0);
+            }
+
+            relaxation.capacities.push(capacity);
+        }
+
+        relaxation
+    }
+
+    //optimal value of the dual of the linear relaxation, along with the multipliers of each
+    //capacity constraint and of the number of copies of each item
+    fn dual(&self) -> Result<Dual<S>, KpError> {
+        let mut dual_problem = Problem::new(OptimizationDirection::Minimize);
+        let mut mu: Vec<[minilp::Variable; S]> = Vec::with_capacity(self.capacities.len());
+        for capacity in self.capacities.iter() {
+            mu.push(capacity.map(|c| dual_problem.add_var(c, (0.0, f64::INFINITY))));
+        }
+
+        let lambda: Vec<minilp::Variable> = self
+            .quantities
+            .iter()
+            .map(|q| dual_problem.add_var(*q, (0.0, f64::INFINITY)))
+            .collect();
+
+        for k in 0..self.capacities.len() {
+            for j in 0..self.profits.len() {
+                let mut formula: Vec<(minilp::Variable, f64)> = Vec::with_capacity(S + 1);
+                for r in 0..S {
+                    formula.push((mu[k][r], self.weights[j][r]));
+                }
+
+                formula.push((lambda[j], 1.0));
+                dual_problem.add_constraint(&formula, ComparisonOp::Ge, self.profits[j]);
+            }
+        }
+
+        let solution = dual_problem
+            .solve()
+            .map_err(|e| KpError::Backend(e.to_string()))?;
+
+        Ok((
+            solution.objective(),
+            mu.iter().map(|x| x.map(|v| solution[v])).collect(),
+            lambda.iter().map(|v| solution[*v]).collect(),
+        ))
+    }
+}
+
+//optimal value of a single knapsack with one dimension, where each item has a number of copies.
+//copies are grouped into bundles of 1, 2, 4, ... so the problem can be solved as a binary one
+fn knapsack_value(profits: &[f64], weights: &[f64], quantities: &[f64], c: f64) -> (f64, Vec<f64>) {
+    let mut bundle_j: Vec<usize> = Vec::new();
+    let mut bundle_count: Vec<f64> = Vec::new();
+    let mut bundle_p: Vec<f64> = Vec::new();
+    let mut bundle_w: Vec<f64> = Vec::new();
+    for j in 0..profits.len() {
+        if profits[j] <= 0.0 || weights[j] > c {
+            continue;
+        }
+
+        let mut remaining = if weights[j] > 0.0 {
+            quantities[j].min((c / weights[j]).floor())
+        } else {
+            quantities[j]
+        };
+
+        let mut count = 1.0;
+        while remaining > 0.0 {
+            let count_min = f64::min(count, remaining);
+            bundle_j.push(j);
+            bundle_count.push(count_min);
+            bundle_p.push(profits[j] * count_min);
+            bundle_w.push(weights[j] * count_min);
+            remaining -= count_min;
+            count *= 2.0;
+        }
+    }
+
+    let solution = minknap(&bundle_p, &bundle_w, c);
+    let mut value = 0.0;
+    let mut x = vec![0.0; profits.len()];
+    for (b, packed) in solution.iter().enumerate() {
+        if *packed {
+            value += bundle_p[b];
+            x[bundle_j[b]] += bundle_count[b];
+        }
+    }
+
+    (value, x)
+}
+
+//bound of the linear relaxation, where any fraction of an item may be packed.
+//for a single knapsack with one dimension this is the Dantzig bound
+pub fn linear<const S: usize>(problem: &BoundedProblem<f64, S>) -> Result<f64, KpError> {
+    let relaxation = Relaxation::new(problem);
+    if relaxation.profits.is_empty() || relaxation.capacities.is_empty() {
+        return Ok(relaxation.value);
+    }
+
+    Ok(relaxation.value + relaxation.dual()?.0)
+}
+
+//bound of the surrogate relaxation, which merges every capacity constraint of every knapsack
+//into one using the optimal dual multipliers of the linear relaxation as weights, then
+//solves the resulting knapsack exactly. the multipliers of each dimension are summed over the
+//knapsacks, so with several knapsacks the bound is capped by the linear one
+pub fn surrogate<const S: usize>(problem: &BoundedProblem<f64, S>) -> Result<f64, KpError> {
+    let relaxation = Relaxation::new(problem);
+    if relaxation.profits.is_empty() || relaxation.capacities.is_empty() {
+        return Ok(relaxation.value);
+    }
+
+    let (linear_bound, mu, _) = relaxation.dual()?;
+    let mut sigma = [0.0; S];
+    for mu_k in mu.iter() {
+        for r in 0..S {
+            sigma[r] += mu_k[r];
+        }
+    }
+
+    //only the ratio between multipliers matters
+    let sigma_max = sigma.iter().fold(0.0, |x: f64, y| x.max(*y));
+    if sigma_max > 0.0 {
+        sigma = sigma.map(|x| x / sigma_max);
+    } else {
+        sigma = [1.0; S];
+    }
+
+    //items that fit in no knapsack are left out
+    let mut weights: Vec<f64> = Vec::with_capacity(relaxation.profits.len());
+    let mut quantities: Vec<f64> = Vec::with_capacity(relaxation.profits.len());
+    for j in 0..relaxation.profits.len() {
+        let fits = relaxation
+            .capacities
+            .iter()
+            .any(|c| (0..S).all(|r| relaxation.weights[j][r] <= c[r]));
+
+        weights.push((0..S).map(|r| sigma[r] * relaxation.weights[j][r]).sum());
+        quantities.push(if fits { relaxation.quantities[j] } else { 0.0 });
+    }
+
+    let mut c = 0.0;
+    for capacity in relaxation.capacities.iter() {
+        for r in 0..S {
+            c += sigma[r] * capacity[r];
+        }
+    }
+
+    //allow for rounding when solutions fill a knapsack exactly
+    c *= 1.0 + 1e-9;
+    let (value, _) = knapsack_value(&relaxation.profits, &weights, &quantities, c);
+    Ok(relaxation.value + value.min(linear_bound))
+}
+
+//bound of the Lagrangian relaxation, which moves every capacity constraint but the first
+//dimension of each knapsack, and the number of copies of each item shared between knapsacks,
+//into the objective. each knapsack then becomes a separate one dimensional problem.
+//the multipliers start from the dual of the linear relaxation and are improved by subgradient
+//optimization. the bound of the first multipliers is at most the linear bound, and the best
+//bound of any multipliers is returned
+pub fn lagrangian<const S: usize>(problem: &BoundedProblem<f64, S>) -> Result<f64, KpError> {
+    let relaxation = Relaxation::new(problem);
+    if relaxation.profits.is_empty() || relaxation.capacities.is_empty() {
+        return Ok(relaxation.value);
+    }
+
+    let n = relaxation.profits.len();
+    let m = relaxation.capacities.len();
+    let (_, mut mu, mut lambda) = relaxation.dual()?;
+
+    //with a single knapsack the number of copies stays a constraint of the subproblem
+    if m == 1 {
+        lambda = vec![0.0; n];
+    }
+
+    let mut best = f64::INFINITY;
+    let mut theta = 2.0;
+    let mut stall = 0;
+    for _ in 0..100 {
+        let mut bound = 0.0;
+        let mut subgradient_mu = vec![[0.0; S]; m];
+        let mut subgradient_lambda = relaxation.quantities.clone();
+        for k in 0..m {
+            let mut profits: Vec<f64> = Vec::with_capacity(n);
+            let mut weights: Vec<f64> = Vec::with_capacity(n);
+            let mut quantities: Vec<f64> = Vec::with_capacity(n);
+            for j in 0..n {
+                let mut profit = relaxation.profits[j] - lambda[j];
+                for r in 1..S {
+                    profit -= mu[k][r] * relaxation.weights[j][r];
+                }
+
+                let fits = (0..S).all(|r| relaxation.weights[j][r] <= relaxation.capacities[k][r]);
+                profits.push(profit);
+                weights.push(relaxation.weights[j][0]);
+                quantities.push(if fits { relaxation.quantities[j] } else { 0.0 });
+            }
+
+            let (value, x) =
+                knapsack_value(&profits, &weights, &quantities, relaxation.capacities[k][0]);
+            bound += value;
+            for r in 1..S {
+                bound += mu[k][r] * relaxation.capacities[k][r];
+                subgradient_mu[k][r] = relaxation.capacities[k][r];
+                for j in 0..n {
+                    subgradient_mu[k][r] -= relaxation.weights[j][r] * x[j];
+                }
+            }
+
+            for j in 0..n {
+                subgradient_lambda[j] -= x[j];
+            }
+        }
+
+        if m > 1 {
+            for j in 0..n {
+                bound += lambda[j] * relaxation.quantities[j];
+            }
+        }
+
+        if bound < best {
+            best = bound;
+            stall = 0;
+        } else {
+            stall += 1;
+            if stall == 10 {
+                theta /= 2.0;
+                stall = 0;
+            }
+        }
+
+        //step against the subgradient, keeping the multipliers non-negative
+        let mut norm = 0.0;
+        for k in 0..m {
+            for r in 1..S {
+                norm += subgradient_mu[k][r] * subgradient_mu[k][r];
+            }
+        }
+
+        if m > 1 {
+            for j in 0..n {
+                norm += subgradient_lambda[j] * subgradient_lambda[j];
+            }
+        }
+
+        if norm == 0.0 {
+            break;
+        }
+
+        let step = theta * bound / norm;
+        for k in 0..m {
+            for r in 1..S {
+                mu[k][r] = (mu[k][r] - step * subgradient_mu[k][r]).max(0.0);
+            }
+        }
+
+        if m > 1 {
+            for j in 0..n {
+                lambda[j] = (lambda[j] - step * subgradient_lambda[j]).max(0.0);
+            }
+        }
+    }
+
+    Ok(relaxation.value + best)
+}
+
+//relative distance of a solution from an upper bound
+pub fn gap<K>(solution: &K, bound: f64) -> f64
+where
+    K: Objective,
+{
+    if bound <= 0.0 {
+        return 0.0;
+    }
+
+    ((bound - solution.value()) / bound).max(0.0)
+}
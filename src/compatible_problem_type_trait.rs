@@ -26,6 +26,19 @@ pub trait CompatibleProblemType:
     {
         Some(Self::default())
     }
+
+    fn max_value() -> f64 {
+        f64::MAX
+    }
+
+    //None if the result is too large for the type. floats become infinite instead
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(self * other)
+    }
 }
 
 pub trait UnboundedCompatibility: Default {
@@ -69,6 +82,18 @@ macro_rules! impl_CompatibleProblemType_for_unsigned {
                 fn identity() -> Self {
                     1
                 }
+
+                fn max_value() -> f64 {
+                    <$type>::MAX as f64
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$type>::checked_add(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$type>::checked_mul(self, other)
+                }
             }
         )*
     };
@@ -85,12 +110,16 @@ macro_rules! impl_CompatibleProblemType_for_floats {
                 }
 
                 fn key_to_type(key: u64) -> Self {
-                    unsafe { std::mem::transmute::<u64, f64>(key) as Self }
+                    f64::from_bits(key) as Self
                 }
 
                 fn identity() -> Self {
                     1.0
                 }
+
+                fn max_value() -> f64 {
+                    <$type>::MAX as f64
+                }
             }
         )*
     }
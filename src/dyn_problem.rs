@@ -0,0 +1,433 @@
+use crate::compatible_problem_type_trait::CompatibleProblemType;
+use crate::error::KpError;
+use crate::item::{Item, ItemId, ProblemItems};
+use crate::knapsack::{Knapsack, ProblemKnapsacks};
+use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solve_control::SolveControl;
+
+//counterparts of Item, Knapsack and BoundedProblem whose number of dimensions is only known
+//at runtime, such as from a configuration file. they are solved by converting them to the
+//const generic types for their number of dimensions, so solvers must be instantiated for
+//every number of dimensions up to MAX_DIMENSIONS.
+
+//item whose weights are a Vec instead of an array
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynItem<T, Id = ()>
+where
+    T: CompatibleProblemType,
+{
+    pub value: f64,
+    pub weights: Vec<T>,
+    pub quantity: T,
+    pub id: Id,
+}
+
+impl<T> DynItem<T>
+where
+    T: CompatibleProblemType,
+{
+    pub fn new(value: f64, weights: Vec<T>, quantity: T) -> Self {
+        Self {
+            value: value,
+            weights: weights,
+            quantity: quantity,
+            id: (),
+        }
+    }
+
+    pub fn binary(value: f64, weights: Vec<T>) -> Self {
+        Self::new(value, weights, <T as CompatibleProblemType>::identity())
+    }
+}
+
+impl<T, Id> DynItem<T, Id>
+where
+    T: CompatibleProblemType,
+{
+    pub fn dimensions(&self) -> usize {
+        self.weights.len()
+    }
+
+    //the same item with an id attached, replacing any it had
+    pub fn with_id<I>(self, id: I) -> DynItem<T, I> {
+        DynItem::<T, I> {
+            value: self.value,
+            weights: self.weights,
+            quantity: self.quantity,
+            id: id,
+        }
+    }
+}
+
+impl<T, const S: usize, Id> From<Item<T, S, T, Id>> for DynItem<T, Id>
+where
+    T: CompatibleProblemType,
+{
+    fn from(item: Item<T, S, T, Id>) -> Self {
+        DynItem::new(item.value, item.weights.to_vec(), item.quantity).with_id(item.id)
+    }
+}
+
+//fails with ItemDimensions(0) if the item does not have S weights
+impl<T, const S: usize, Id> TryFrom<DynItem<T, Id>> for Item<T, S, T, Id>
+where
+    T: CompatibleProblemType,
+{
+    type Error = KpError;
+
+    fn try_from(item: DynItem<T, Id>) -> Result<Self, Self::Error> {
+        let weights =
+            <[T; S]>::try_from(item.weights.as_slice()).map_err(|_| KpError::ItemDimensions(0))?;
+        Ok(Item::new(item.value, weights, item.quantity).with_id(item.id))
+    }
+}
+
+//knapsack whose capacities are a Vec instead of an array
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynKnapsack<T, Id = ()>
+where
+    T: CompatibleProblemType,
+{
+    value: f64,
+    items: Vec<DynItem<T, Id>>,
+    weights: Vec<T>,
+    pub capacity: Vec<T>,
+}
+
+impl<T, Id> DynKnapsack<T, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    pub fn new(capacity: Vec<T>) -> Self {
+        Self {
+            value: 0.0,
+            items: Vec::new(),
+            weights: vec![<T as Default>::default(); capacity.len()],
+            capacity: capacity,
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn weights(&self) -> &[T] {
+        &self.weights
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.capacity.len()
+    }
+
+    //false if the item does not fit or has a different number of dimensions. weights too
+    //large for T do not fit. items with the same value, weights and id are merged
+    pub fn add(&mut self, item: DynItem<T, Id>) -> bool {
+        if item.weights.len() != self.capacity.len() {
+            return false;
+        }
+
+        let mut weights = Vec::with_capacity(self.capacity.len());
+        for r in 0..self.capacity.len() {
+            let weight = item.weights[r]
+                .checked_mul(item.quantity)
+                .and_then(|x| x.checked_add(self.weights[r]));
+            match weight {
+                Some(weight) if weight <= self.capacity[r] => weights.push(weight),
+                _ => return false,
+            }
+        }
+
+        self.value += item.value * item.quantity.into();
+        self.weights = weights;
+
+        if let Some(stored_item) = self
+            .items
+            .iter_mut()
+            .find(|x| x.value == item.value && x.weights == item.weights && x.id == item.id)
+        {
+            stored_item.quantity += item.quantity;
+        } else {
+            self.items.push(item);
+        }
+
+        return true;
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, DynItem<T, Id>> {
+        self.items.iter()
+    }
+}
+
+impl<T, Id> IntoIterator for DynKnapsack<T, Id>
+where
+    T: CompatibleProblemType,
+{
+    type Item = DynItem<T, Id>;
+    type IntoIter = std::vec::IntoIter<DynItem<T, Id>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<T, const S: usize, Id> From<Knapsack<T, S, Id>> for DynKnapsack<T, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    fn from(knapsack: Knapsack<T, S, Id>) -> Self {
+        let mut converted = Self::new(knapsack.capacity.to_vec());
+        for item in knapsack {
+            converted.add(DynItem::from(item));
+        }
+
+        converted
+    }
+}
+
+//fails with KnapsackDimensions(0) if the knapsack or any item in it does not have S
+//dimensions, or if its items no longer fit in its capacity
+impl<T, const S: usize, Id> TryFrom<DynKnapsack<T, Id>> for Knapsack<T, S, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    type Error = KpError;
+
+    fn try_from(knapsack: DynKnapsack<T, Id>) -> Result<Self, Self::Error> {
+        let capacity = <[T; S]>::try_from(knapsack.capacity.as_slice())
+            .map_err(|_| KpError::KnapsackDimensions(0))?;
+
+        //the capacity is public so may have been resized after items were added
+        let mut converted = Knapsack::<T, S, Id>::new(capacity);
+        for item in knapsack {
+            let item = Item::try_from(item).map_err(|_| KpError::KnapsackDimensions(0))?;
+            if !converted.add(item) {
+                return Err(KpError::KnapsackDimensions(0));
+            }
+        }
+
+        Ok(converted)
+    }
+}
+
+//bounded problem whose number of dimensions is only known at runtime
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynBoundedProblem<T, Id = ()>
+where
+    T: CompatibleProblemType,
+{
+    pub items: Vec<DynItem<T, Id>>,
+    pub knapsacks: Vec<DynKnapsack<T, Id>>,
+}
+
+impl<T, Id> DynBoundedProblem<T, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    pub fn new(items: Vec<DynItem<T, Id>>, knapsacks: Vec<DynKnapsack<T, Id>>) -> Self {
+        Self {
+            items: items,
+            knapsacks: knapsacks,
+        }
+    }
+
+    //the dimensions of the first knapsack, or of the first item if there are no knapsacks
+    pub fn dimensions(&self) -> usize {
+        match self.knapsacks.first() {
+            Some(knapsack) => knapsack.dimensions(),
+            None => self.items.first().map_or(0, |x| x.dimensions()),
+        }
+    }
+
+    //items with ids are solved with their ids removed and given back, as with
+    //BoundedProblem::using_ids
+    pub fn using_ids<N>(self, solver: N) -> Vec<DynKnapsack<T, Id>>
+    where
+        N: DimensionSolver<T>,
+    {
+        self.try_using_ids(solver).unwrap()
+    }
+
+    pub fn try_using_ids<N>(self, solver: N) -> Result<Vec<DynKnapsack<T, Id>>, KpError>
+    where
+        N: DimensionSolver<T>,
+    {
+        solve_dimensions_ids(solver, self)
+    }
+}
+
+impl<T> DynBoundedProblem<T>
+where
+    T: CompatibleProblemType,
+{
+    pub fn using<N>(self, solver: N) -> Vec<DynKnapsack<T>>
+    where
+        N: DimensionSolver<T>,
+    {
+        self.try_using(solver).unwrap()
+    }
+
+    pub fn try_using<N>(self, solver: N) -> Result<Vec<DynKnapsack<T>>, KpError>
+    where
+        N: DimensionSolver<T>,
+    {
+        solve_dimensions(solver, self, None)
+    }
+
+    //dimensions given at runtime are not checked until the problem is solved, so the errors
+    //of converting and solving the problem are returned rather than panicking
+    pub fn using_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> Result<Vec<DynKnapsack<T>>, KpError>
+    where
+        N: DimensionSolver<T>,
+    {
+        solve_dimensions(solver, self, Some(control))
+    }
+}
+
+impl<T, const S: usize, Id> From<BoundedProblem<T, S, T, Id>> for DynBoundedProblem<T, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    fn from(problem: BoundedProblem<T, S, T, Id>) -> Self {
+        Self::new(
+            problem.items.into_iter().map(DynItem::from).collect(),
+            problem
+                .knapsacks
+                .into_iter()
+                .map(DynKnapsack::from)
+                .collect(),
+        )
+    }
+}
+
+//fails with the index of the first item or knapsack that does not have S dimensions
+impl<T, const S: usize, Id> TryFrom<DynBoundedProblem<T, Id>> for BoundedProblem<T, S, T, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    type Error = KpError;
+
+    fn try_from(problem: DynBoundedProblem<T, Id>) -> Result<Self, Self::Error> {
+        let mut items = ProblemItems::<T, S, T, Id>::new();
+        for (j, item) in problem.items.into_iter().enumerate() {
+            items.add(Item::try_from(item).map_err(|_| KpError::ItemDimensions(j))?);
+        }
+
+        let mut knapsacks = ProblemKnapsacks::<T, S, Id>::new();
+        for (i, knapsack) in problem.knapsacks.into_iter().enumerate() {
+            knapsacks
+                .add(Knapsack::try_from(knapsack).map_err(|_| KpError::KnapsackDimensions(i))?);
+        }
+
+        Ok(items.insert_into(knapsacks))
+    }
+}
+
+//solve the problem as one of S dimensions, returning the errors of the solver
+fn solve_in<T, N, const S: usize>(
+    solver: N,
+    problem: DynBoundedProblem<T>,
+    control: Option<&SolveControl>,
+) -> Result<Vec<DynKnapsack<T>>, KpError>
+where
+    T: CompatibleProblemType,
+    N: BoundedSolver<T, S, Output = ProblemKnapsacks<T, S>>,
+{
+    let problem = BoundedProblem::<T, S>::try_from(problem)?;
+    let knapsacks = match control {
+        Some(control) => solver.try_solve_with(problem, control)?,
+        None => solver.try_solve(problem)?,
+    };
+
+    Ok(knapsacks.into_iter().map(DynKnapsack::from).collect())
+}
+
+//solve the problem with ids as one of S dimensions
+fn solve_ids_in<T, N, Id, const S: usize>(
+    solver: N,
+    problem: DynBoundedProblem<T, Id>,
+) -> Result<Vec<DynKnapsack<T, Id>>, KpError>
+where
+    T: CompatibleProblemType,
+    N: BoundedSolver<T, S, Output = ProblemKnapsacks<T, S>>,
+    Id: ItemId,
+{
+    let problem = BoundedProblem::<T, S, T, Id>::try_from(problem)?;
+    let knapsacks = problem.try_using_ids(solver)?;
+    Ok(knapsacks.into_iter().map(DynKnapsack::from).collect())
+}
+
+macro_rules! dimension_solver {
+    ($($s:literal),+) => {
+        //largest number of dimensions a DynBoundedProblem can have
+        pub const MAX_DIMENSIONS: usize = [$($s),+].len();
+
+        //bounded solvers instantiated for every number of dimensions up to MAX_DIMENSIONS
+        //that return knapsacks. implemented for every such solver, which includes every
+        //built-in bounded solver
+        pub trait DimensionSolver<T>:
+            $(BoundedSolver<T, $s, Output = ProblemKnapsacks<T, $s>> +)+
+        where
+            T: CompatibleProblemType,
+        {
+        }
+
+        impl<T, N> DimensionSolver<T> for N
+        where
+            T: CompatibleProblemType,
+            N: $(BoundedSolver<T, $s, Output = ProblemKnapsacks<T, $s>> +)+,
+        {
+        }
+
+        fn solve_dimensions<T, N>(
+            solver: N,
+            problem: DynBoundedProblem<T>,
+            control: Option<&SolveControl>,
+        ) -> Result<Vec<DynKnapsack<T>>, KpError>
+        where
+            T: CompatibleProblemType,
+            N: DimensionSolver<T>,
+        {
+            match problem.dimensions() {
+                $($s => solve_in::<T, N, $s>(solver, problem, control),)+
+                d if d > MAX_DIMENSIONS => Err(KpError::TooManyDimensions(MAX_DIMENSIONS)),
+                d => Err(KpError::UnsupportedDimensions(d)),
+            }
+        }
+
+        fn solve_dimensions_ids<T, N, Id>(
+            solver: N,
+            problem: DynBoundedProblem<T, Id>,
+        ) -> Result<Vec<DynKnapsack<T, Id>>, KpError>
+        where
+            T: CompatibleProblemType,
+            N: DimensionSolver<T>,
+            Id: ItemId,
+        {
+            match problem.dimensions() {
+                $($s => solve_ids_in::<T, N, Id, $s>(solver, problem),)+
+                d if d > MAX_DIMENSIONS => Err(KpError::TooManyDimensions(MAX_DIMENSIONS)),
+                d => Err(KpError::UnsupportedDimensions(d)),
+            }
+        }
+    };
+}
+
+dimension_solver!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
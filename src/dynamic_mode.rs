@@ -0,0 +1,16 @@
+//memory the dynamic solvers may use for a dense memo before switching to another table
+pub const DEFAULT_MEMORY_BUDGET: usize = 1 << 30;
+
+//how the dynamic solvers handle problems with more than one knapsack
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum DynamicMode {
+    //joint if its dense memo or sparse table fits in the memory budget, sequential otherwise
+    #[default]
+    Auto,
+    //one state space spanning the capacities of every knapsack. exact, but the memo grows
+    //with the product of all capacities
+    Joint,
+    //fill the knapsacks one at a time in order, removing packed items before filling the next.
+    //uses as much memory as the largest knapsack but is not guaranteed to be optimal
+    Sequential,
+}
@@ -0,0 +1,363 @@
+use crate::error::KpError;
+use crate::item::Item;
+use crate::profit_type::ProfitType;
+use crate::solve_control::SolveControl;
+
+use std::collections::HashMap;
+
+//alternatives to the dense memo of the dynamic solvers, used when it does not fit in memory.
+//items are given with the number of copies available as their quantity, and each function
+//returns the quantity of each item placed in each knapsack, along with whether the control
+//stopped it. the control is checked against rows, the number of rows filled by every table,
+//before each row, and a stopped table places the copies of the rows it filled.
+
+//the memos add values up unchecked, so the sums of every value given that is worth more than
+//nothing and of every one worth less are checked to fit first. a value of None has overflowed
+pub(crate) fn check_values<V>(values: impl Iterator<Item = Option<V>>) -> Result<(), KpError>
+where
+    V: ProfitType,
+{
+    let mut gains = V::default();
+    let mut losses = V::default();
+    for value in values {
+        let value = value.ok_or(KpError::Overflow)?;
+        let sum = if value > V::default() {
+            &mut gains
+        } else {
+            &mut losses
+        };
+
+        *sum = sum.checked_add(value).ok_or(KpError::Overflow)?;
+    }
+
+    Ok(())
+}
+
+//bytes used by the dense memo, or None if it would overflow
+pub(crate) fn dense_size<V, const S: usize>(
+    items: &[Item<u32, S, u32, (), V>],
+    capacities: &[[usize; S]],
+) -> Option<usize>
+where
+    V: ProfitType,
+{
+    let mut cells = Some(1_usize);
+    for item in items.iter() {
+        cells = cells.and_then(|x| x.checked_add(item.quantity as usize));
+    }
+
+    for capacity in capacities.iter() {
+        for cap in capacity {
+            cells = cells.and_then(|x| x.checked_mul(cap + 1));
+        }
+    }
+
+    cells.and_then(|x| x.checked_mul(std::mem::size_of::<V>()))
+}
+
+//bytes used by the profit indexed memo. only possible for a single knapsack with one
+//dimension and integral values, otherwise None
+pub(crate) fn profit_size<V, const S: usize>(
+    items: &[Item<u32, S, u32, (), V>],
+    capacities: &[[usize; S]],
+) -> Option<usize>
+where
+    V: ProfitType,
+{
+    if S != 1 || capacities.len() != 1 {
+        return None;
+    }
+
+    let mut rows = 1_usize;
+    let mut total = 0_usize;
+    for item in items.iter() {
+        let value = profit_index(item.value)?;
+        rows = rows.checked_add(item.quantity as usize)?;
+        total = total.checked_add(value.checked_mul(item.quantity as usize)?)?;
+    }
+
+    rows.checked_mul(total.checked_add(1)?)?
+        .checked_mul(std::mem::size_of::<u64>())
+}
+
+//column of the profit indexed memo a value moves by, if it is a whole number of at least 0
+fn profit_index<V>(value: V) -> Option<usize>
+where
+    V: ProfitType,
+{
+    usize::try_from(value.to_u64()?).ok()
+}
+
+//memo of the smallest weight needed to reach each total profit, one row per item copy. only
+//used once profit_size has found every value to be a whole number and the total to fit
+pub(crate) fn fill_profit<V, const S: usize>(
+    items: &[Item<u32, S, u32, (), V>],
+    capacity: usize,
+    control: &SolveControl,
+    rows: &mut usize,
+) -> (Vec<u32>, bool)
+where
+    V: ProfitType,
+{
+    let mut copies: Vec<usize> = Vec::new();
+    let mut total = 0;
+    for (i, item) in items.iter().enumerate() {
+        for _ in 0..item.quantity {
+            copies.push(i);
+            total += profit_index(item.value).unwrap();
+        }
+    }
+
+    let width = total + 1;
+    let mut memo = vec![u64::MAX; (copies.len() + 1) * width];
+    memo[0] = 0;
+    let mut filled = 0;
+    let mut stopped = false;
+    for (k, i) in copies.iter().enumerate() {
+        if control.should_stop(*rows) {
+            stopped = true;
+            break;
+        }
+
+        *rows += 1;
+        let value = profit_index(items[*i].value).unwrap();
+        let weight = items[*i].weights[0] as u64;
+        let (prev, row) = memo[k * width..(k + 2) * width].split_at_mut(width);
+        for p in 0..width {
+            row[p] = prev[p];
+            if p >= value && prev[p - value] != u64::MAX && prev[p - value] + weight < row[p] {
+                row[p] = prev[p - value] + weight;
+            }
+        }
+
+        filled = k + 1;
+    }
+
+    //largest profit whose weight fits in the last row filled, then backtrack
+    let last = filled * width;
+    let mut p = 0;
+    for q in 0..width {
+        if memo[last + q] <= capacity as u64 {
+            p = q;
+        }
+    }
+
+    let mut item_quantity = vec![0_u32; items.len()];
+    for k in (1..=filled).rev() {
+        if memo[k * width + p] != memo[(k - 1) * width + p] {
+            item_quantity[copies[k - 1]] += 1;
+            p -= profit_index(items[copies[k - 1]].value).unwrap();
+        }
+    }
+
+    (item_quantity, stopped)
+}
+
+struct SparseState<V> {
+    pub weights: Vec<usize>, //weight in each dimension of each knapsack
+    pub value: V,
+}
+
+//how a state was reached, kept for every row to backtrack once the last row is filled
+#[derive(Clone, Copy)]
+struct SparseLink {
+    pub parent: usize,   //state in the previous row
+    pub knapsack: usize, //knapsack the copy was placed in
+}
+
+const NONE: usize = usize::MAX;
+
+//drop the states of a row that weigh at least as much as another in every dimension of every
+//knapsack without being worth more, returning whether each state is kept
+fn undominated<V>(row: &[SparseState<V>]) -> Vec<bool>
+where
+    V: ProfitType,
+{
+    let mut order: Vec<usize> = (0..row.len()).collect();
+    let total = |s: usize| row[s].weights.iter().sum::<usize>();
+    order.sort_by(|a, b| {
+        row[*b]
+            .value
+            .partial_cmp(&row[*a].value)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(total(*a).cmp(&total(*b)))
+    });
+
+    let mut kept = vec![false; row.len()];
+
+    //with one weight each, a state is kept only if it is lighter than every state worth more
+    if row.first().is_some_and(|x| x.weights.len() == 1) {
+        let mut lightest = usize::MAX;
+        for s in order {
+            kept[s] = row[s].weights[0] < lightest;
+            lightest = lightest.min(row[s].weights[0]);
+        }
+
+        return kept;
+    }
+
+    let mut front: Vec<usize> = Vec::with_capacity(row.len());
+    for s in order {
+        let dominated = front.iter().any(|k| {
+            row[*k]
+                .weights
+                .iter()
+                .zip(row[s].weights.iter())
+                .all(|(x, y)| x <= y)
+        });
+
+        if !dominated {
+            front.push(s);
+            kept[s] = true;
+        }
+    }
+
+    kept
+}
+
+//rows only hold reachable weight vectors, hashed to merge equal ones, and states dominated by
+//another are dropped. only the last row is kept whole, with the links of earlier rows kept to
+//backtrack. the number of states allocated is added to states, and KpError::MemoryBudget is
+//returned once the row and the links take more than memory_budget bytes
+pub(crate) fn fill_sparse<V, const S: usize>(
+    items: &[Item<u32, S, u32, (), V>],
+    capacities: &[[usize; S]],
+    memory_budget: usize,
+    control: &SolveControl,
+    rows: &mut usize,
+    states: &mut usize,
+) -> Result<(Vec<Vec<u32>>, bool), KpError>
+where
+    V: ProfitType,
+{
+    let mut copies: Vec<usize> = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        for _ in 0..item.quantity {
+            copies.push(i);
+        }
+    }
+
+    let state_size = std::mem::size_of::<SparseState<V>>()
+        + std::mem::size_of::<SparseLink>()
+        + capacities.len() * S * std::mem::size_of::<usize>();
+    let link_size = std::mem::size_of::<SparseLink>();
+
+    let mut row: Vec<SparseState<V>> = vec![SparseState {
+        weights: vec![0; capacities.len() * S],
+        value: V::default(),
+    }];
+
+    let mut links: Vec<Vec<SparseLink>> = Vec::with_capacity(copies.len());
+    let mut link_bytes = 0_usize;
+    *states += 1;
+
+    let mut stopped = false;
+    for i in copies.iter() {
+        if control.should_stop(*rows) {
+            stopped = true;
+            break;
+        }
+
+        *rows += 1;
+        let item = &items[*i];
+        let mut next: Vec<SparseState<V>> = Vec::with_capacity(row.len());
+        let mut next_links: Vec<SparseLink> = Vec::with_capacity(row.len());
+        let mut lookup: HashMap<Vec<usize>, usize> = HashMap::with_capacity(row.len());
+        let mut insert = |state: SparseState<V>,
+                          link: SparseLink,
+                          next: &mut Vec<SparseState<V>>,
+                          next_links: &mut Vec<SparseLink>| {
+            if let Some(&index) = lookup.get(&state.weights) {
+                if state.value > next[index].value {
+                    next[index] = state;
+                    next_links[index] = link;
+                }
+            } else {
+                lookup.insert(state.weights.clone(), next.len());
+                next.push(state);
+                next_links.push(link);
+            }
+        };
+
+        for (s, state) in row.iter().enumerate() {
+            insert(
+                SparseState {
+                    weights: state.weights.clone(),
+                    value: state.value,
+                },
+                SparseLink {
+                    parent: s,
+                    knapsack: NONE,
+                },
+                &mut next,
+                &mut next_links,
+            );
+
+            'knapsack: for t in 0..capacities.len() {
+                let mut weights = state.weights.clone();
+                for r in 0..S {
+                    weights[t * S + r] += item.weights[r] as usize;
+                    if weights[t * S + r] > capacities[t][r] {
+                        continue 'knapsack;
+                    }
+                }
+
+                insert(
+                    SparseState {
+                        weights: weights,
+                        value: state.value + item.value,
+                    },
+                    SparseLink {
+                        parent: s,
+                        knapsack: t,
+                    },
+                    &mut next,
+                    &mut next_links,
+                );
+            }
+        }
+
+        let kept = undominated(&next);
+        links.push(
+            next_links
+                .into_iter()
+                .zip(kept.iter())
+                .filter(|(_, x)| **x)
+                .map(|(link, _)| link)
+                .collect(),
+        );
+
+        row = next
+            .into_iter()
+            .zip(kept.iter())
+            .filter(|(_, x)| **x)
+            .map(|(state, _)| state)
+            .collect();
+
+        *states += row.len();
+        link_bytes += row.len() * link_size;
+        if link_bytes + row.len() * state_size > memory_budget {
+            return Err(KpError::MemoryBudget);
+        }
+    }
+
+    //best state of the last row filled, then follow the links back
+    let mut index = 0;
+    for (s, state) in row.iter().enumerate() {
+        if state.value > row[index].value {
+            index = s;
+        }
+    }
+
+    let mut item_quantity = vec![vec![0_u32; items.len()]; capacities.len()];
+    for k in (0..links.len()).rev() {
+        let link = links[k][index];
+        if link.knapsack != NONE {
+            item_quantity[link.knapsack][copies[k]] += 1;
+        }
+
+        index = link.parent;
+    }
+
+    Ok((item_quantity, stopped))
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+
+//reasons a problem could not be solved
+#[derive(Clone, PartialEq, Debug)]
+pub enum KpError {
+    //the item at the index has a value, weight or quantity that is NaN or infinite
+    NonFiniteItem(usize),
+    //the knapsack at the index has a capacity or weight that is NaN or infinite
+    NonFiniteKnapsack(usize),
+    //the item at the index could not be ranked against the others, such as an item with
+    //neither value nor weight
+    IncomparableItem(usize),
+    //the knapsack at the index could not be ranked against the others
+    IncomparableKnapsack(usize),
+    //a capacity or weight is too large for the tables of the solver, or a sum of values is too
+    //large for the value type
+    Overflow,
+    //the linear programming solver failed, with its reason
+    Backend(String),
+    //the item at the index has a different number of weights than the knapsacks have
+    //capacities
+    ItemDimensions(usize),
+    //the knapsack at the index has a different number of capacities than the others, or
+    //holds an item with a different number of weights
+    KnapsackDimensions(usize),
+    //the problem has a number of dimensions solvers are not instantiated for, such as 0
+    UnsupportedDimensions(usize),
+    //the unbounded item at the index has value but no weight, so any number of copies fit and
+    //there is no best solution
+    UnboundedItem(usize),
+    //the tables of the solver need more memory than its memory budget allows
+    MemoryBudget,
+    //a setting of the solver is out of the range it accepts, with the reason
+    InvalidParameter(&'static str),
+    //the problem has more items than the solver can take, with the largest number it can
+    TooManyItems(usize),
+    //the problem has more dimensions than solvers are instantiated for at runtime, with
+    //the largest number they are
+    TooManyDimensions(usize),
+}
+
+impl fmt::Display for KpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KpError::NonFiniteItem(j) => write!(f, "item {j} has a number that is not finite"),
+            KpError::NonFiniteKnapsack(i) => {
+                write!(f, "knapsack {i} has a number that is not finite")
+            }
+            KpError::IncomparableItem(j) => write!(f, "item {j} cannot be ranked"),
+            KpError::IncomparableKnapsack(i) => write!(f, "knapsack {i} cannot be ranked"),
+            KpError::Overflow => write!(f, "a capacity, weight or value is too large"),
+            KpError::Backend(reason) => write!(f, "backend failed: {reason}"),
+            KpError::ItemDimensions(j) => {
+                write!(f, "item {j} has a different number of dimensions")
+            }
+            KpError::KnapsackDimensions(i) => {
+                write!(f, "knapsack {i} has a different number of dimensions")
+            }
+            KpError::UnsupportedDimensions(d) => write!(f, "{d} dimensions are not supported"),
+            KpError::UnboundedItem(j) => {
+                write!(f, "item {j} has value but no weight and is unbounded")
+            }
+            KpError::MemoryBudget => write!(f, "the tables do not fit in the memory budget"),
+            KpError::InvalidParameter(reason) => write!(f, "invalid parameter: {reason}"),
+            KpError::TooManyItems(n) => write!(f, "more than {n} items cannot be solved"),
+            KpError::TooManyDimensions(d) => {
+                write!(f, "more than {d} dimensions cannot be solved at runtime")
+            }
+        }
+    }
+}
+
+impl std::error::Error for KpError {}
@@ -1,70 +1,106 @@
 use crate::compatible_problem_type_trait::{CompatibleProblemType, UnboundedCompatibility};
 use crate::knapsack::{BinaryProblemKnapsacks, ProblemKnapsacks};
 use crate::problem_type::{BinaryProblem, BinaryProblemMut, BoundedProblem, BoundedProblemMut};
+use crate::profit_type::ProfitType;
 use crate::unbounded_struct::unbounded;
 
-use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+//identifiers or payloads attached to items, such as the product an item stands for. items
+//with different ids are kept apart even if they have the same value and weights
+pub trait ItemId: Clone + Eq + Hash {}
+
+impl<Id> ItemId for Id where Id: Clone + Eq + Hash {}
+
+//key items are matched on besides their id: the bits of the value and the weights
+pub type ItemKey<const S: usize> = (u64, [u64; S]);
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct Item<T, const S: usize, N = T>
+pub struct Item<T, const S: usize, N = T, Id = (), V = f64>
 where
     T: CompatibleProblemType,
     N: UnboundedCompatibility,
+    V: ProfitType,
 {
-    pub value: f64,
+    pub value: V,
     pub weights: [T; S],
     pub quantity: N,
+    pub id: Id,
 }
 
 pub type BinaryItem<T, const S: usize> = Item<T, S>;
 pub type UnboundedItem<T, const S: usize> = Item<T, S, unbounded>;
 
-impl<T, const S: usize, N> Item<T, S, N>
+impl<T, const S: usize, N, V> Item<T, S, N, (), V>
 where
     T: CompatibleProblemType,
     N: UnboundedCompatibility,
+    V: ProfitType,
 {
-    pub fn new(value: f64, weights: [T; S], quantity: N) -> Self {
+    pub fn new(value: V, weights: [T; S], quantity: N) -> Self {
         Self {
             value: value,
             weights: weights,
             quantity: quantity,
+            id: (),
         }
     }
+}
 
-    pub fn to_key(&self) -> (u64, [u64; S]) {
+impl<T, const S: usize, N, Id, V> Item<T, S, N, Id, V>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility,
+    V: ProfitType,
+{
+    pub fn to_key(&self) -> ItemKey<S> {
         (
-            self.value.to_bits() as u64,
+            V::type_to_key(self.value),
             self.weights.map(|x| T::type_to_key(x)),
         )
     }
+
+    //the same item with an id attached, replacing any it had
+    pub fn with_id<I>(self, id: I) -> Item<T, S, N, I, V> {
+        Item::<T, S, N, I, V> {
+            value: self.value,
+            weights: self.weights,
+            quantity: self.quantity,
+            id: id,
+        }
+    }
 }
 
-impl<T, const S: usize> Item<T, S>
+impl<T, const S: usize, V> Item<T, S, T, (), V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    pub fn binary(value: f64, weights: [T; S]) -> Self {
+    pub fn binary(value: V, weights: [T; S]) -> Self {
         Self {
             value: value,
             weights: weights,
             quantity: <T as CompatibleProblemType>::identity(),
+            id: (),
         }
     }
 }
 
-impl<T, const S: usize> Item<T, S>
+impl<T, const S: usize, Id, V> Item<T, S, T, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    pub fn to_generic<N>(self) -> Item<N, S>
+    pub fn to_generic<N>(self) -> Item<N, S, N, Id, V>
     where
         N: CompatibleProblemType + From<T>,
     {
-        Item::<N, S> {
+        Item::<N, S, N, Id, V> {
             value: self.value,
             weights: self.weights.map(|x| N::from(x)),
             quantity: N::from(self.quantity),
+            id: self.id,
         }
     }
 }
@@ -81,92 +117,313 @@ where
             value: self.value,
             weights: self.weights.map(|x| N::from(x)),
             quantity: unbounded,
+            id: (),
         }
     }
 }
 
+//items in the order they were added, found through the indices of the items with each value
+//and weights. the id is only kept in the item and compared within those indices
 #[derive(Clone)]
-pub struct ProblemItems<T, const S: usize, N = T>
+pub(crate) struct ItemMap<T, const S: usize, N, Id, V>
 where
     T: CompatibleProblemType,
     N: UnboundedCompatibility,
+    V: ProfitType,
 {
-    items: IndexMap<(u64, [u64; S]), Item<T, S, N>>,
+    items: Vec<Item<T, S, N, Id, V>>,
+    indices: HashMap<ItemKey<S>, Vec<usize>>,
 }
 
-pub type UnboundedProblemItems<T, const S: usize> = ProblemItems<T, S, unbounded>;
-
-impl<T, const S: usize, N> ProblemItems<T, S, N>
+impl<T, const S: usize, N, Id, V> ItemMap<T, S, N, Id, V>
 where
     T: CompatibleProblemType,
-    N: UnboundedCompatibility + std::ops::AddAssign,
+    N: UnboundedCompatibility,
+    Id: ItemId,
+    V: ProfitType,
 {
-    pub fn new() -> Self {
-        ProblemItems::<T, S, N> {
-            items: IndexMap::new(),
+    pub(crate) fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            indices: HashMap::new(),
         }
     }
 
-    pub fn get_item(&self, key: (f64, [T; S])) -> Option<&Item<T, S, N>> {
-        self.items
-            .get(&(key.0.to_bits() as u64, key.1.map(|x| T::type_to_key(x))))
+    pub(crate) fn get_index_of(&self, key: ItemKey<S>, id: &Id) -> Option<usize> {
+        self.indices
+            .get(&key)?
+            .iter()
+            .find(|j| self.items[**j].id == *id)
+            .copied()
     }
 
-    pub fn get_item_mut(&mut self, key: (f64, [T; S])) -> Option<&mut Item<T, S, N>> {
-        self.items
-            .get_mut(&(key.0.to_bits() as u64, key.1.map(|x| T::type_to_key(x))))
+    pub(crate) fn get(&self, key: ItemKey<S>, id: &Id) -> Option<&Item<T, S, N, Id, V>> {
+        self.get_index_of(key, id).map(|j| &self.items[j])
     }
 
-    pub fn get_index_of(&self, key: (f64, [T; S])) -> Option<usize> {
-        self.items
-            .get_index_of(&(key.0.to_bits() as u64, key.1.map(|x| T::type_to_key(x))))
+    pub(crate) fn get_mut(
+        &mut self,
+        key: ItemKey<S>,
+        id: &Id,
+    ) -> Option<&mut Item<T, S, N, Id, V>> {
+        self.get_index_of(key, id).map(|j| &mut self.items[j])
     }
 
-    pub fn add(&mut self, item: Item<T, S, N>) {
-        if let Some(stored_item) = self.items.get_mut(&item.to_key()) {
-            stored_item.quantity += item.quantity;
-        } else {
-            self.items.insert(item.to_key(), item);
+    //the item must not match one already stored
+    pub(crate) fn insert(&mut self, item: Item<T, S, N, Id, V>) {
+        self.indices
+            .entry(item.to_key())
+            .or_default()
+            .push(self.items.len());
+        self.items.push(item);
+    }
+
+    pub(crate) fn get_index(&self, index: usize) -> Option<&Item<T, S, N, Id, V>> {
+        self.items.get(index)
+    }
+
+    pub(crate) fn get_index_mut(&mut self, index: usize) -> Option<&mut Item<T, S, N, Id, V>> {
+        self.items.get_mut(index)
+    }
+
+    pub(crate) fn shift_remove_index(&mut self, index: usize) -> Option<Item<T, S, N, Id, V>> {
+        let key = self.items.get(index)?.to_key();
+        if let Some(bucket) = self.indices.get_mut(&key) {
+            bucket.retain(|j| *j != index);
+            if bucket.is_empty() {
+                self.indices.remove(&key);
+            }
         }
+
+        for bucket in self.indices.values_mut() {
+            for j in bucket.iter_mut() {
+                if *j > index {
+                    *j -= 1;
+                }
+            }
+        }
+
+        Some(self.items.remove(index))
+    }
+
+    pub(crate) fn shift_remove(
+        &mut self,
+        key: ItemKey<S>,
+        id: &Id,
+    ) -> Option<Item<T, S, N, Id, V>> {
+        let index = self.get_index_of(key, id)?;
+        self.shift_remove_index(index)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.items.clear();
+        self.indices.clear();
+    }
+}
+
+impl<T, const S: usize, N, Id, V> ItemMap<T, S, N, Id, V>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility,
+    V: ProfitType,
+{
+    pub(crate) fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub(crate) fn into_values(self) -> std::vec::IntoIter<Item<T, S, N, Id, V>> {
+        self.items.into_iter()
+    }
+
+    pub(crate) fn values(&self) -> std::slice::Iter<'_, Item<T, S, N, Id, V>> {
+        self.items.iter()
+    }
+
+    pub(crate) fn values_mut(&mut self) -> std::slice::IterMut<'_, Item<T, S, N, Id, V>> {
+        self.items.iter_mut()
+    }
+}
+
+//items are compared by their value, weights and id, whatever order they were added in
+impl<T, const S: usize, N, Id, V> PartialEq for ItemMap<T, S, N, Id, V>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility,
+    Id: ItemId,
+    V: ProfitType,
+    Item<T, S, N, Id, V>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .values()
+                .all(|item| other.get(item.to_key(), &item.id) == Some(item))
+    }
+}
+
+impl<T, const S: usize, N, Id, V> std::fmt::Debug for ItemMap<T, S, N, Id, V>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility,
+    V: ProfitType,
+    Item<T, S, N, Id, V>: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.items.iter()).finish()
+    }
+}
+
+impl<T, const S: usize, N, Id, V> std::ops::Index<usize> for ItemMap<T, S, N, Id, V>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility,
+    V: ProfitType,
+{
+    type Output = Item<T, S, N, Id, V>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.items[index]
+    }
+}
+
+impl<T, const S: usize, N, Id, V> std::ops::IndexMut<usize> for ItemMap<T, S, N, Id, V>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility,
+    V: ProfitType,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.items[index]
     }
+}
+
+#[derive(Clone)]
+pub struct ProblemItems<T, const S: usize, N = T, Id = (), V = f64>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility,
+    V: ProfitType,
+{
+    items: ItemMap<T, S, N, Id, V>,
+}
+
+pub type UnboundedProblemItems<T, const S: usize> = ProblemItems<T, S, unbounded>;
 
-    pub fn remove_item(&mut self, key: (f64, [T; S])) -> Option<Item<T, S, N>> {
-        self.items
-            .shift_remove(&(key.0.to_bits() as u64, key.1.map(|x| T::type_to_key(x))))
+impl<T, const S: usize, N, Id, V> ProblemItems<T, S, N, Id, V>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility + std::ops::AddAssign,
+    Id: ItemId,
+    V: ProfitType,
+{
+    pub fn new() -> Self {
+        ProblemItems::<T, S, N, Id, V> {
+            items: ItemMap::new(),
+        }
     }
 
-    pub fn remove_index(&mut self, index: usize) -> Option<Item<T, S, N>> {
-        match self.items.shift_remove_index(index) {
-            Some((_, item)) => Some(item),
-            None => None,
+    //items with the same value, weights and id are merged
+    pub fn add(&mut self, item: Item<T, S, N, Id, V>) {
+        if let Some(stored_item) = self.items.get_mut(item.to_key(), &item.id) {
+            stored_item.quantity += item.quantity;
+        } else {
+            self.items.insert(item);
         }
     }
 
+    pub fn remove_index(&mut self, index: usize) -> Option<Item<T, S, N, Id, V>> {
+        self.items.shift_remove_index(index)
+    }
+
     pub fn len(&self) -> usize {
         self.items.len()
     }
 
-    pub fn into_iter(self) -> indexmap::map::IntoValues<(u64, [u64; S]), Item<T, S, N>> {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> std::vec::IntoIter<Item<T, S, N, Id, V>> {
         self.items.into_values()
     }
 
-    pub fn iter<'a>(&'a self) -> indexmap::map::Values<'a, (u64, [u64; S]), Item<T, S, N>> {
+    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, Item<T, S, N, Id, V>> {
         self.items.values()
     }
 
-    pub fn iter_mut<'a>(
-        &'a mut self,
-    ) -> indexmap::map::ValuesMut<'a, (u64, [u64; S]), Item<T, S, N>> {
+    pub fn iter_mut<'a>(&'a mut self) -> std::slice::IterMut<'a, Item<T, S, N, Id, V>> {
         self.items.values_mut()
     }
 
-    pub fn insert_into(self, knapsacks: ProblemKnapsacks<T, S>) -> BoundedProblem<T, S, N> {
-        BoundedProblem::<T, S, N> {
+    pub fn insert_into(
+        self,
+        knapsacks: ProblemKnapsacks<T, S, Id, V>,
+    ) -> BoundedProblem<T, S, N, Id, V> {
+        BoundedProblem::<T, S, N, Id, V> {
             items: self,
             knapsacks: knapsacks,
         }
     }
+}
+
+impl<T, const S: usize, N, Id, V> Default for ProblemItems<T, S, N, Id, V>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility + std::ops::AddAssign,
+    Id: ItemId,
+    V: ProfitType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//items without ids are looked up by their value and weights
+impl<T, const S: usize, N, V> ProblemItems<T, S, N, (), V>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility + std::ops::AddAssign,
+    V: ProfitType,
+{
+    pub fn get_item(&self, key: (V, [T; S])) -> Option<&Item<T, S, N, (), V>> {
+        self.items.get(
+            (V::type_to_key(key.0), key.1.map(|x| T::type_to_key(x))),
+            &(),
+        )
+    }
+
+    pub fn get_item_mut(&mut self, key: (V, [T; S])) -> Option<&mut Item<T, S, N, (), V>> {
+        self.items.get_mut(
+            (V::type_to_key(key.0), key.1.map(|x| T::type_to_key(x))),
+            &(),
+        )
+    }
 
+    pub fn get_index_of(&self, key: (V, [T; S])) -> Option<usize> {
+        self.items.get_index_of(
+            (V::type_to_key(key.0), key.1.map(|x| T::type_to_key(x))),
+            &(),
+        )
+    }
+
+    pub fn remove_item(&mut self, key: (V, [T; S])) -> Option<Item<T, S, N, (), V>> {
+        self.items.shift_remove(
+            (V::type_to_key(key.0), key.1.map(|x| T::type_to_key(x))),
+            &(),
+        )
+    }
+}
+
+impl<T, const S: usize, N> ProblemItems<T, S, N>
+where
+    T: CompatibleProblemType,
+    N: UnboundedCompatibility + std::ops::AddAssign,
+{
     pub fn insert_mut_into<'a>(
         &'a mut self,
         knapsacks: ProblemKnapsacks<T, S>,
@@ -178,15 +435,17 @@ where
     }
 }
 
-impl<T, const S: usize> ProblemItems<T, S>
+impl<T, const S: usize, Id, V> ProblemItems<T, S, T, Id, V>
 where
     T: CompatibleProblemType,
+    Id: ItemId,
+    V: ProfitType,
 {
-    pub fn to_generic<N>(self) -> ProblemItems<N, S>
+    pub fn to_generic<N>(self) -> ProblemItems<N, S, N, Id, V>
     where
         N: CompatibleProblemType + From<T>,
     {
-        let mut items = ProblemItems::<N, S>::new();
+        let mut items = ProblemItems::<N, S, N, Id, V>::new();
         for item in self {
             items.add(item.to_generic::<N>());
         }
@@ -212,61 +471,66 @@ where
     }
 }
 
-impl<T, const S: usize, N> IntoIterator for ProblemItems<T, S, N>
+impl<T, const S: usize, N, Id, V> IntoIterator for ProblemItems<T, S, N, Id, V>
 where
     T: CompatibleProblemType,
     N: UnboundedCompatibility,
+    V: ProfitType,
 {
-    type Item = Item<T, S, N>;
-    type IntoIter = indexmap::map::IntoValues<(u64, [u64; S]), Item<T, S, N>>;
+    type Item = Item<T, S, N, Id, V>;
+    type IntoIter = std::vec::IntoIter<Item<T, S, N, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.items.into_values()
     }
 }
 
-impl<'a, T, const S: usize, N> IntoIterator for &'a ProblemItems<T, S, N>
+impl<'a, T, const S: usize, N, Id, V> IntoIterator for &'a ProblemItems<T, S, N, Id, V>
 where
     T: CompatibleProblemType,
     N: UnboundedCompatibility,
+    V: ProfitType,
 {
-    type Item = <indexmap::map::Values<'a, (u64, [u64; S]), Item<T, S, N>> as Iterator>::Item;
-    type IntoIter = indexmap::map::Values<'a, (u64, [u64; S]), Item<T, S, N>>;
+    type Item = &'a Item<T, S, N, Id, V>;
+    type IntoIter = std::slice::Iter<'a, Item<T, S, N, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.items.values()
     }
 }
 
-impl<'a, T, const S: usize, N> IntoIterator for &'a mut ProblemItems<T, S, N>
+impl<'a, T, const S: usize, N, Id, V> IntoIterator for &'a mut ProblemItems<T, S, N, Id, V>
 where
     T: CompatibleProblemType,
     N: UnboundedCompatibility,
+    V: ProfitType,
 {
-    type Item = <indexmap::map::ValuesMut<'a, (u64, [u64; S]), Item<T, S, N>> as Iterator>::Item;
-    type IntoIter = indexmap::map::ValuesMut<'a, (u64, [u64; S]), Item<T, S, N>>;
+    type Item = &'a mut Item<T, S, N, Id, V>;
+    type IntoIter = std::slice::IterMut<'a, Item<T, S, N, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.items.values_mut()
     }
 }
 
-impl<T, const S: usize, N> std::ops::Index<usize> for ProblemItems<T, S, N>
+impl<T, const S: usize, N, Id, V> std::ops::Index<usize> for ProblemItems<T, S, N, Id, V>
 where
     T: CompatibleProblemType,
     N: UnboundedCompatibility,
+    V: ProfitType,
 {
-    type Output = Item<T, S, N>;
+    type Output = Item<T, S, N, Id, V>;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.items[index]
     }
 }
 
-impl<T, const S: usize, N> std::ops::IndexMut<usize> for ProblemItems<T, S, N>
+impl<T, const S: usize, N, Id, V> std::ops::IndexMut<usize> for ProblemItems<T, S, N, Id, V>
 where
     T: CompatibleProblemType,
     N: UnboundedCompatibility,
+    V: ProfitType,
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.items[index]
@@ -274,27 +538,29 @@ where
 }
 
 #[derive(Clone)]
-pub struct BinaryProblemItems<T, const S: usize>
+pub struct BinaryProblemItems<T, const S: usize, Id = (), V = f64>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    pub(crate) items: Vec<Item<T, S>>,
+    pub(crate) items: Vec<Item<T, S, T, Id, V>>,
 }
 
-impl<T, const S: usize> BinaryProblemItems<T, S>
+impl<T, const S: usize, Id, V> BinaryProblemItems<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
     pub fn new() -> Self {
-        BinaryProblemItems::<T, S> { items: Vec::new() }
+        BinaryProblemItems::<T, S, Id, V> { items: Vec::new() }
     }
 
-    pub fn add(&mut self, item: Item<T, S>) {
+    pub fn add(&mut self, item: Item<T, S, T, Id, V>) {
         self.items.push(item);
     }
 
-    pub fn remove_at_index(&mut self, index: usize) -> Option<Item<T, S>> {
-        if let None = self.items.get(index) {
+    pub fn remove_at_index(&mut self, index: usize) -> Option<Item<T, S, T, Id, V>> {
+        if index >= self.items.len() {
             return None;
         }
 
@@ -305,25 +571,50 @@ where
         self.items.len()
     }
 
-    pub fn into_iter(self) -> std::vec::IntoIter<Item<T, S>> {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> std::vec::IntoIter<Item<T, S, T, Id, V>> {
         self.items.into_iter()
     }
 
-    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, Item<T, S>> {
+    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, Item<T, S, T, Id, V>> {
         self.items.iter()
     }
 
-    pub fn iter_mut<'a>(&'a mut self) -> std::slice::IterMut<'a, Item<T, S>> {
+    pub fn iter_mut<'a>(&'a mut self) -> std::slice::IterMut<'a, Item<T, S, T, Id, V>> {
         self.items.iter_mut()
     }
 
-    pub fn insert_into(self, knapsacks: BinaryProblemKnapsacks<T, S>) -> BinaryProblem<T, S> {
-        BinaryProblem::<T, S> {
+    pub fn insert_into(
+        self,
+        knapsacks: BinaryProblemKnapsacks<T, S, Id, V>,
+    ) -> BinaryProblem<T, S, Id, V> {
+        BinaryProblem::<T, S, Id, V> {
             items: self,
             knapsacks: knapsacks,
         }
     }
 
+    pub fn to_generic<N>(self) -> BinaryProblemItems<N, S, Id, V>
+    where
+        N: CompatibleProblemType + From<T>,
+    {
+        let mut items = BinaryProblemItems::<N, S, Id, V>::new();
+        for item in self {
+            items.add(item.to_generic::<N>());
+        }
+
+        items
+    }
+}
+
+impl<T, const S: usize> BinaryProblemItems<T, S>
+where
+    T: CompatibleProblemType,
+{
     pub fn insert_mut_into<'a>(
         &'a mut self,
         knapsacks: BinaryProblemKnapsacks<T, S>,
@@ -333,70 +624,73 @@ where
             knapsacks: knapsacks,
         }
     }
+}
 
-    pub fn to_generic<N>(self) -> BinaryProblemItems<N, S>
-    where
-        N: CompatibleProblemType + From<T>,
-    {
-        let mut items = BinaryProblemItems::<N, S>::new();
-        for item in self {
-            items.add(item.to_generic::<N>());
-        }
-
-        items
+impl<T, const S: usize, Id, V> Default for BinaryProblemItems<T, S, Id, V>
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+{
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl<T, const S: usize> IntoIterator for BinaryProblemItems<T, S>
+impl<T, const S: usize, Id, V> IntoIterator for BinaryProblemItems<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = Item<T, S>;
-    type IntoIter = std::vec::IntoIter<Item<T, S>>;
+    type Item = Item<T, S, T, Id, V>;
+    type IntoIter = std::vec::IntoIter<Item<T, S, T, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.items.into_iter()
     }
 }
 
-impl<'a, T, const S: usize> IntoIterator for &'a BinaryProblemItems<T, S>
+impl<'a, T, const S: usize, Id, V> IntoIterator for &'a BinaryProblemItems<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = <std::slice::Iter<'a, Item<T, S>> as Iterator>::Item;
-    type IntoIter = std::slice::Iter<'a, Item<T, S>>;
+    type Item = <std::slice::Iter<'a, Item<T, S, T, Id, V>> as Iterator>::Item;
+    type IntoIter = std::slice::Iter<'a, Item<T, S, T, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.items.iter()
     }
 }
 
-impl<'a, T, const S: usize> IntoIterator for &'a mut BinaryProblemItems<T, S>
+impl<'a, T, const S: usize, Id, V> IntoIterator for &'a mut BinaryProblemItems<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = <std::slice::IterMut<'a, Item<T, S>> as Iterator>::Item;
-    type IntoIter = std::slice::IterMut<'a, Item<T, S>>;
+    type Item = <std::slice::IterMut<'a, Item<T, S, T, Id, V>> as Iterator>::Item;
+    type IntoIter = std::slice::IterMut<'a, Item<T, S, T, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.items.iter_mut()
     }
 }
 
-impl<T, const S: usize> std::ops::Index<usize> for BinaryProblemItems<T, S>
+impl<T, const S: usize, Id, V> std::ops::Index<usize> for BinaryProblemItems<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Output = Item<T, S>;
+    type Output = Item<T, S, T, Id, V>;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.items[index]
     }
 }
 
-impl<T, const S: usize> std::ops::IndexMut<usize> for BinaryProblemItems<T, S>
+impl<T, const S: usize, Id, V> std::ops::IndexMut<usize> for BinaryProblemItems<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.items[index]
@@ -36,6 +36,7 @@ macro_rules! items {
                                           CompatibleProblemType
                                      >::identity()
                               ),
+                    id: (),
 
                 });
             )*
@@ -70,6 +71,7 @@ macro_rules! items {
                                     $($quantity,)?
                                     unbounded
                               ),
+                    id: (),
 
                 });
             )*
@@ -159,6 +161,7 @@ macro_rules! items_binary {
                                           CompatibleProblemType
                                      >::identity()
                               ),
+                    id: (),
 
                 });
             )*
@@ -1,34 +1,94 @@
 use crate::compatible_problem_type_trait::{CompatibleProblemType, UnboundedCompatibility};
-use crate::item::Item;
+use crate::error::KpError;
+use crate::item::{Item, ItemId, ItemMap};
+use crate::profit_type::ProfitType;
 
-use indexmap::IndexMap;
 use std::cmp::Ordering;
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Knapsack<T, const S: usize>
+//value of a knapsack with copies of an item added, saturating at the bounds of the value type,
+//or None for copies whose value cannot be held at all
+fn saturated_value<T, V>(knapsack: V, value: V, quantity: T) -> Result<Option<V>, KpError>
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+{
+    Ok(value
+        .saturating_times(quantity.into())
+        .map(|x| knapsack.saturating_add(x)))
+}
+
+//value of a knapsack with copies of an item added, None for copies whose value cannot be held
+//at all, or KpError::Overflow if the sum overflows
+fn added_value<T, V>(knapsack: V, value: V, quantity: T) -> Result<Option<V>, KpError>
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+{
+    if value.saturating_times(quantity.into()).is_none() {
+        return Ok(None);
+    }
+
+    value
+        .times(quantity.into())
+        .and_then(|x| knapsack.checked_add(x))
+        .map(Some)
+        .ok_or(KpError::Overflow)
+}
+
+//value of a knapsack with copies of an item taken out, or None if it overflows
+fn taken_value<T, V>(knapsack: V, value: V, quantity: T) -> Option<V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    value: f64,
-    items: IndexMap<(u64, [u64; S]), Item<T, S>>,
+    value
+        .times(quantity.into())
+        .and_then(|x| knapsack.checked_sub(x))
+}
+
+#[derive(Debug, Clone)]
+pub struct Knapsack<T, const S: usize, Id = (), V = f64>
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+{
+    value: V,
+    items: ItemMap<T, S, T, Id, V>,
     weights: [T; S],
     pub capacity: [T; S],
 }
 
-impl<T, const S: usize> Knapsack<T, S>
+//the items are looked up to be compared, which needs ids that can be hashed
+impl<T, const S: usize, Id, V> PartialEq for Knapsack<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    Id: ItemId,
+    V: ProfitType,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && self.items == other.items
+            && self.weights == other.weights
+            && self.capacity == other.capacity
+    }
+}
+
+impl<T, const S: usize, Id, V> Knapsack<T, S, Id, V>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+    V: ProfitType,
 {
     pub fn new(capacity: [T; S]) -> Self {
         Self {
-            value: 0.0,
-            items: IndexMap::new(),
+            value: V::default(),
+            items: ItemMap::new(),
             weights: [<T as Default>::default(); S],
             capacity: capacity,
         }
     }
 
-    pub fn value(&self) -> f64 {
+    pub fn value(&self) -> V {
         self.value
     }
 
@@ -36,75 +96,113 @@ where
         &self.weights
     }
 
-    pub fn get_item(&self, key: (f64, [T; S])) -> Option<&Item<T, S>> {
-        self.items
-            .get(&(key.0.to_bits() as u64, key.1.map(|x| T::type_to_key(x))))
+    //items that do not fit, or fractions of a copy of an integer value, are not added. the value
+    //of the knapsack saturates at the bounds of the value type, after which it is no longer exact
+    pub fn add(&mut self, item: Item<T, S, T, Id, V>) -> bool {
+        self.add_valued(item, saturated_value).unwrap_or(false)
     }
 
-    pub fn get_index_of(&self, key: (f64, [T; S])) -> Option<usize> {
-        self.items
-            .get_index_of(&(key.0.to_bits() as u64, key.1.map(|x| T::type_to_key(x))))
+    //as add, but returns KpError::Overflow instead of saturating, leaving the knapsack unchanged
+    pub fn try_add(&mut self, item: Item<T, S, T, Id, V>) -> Result<bool, KpError> {
+        self.add_valued(item, added_value)
     }
 
-    pub fn add(&mut self, item: Item<T, S>) -> bool {
+    fn add_valued(
+        &mut self,
+        item: Item<T, S, T, Id, V>,
+        value_of: fn(V, V, T) -> Result<Option<V>, KpError>,
+    ) -> Result<bool, KpError> {
         for r in 0..S {
             if item.weights[r] * item.quantity + self.weights[r] > self.capacity[r] {
-                return false;
+                return Ok(false);
             }
         }
 
-        self.value += item.value * item.quantity.into();
+        let Some(value) = value_of(self.value, item.value, item.quantity)? else {
+            return Ok(false);
+        };
+
+        self.value = value;
         for r in 0..S {
             self.weights[r] += item.weights[r] * item.quantity;
         }
 
-        if let Some(stored_item) = self.items.get_mut(&item.to_key()) {
+        if let Some(stored_item) = self.items.get_mut(item.to_key(), &item.id) {
             stored_item.quantity += item.quantity;
         } else {
-            self.items.insert(item.to_key(), item);
+            self.items.insert(item);
         }
 
-        return true;
+        return Ok(true);
     }
 
-    pub fn add_mut<R>(&mut self, item: &mut Item<T, S, R>, quantity: T) -> bool
+    pub fn add_mut<R>(&mut self, item: &mut Item<T, S, R, Id, V>, quantity: T) -> bool
+    where
+        R: UnboundedCompatibility + PartialOrd<T> + std::ops::SubAssign<T>,
+    {
+        self.add_mut_valued(item, quantity, saturated_value)
+            .unwrap_or(false)
+    }
+
+    //as add_mut, but returns KpError::Overflow instead of saturating, leaving the knapsack and
+    //the item unchanged
+    pub fn try_add_mut<R>(
+        &mut self,
+        item: &mut Item<T, S, R, Id, V>,
+        quantity: T,
+    ) -> Result<bool, KpError>
+    where
+        R: UnboundedCompatibility + PartialOrd<T> + std::ops::SubAssign<T>,
+    {
+        self.add_mut_valued(item, quantity, added_value)
+    }
+
+    fn add_mut_valued<R>(
+        &mut self,
+        item: &mut Item<T, S, R, Id, V>,
+        quantity: T,
+        value_of: fn(V, V, T) -> Result<Option<V>, KpError>,
+    ) -> Result<bool, KpError>
     where
         R: UnboundedCompatibility + PartialOrd<T> + std::ops::SubAssign<T>,
     {
         if item.quantity < quantity {
-            return false;
+            return Ok(false);
         }
 
         for r in 0..S {
             if item.weights[r] * quantity + self.weights[r] > self.capacity[r] {
-                return false;
+                return Ok(false);
             }
         }
 
-        self.value += item.value * <T as Into<f64>>::into(quantity);
+        let Some(value) = value_of(self.value, item.value, quantity)? else {
+            return Ok(false);
+        };
+
+        self.value = value;
         item.quantity -= quantity;
         for r in 0..S {
             self.weights[r] += item.weights[r] * quantity;
         }
 
-        if let Some(stored_item) = self.items.get_mut(&item.to_key()) {
+        if let Some(stored_item) = self.items.get_mut(item.to_key(), &item.id) {
             stored_item.quantity += quantity;
         } else {
-            self.items.insert(
-                item.to_key(),
-                Item::<T, S> {
-                    value: item.value,
-                    weights: item.weights,
-                    quantity: quantity,
-                },
-            );
+            self.items.insert(Item::<T, S, T, Id, V> {
+                value: item.value,
+                weights: item.weights,
+                quantity: quantity,
+                id: item.id.clone(),
+            });
         }
 
-        return true;
+        return Ok(true);
     }
 
-    pub fn take(&mut self, item: Item<T, S>) -> Option<Item<T, S>> {
-        if let Some(stored_item) = self.items.get_mut(&item.to_key()) {
+    pub fn take(&mut self, item: Item<T, S, T, Id, V>) -> Option<Item<T, S, T, Id, V>> {
+        let value = taken_value(self.value, item.value, item.quantity)?;
+        if let Some(stored_item) = self.items.get_mut(item.to_key(), &item.id) {
             match stored_item.quantity.partial_cmp(&item.quantity).unwrap() {
                 Ordering::Less => {
                     return None;
@@ -115,7 +213,7 @@ where
                 }
             }
 
-            self.value -= item.value * item.quantity.into();
+            self.value = value;
             for r in 0..S {
                 self.weights[r] -= item.weights[r] * item.quantity;
             }
@@ -126,8 +224,9 @@ where
         }
     }
 
-    pub fn take_at_index(&mut self, index: usize, quantity: T) -> Option<Item<T, S>> {
-        if let Some((_, stored_item)) = self.items.get_index_mut(index) {
+    pub fn take_at_index(&mut self, index: usize, quantity: T) -> Option<Item<T, S, T, Id, V>> {
+        let value = taken_value(self.value, self.items.get_index(index)?.value, quantity)?;
+        if let Some(stored_item) = self.items.get_index_mut(index) {
             match stored_item.quantity.partial_cmp(&quantity).unwrap() {
                 Ordering::Less => {
                     return None;
@@ -136,7 +235,7 @@ where
                 Ordering::Greater | Ordering::Equal => stored_item.quantity -= quantity,
             }
 
-            self.value -= stored_item.value * quantity.into();
+            self.value = value;
             for r in 0..S {
                 self.weights[r] -= stored_item.weights[r] * quantity
             }
@@ -147,21 +246,13 @@ where
         }
     }
 
-    pub fn remove_item(&mut self, key: (f64, [T; S])) -> Option<Item<T, S>> {
-        self.items
-            .shift_remove(&(key.0.to_bits() as u64, key.1.map(|x| T::type_to_key(x))))
-    }
-
-    pub fn remove_at_index(&mut self, index: usize) -> Option<Item<T, S>> {
-        match self.items.shift_remove_index(index) {
-            Some((_, item)) => Some(item),
-            None => None,
-        }
+    pub fn remove_at_index(&mut self, index: usize) -> Option<Item<T, S, T, Id, V>> {
+        self.items.shift_remove_index(index)
     }
 
     pub fn clear(&mut self) {
         self.items.clear();
-        self.value = 0.0;
+        self.value = V::default();
         for r in 0..S {
             self.weights[r] = T::default();
         }
@@ -171,19 +262,24 @@ where
         self.items.len()
     }
 
-    pub fn into_iter(self) -> indexmap::map::IntoValues<(u64, [u64; S]), Item<T, S>> {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> std::vec::IntoIter<Item<T, S, T, Id, V>> {
         self.items.into_values()
     }
 
-    pub fn iter<'a>(&'a self) -> indexmap::map::Values<'a, (u64, [u64; S]), Item<T, S>> {
+    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, Item<T, S, T, Id, V>> {
         self.items.values()
     }
 
-    pub fn to_generic<N>(self) -> Knapsack<N, S>
+    pub fn to_generic<N>(self) -> Knapsack<N, S, Id, V>
     where
         N: CompatibleProblemType + From<T>,
     {
-        let mut knapsack = Knapsack::<N, S>::new(self.capacity.map(|x| N::from(x)));
+        let mut knapsack = Knapsack::<N, S, Id, V>::new(self.capacity.map(|x| N::from(x)));
         for item in self {
             knapsack.add(item.to_generic::<N>());
         }
@@ -192,35 +288,66 @@ where
     }
 }
 
-impl<T, const S: usize> IntoIterator for Knapsack<T, S>
+//items without ids are looked up by their value and weights
+impl<T, const S: usize, V> Knapsack<T, S, (), V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = Item<T, S>;
-    type IntoIter = indexmap::map::IntoValues<(u64, [u64; S]), Item<T, S>>;
+    pub fn get_item(&self, key: (V, [T; S])) -> Option<&Item<T, S, T, (), V>> {
+        self.items.get(
+            (V::type_to_key(key.0), key.1.map(|x| T::type_to_key(x))),
+            &(),
+        )
+    }
+
+    pub fn get_index_of(&self, key: (V, [T; S])) -> Option<usize> {
+        self.items.get_index_of(
+            (V::type_to_key(key.0), key.1.map(|x| T::type_to_key(x))),
+            &(),
+        )
+    }
+
+    pub fn remove_item(&mut self, key: (V, [T; S])) -> Option<Item<T, S, T, (), V>> {
+        self.items.shift_remove(
+            (V::type_to_key(key.0), key.1.map(|x| T::type_to_key(x))),
+            &(),
+        )
+    }
+}
+
+impl<T, const S: usize, Id, V> IntoIterator for Knapsack<T, S, Id, V>
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+{
+    type Item = Item<T, S, T, Id, V>;
+    type IntoIter = std::vec::IntoIter<Item<T, S, T, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.items.into_values()
     }
 }
 
-impl<'a, T, const S: usize> IntoIterator for &'a Knapsack<T, S>
+impl<'a, T, const S: usize, Id, V> IntoIterator for &'a Knapsack<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = <indexmap::map::Values<'a, (u64, [u64; S]), Item<T, S>> as Iterator>::Item;
-    type IntoIter = indexmap::map::Values<'a, (u64, [u64; S]), Item<T, S>>;
+    type Item = &'a Item<T, S, T, Id, V>;
+    type IntoIter = std::slice::Iter<'a, Item<T, S, T, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.items.values()
     }
 }
 
-impl<T, const S: usize> std::ops::Index<usize> for Knapsack<T, S>
+impl<T, const S: usize, Id, V> std::ops::Index<usize> for Knapsack<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Output = Item<T, S>;
+    type Output = Item<T, S, T, Id, V>;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.items[index]
@@ -228,32 +355,48 @@ where
 }
 
 #[derive(Default, Clone)]
-pub struct ProblemKnapsacks<T, const S: usize>
+pub struct ProblemKnapsacks<T, const S: usize, Id = (), V = f64>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    knapsacks: Vec<Knapsack<T, S>>,
+    knapsacks: Vec<Knapsack<T, S, Id, V>>,
 }
 
-impl<T, const S: usize> ProblemKnapsacks<T, S>
+impl<T, const S: usize, Id, V> ProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    Id: ItemId,
+    V: ProfitType,
 {
     pub fn new() -> Self {
-        ProblemKnapsacks::<T, S> {
+        ProblemKnapsacks::<T, S, Id, V> {
             knapsacks: Vec::new(),
         }
     }
 
-    pub fn value(&self) -> f64 {
-        let mut value = 0.0;
+    //the total value, saturating at the bounds of the value type
+    pub fn value(&self) -> V {
+        let mut value = V::default();
         for knapsack in &self.knapsacks {
-            value += knapsack.value();
+            value = value.saturating_add(knapsack.value());
         }
 
         value
     }
 
+    //the total value, or KpError::Overflow if it does not fit in the value type
+    pub fn try_value(&self) -> Result<V, KpError> {
+        let mut value = V::default();
+        for knapsack in &self.knapsacks {
+            value = value
+                .checked_add(knapsack.value())
+                .ok_or(KpError::Overflow)?;
+        }
+
+        Ok(value)
+    }
+
     pub fn weights(&self) -> [T; S] {
         let mut weights = [<T as Default>::default(); S];
         for knapsack in &self.knapsacks {
@@ -265,7 +408,7 @@ where
         weights
     }
 
-    pub fn add(&mut self, knapsack: Knapsack<T, S>) {
+    pub fn add(&mut self, knapsack: Knapsack<T, S, Id, V>) {
         self.knapsacks.push(knapsack);
     }
 
@@ -273,23 +416,28 @@ where
         self.knapsacks.len()
     }
 
-    pub fn into_iter(self) -> std::vec::IntoIter<Knapsack<T, S>> {
+    pub fn is_empty(&self) -> bool {
+        self.knapsacks.is_empty()
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> std::vec::IntoIter<Knapsack<T, S, Id, V>> {
         self.knapsacks.into_iter()
     }
 
-    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, Knapsack<T, S>> {
+    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, Knapsack<T, S, Id, V>> {
         self.knapsacks.iter()
     }
 
-    pub fn iter_mut<'a>(&'a mut self) -> std::slice::IterMut<'a, Knapsack<T, S>> {
+    pub fn iter_mut<'a>(&'a mut self) -> std::slice::IterMut<'a, Knapsack<T, S, Id, V>> {
         self.knapsacks.iter_mut()
     }
 
-    pub fn to_generic<N>(self) -> ProblemKnapsacks<N, S>
+    pub fn to_generic<N>(self) -> ProblemKnapsacks<N, S, Id, V>
     where
         N: CompatibleProblemType + From<T>,
     {
-        let mut knapsacks = ProblemKnapsacks::<N, S>::new();
+        let mut knapsacks = ProblemKnapsacks::<N, S, Id, V>::new();
         for knapsack in self {
             knapsacks.add(knapsack.to_generic::<N>());
         }
@@ -298,56 +446,61 @@ where
     }
 }
 
-impl<T, const S: usize> IntoIterator for ProblemKnapsacks<T, S>
+impl<T, const S: usize, Id, V> IntoIterator for ProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = Knapsack<T, S>;
-    type IntoIter = std::vec::IntoIter<Knapsack<T, S>>;
+    type Item = Knapsack<T, S, Id, V>;
+    type IntoIter = std::vec::IntoIter<Knapsack<T, S, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.knapsacks.into_iter()
     }
 }
 
-impl<'a, T, const S: usize> IntoIterator for &'a ProblemKnapsacks<T, S>
+impl<'a, T, const S: usize, Id, V> IntoIterator for &'a ProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = <std::slice::Iter<'a, Knapsack<T, S>> as Iterator>::Item;
-    type IntoIter = std::slice::Iter<'a, Knapsack<T, S>>;
+    type Item = &'a Knapsack<T, S, Id, V>;
+    type IntoIter = std::slice::Iter<'a, Knapsack<T, S, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.knapsacks.as_slice().into_iter()
+        self.knapsacks.as_slice().iter()
     }
 }
 
-impl<'a, T, const S: usize> IntoIterator for &'a mut ProblemKnapsacks<T, S>
+impl<'a, T, const S: usize, Id, V> IntoIterator for &'a mut ProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = <std::slice::IterMut<'a, Knapsack<T, S>> as Iterator>::Item;
-    type IntoIter = std::slice::IterMut<'a, Knapsack<T, S>>;
+    type Item = &'a mut Knapsack<T, S, Id, V>;
+    type IntoIter = std::slice::IterMut<'a, Knapsack<T, S, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.knapsacks.as_mut_slice().into_iter()
+        self.knapsacks.as_mut_slice().iter_mut()
     }
 }
 
-impl<T, const S: usize> std::ops::Index<usize> for ProblemKnapsacks<T, S>
+impl<T, const S: usize, Id, V> std::ops::Index<usize> for ProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Output = Knapsack<T, S>;
+    type Output = Knapsack<T, S, Id, V>;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.knapsacks[index]
     }
 }
 
-impl<T, const S: usize> std::ops::IndexMut<usize> for ProblemKnapsacks<T, S>
+impl<T, const S: usize, Id, V> std::ops::IndexMut<usize> for ProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.knapsacks[index]
@@ -355,30 +508,32 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct BinaryKnapsack<T, const S: usize>
+pub struct BinaryKnapsack<T, const S: usize, Id = (), V = f64>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    value: f64,
-    items: Vec<Item<T, S>>,
+    value: V,
+    items: Vec<Item<T, S, T, Id, V>>,
     weights: [T; S],
     pub capacity: [T; S],
 }
 
-impl<T, const S: usize> BinaryKnapsack<T, S>
+impl<T, const S: usize, Id, V> BinaryKnapsack<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
     pub fn new(capacity: [T; S]) -> Self {
         Self {
-            value: 0.0,
+            value: V::default(),
             items: Vec::new(),
             weights: [<T as Default>::default(); S],
             capacity: capacity,
         }
     }
 
-    pub fn value(&self) -> f64 {
+    pub fn value(&self) -> V {
         self.value
     }
 
@@ -386,52 +541,109 @@ where
         &self.weights
     }
 
-    pub fn add(&mut self, item: Item<T, S>) -> bool {
+    //items that do not fit, or fractions of a copy of an integer value, are not added. the value
+    //of the knapsack saturates at the bounds of the value type, after which it is no longer exact
+    pub fn add(&mut self, item: Item<T, S, T, Id, V>) -> bool {
+        self.add_valued(item, saturated_value).unwrap_or(false)
+    }
+
+    //as add, but returns KpError::Overflow instead of saturating, leaving the knapsack unchanged
+    pub fn try_add(&mut self, item: Item<T, S, T, Id, V>) -> Result<bool, KpError> {
+        self.add_valued(item, added_value)
+    }
+
+    fn add_valued(
+        &mut self,
+        item: Item<T, S, T, Id, V>,
+        value_of: fn(V, V, T) -> Result<Option<V>, KpError>,
+    ) -> Result<bool, KpError> {
         for r in 0..S {
             if item.weights[r] * item.quantity + self.weights[r] > self.capacity[r] {
-                return false;
+                return Ok(false);
             }
         }
 
-        self.value += item.value * item.quantity.into();
+        let Some(value) = value_of(self.value, item.value, item.quantity)? else {
+            return Ok(false);
+        };
+
+        self.value = value;
         for r in 0..S {
             self.weights[r] += item.weights[r] * item.quantity;
         }
 
         self.items.push(item);
-        return true;
+        return Ok(true);
+    }
+
+    pub fn add_mut<R>(&mut self, item: &mut Item<T, S, R, Id, V>, quantity: T) -> bool
+    where
+        R: UnboundedCompatibility + PartialOrd<T> + std::ops::SubAssign<T>,
+        Id: Clone,
+    {
+        self.add_mut_valued(item, quantity, saturated_value)
+            .unwrap_or(false)
     }
 
-    pub fn add_mut<R>(&mut self, item: &mut Item<T, S, R>, quantity: T) -> bool
+    //as add_mut, but returns KpError::Overflow instead of saturating, leaving the knapsack and
+    //the item unchanged
+    pub fn try_add_mut<R>(
+        &mut self,
+        item: &mut Item<T, S, R, Id, V>,
+        quantity: T,
+    ) -> Result<bool, KpError>
     where
         R: UnboundedCompatibility + PartialOrd<T> + std::ops::SubAssign<T>,
+        Id: Clone,
+    {
+        self.add_mut_valued(item, quantity, added_value)
+    }
+
+    fn add_mut_valued<R>(
+        &mut self,
+        item: &mut Item<T, S, R, Id, V>,
+        quantity: T,
+        value_of: fn(V, V, T) -> Result<Option<V>, KpError>,
+    ) -> Result<bool, KpError>
+    where
+        R: UnboundedCompatibility + PartialOrd<T> + std::ops::SubAssign<T>,
+        Id: Clone,
     {
         if item.quantity < quantity {
-            return false;
+            return Ok(false);
         }
 
         for r in 0..S {
             if item.weights[r] * quantity + self.weights[r] > self.capacity[r] {
-                return false;
+                return Ok(false);
             }
         }
 
-        self.value += item.value * <T as Into<f64>>::into(quantity);
+        let Some(value) = value_of(self.value, item.value, quantity)? else {
+            return Ok(false);
+        };
+
+        self.value = value;
         item.quantity -= quantity;
         for r in 0..S {
             self.weights[r] += item.weights[r] * quantity;
         }
 
-        self.items.push(Item::<T, S> {
+        self.items.push(Item::<T, S, T, Id, V> {
             value: item.value,
             weights: item.weights,
             quantity: quantity,
+            id: item.id.clone(),
         });
 
-        return true;
+        return Ok(true);
     }
 
-    pub fn take_at_index(&mut self, index: usize, quantity: T) -> Option<Item<T, S>> {
+    pub fn take_at_index(&mut self, index: usize, quantity: T) -> Option<Item<T, S, T, Id, V>>
+    where
+        Id: Clone,
+    {
+        let value = taken_value(self.value, self.items.get(index)?.value, quantity)?;
         if let Some(stored_item) = self.items.get_mut(index) {
             match stored_item.quantity.partial_cmp(&quantity).unwrap() {
                 Ordering::Less => {
@@ -443,7 +655,7 @@ where
                 }
             }
 
-            self.value -= stored_item.value * quantity.into();
+            self.value = value;
             for r in 0..S {
                 self.weights[r] -= stored_item.weights[r] * quantity;
             }
@@ -454,8 +666,8 @@ where
         }
     }
 
-    pub fn remove_at_index(&mut self, index: usize) -> Option<Item<T, S>> {
-        if let None = self.items.get(index) {
+    pub fn remove_at_index(&mut self, index: usize) -> Option<Item<T, S, T, Id, V>> {
+        if index >= self.items.len() {
             return None;
         }
 
@@ -464,7 +676,7 @@ where
 
     pub fn clear(&mut self) {
         self.items.clear();
-        self.value = 0.0;
+        self.value = V::default();
         for r in 0..S {
             self.weights[r] = T::default();
         }
@@ -474,19 +686,24 @@ where
         self.items.len()
     }
 
-    pub fn into_iter(self) -> std::vec::IntoIter<Item<T, S>> {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> std::vec::IntoIter<Item<T, S, T, Id, V>> {
         self.items.into_iter()
     }
 
-    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, Item<T, S>> {
+    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, Item<T, S, T, Id, V>> {
         self.items.iter()
     }
 
-    pub fn to_generic<N>(self) -> BinaryKnapsack<N, S>
+    pub fn to_generic<N>(self) -> BinaryKnapsack<N, S, Id, V>
     where
         N: CompatibleProblemType + From<T>,
     {
-        let mut knapsack = BinaryKnapsack::<N, S>::new(self.capacity.map(|x| N::from(x)));
+        let mut knapsack = BinaryKnapsack::<N, S, Id, V>::new(self.capacity.map(|x| N::from(x)));
         for item in self {
             knapsack.add(item.to_generic::<N>());
         }
@@ -495,57 +712,74 @@ where
     }
 }
 
-impl<T, const S: usize> IntoIterator for BinaryKnapsack<T, S>
+impl<T, const S: usize, Id, V> IntoIterator for BinaryKnapsack<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = Item<T, S>;
-    type IntoIter = std::vec::IntoIter<Item<T, S>>;
+    type Item = Item<T, S, T, Id, V>;
+    type IntoIter = std::vec::IntoIter<Item<T, S, T, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.items.into_iter()
     }
 }
 
-impl<'a, T, const S: usize> IntoIterator for &'a BinaryKnapsack<T, S>
+impl<'a, T, const S: usize, Id, V> IntoIterator for &'a BinaryKnapsack<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = <std::slice::Iter<'a, Item<T, S>> as Iterator>::Item;
-    type IntoIter = std::slice::Iter<'a, Item<T, S>>;
+    type Item = <std::slice::Iter<'a, Item<T, S, T, Id, V>> as Iterator>::Item;
+    type IntoIter = std::slice::Iter<'a, Item<T, S, T, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.items.as_slice().into_iter()
+        self.items.as_slice().iter()
     }
 }
 
 #[derive(Default, Clone)]
-pub struct BinaryProblemKnapsacks<T, const S: usize>
+pub struct BinaryProblemKnapsacks<T, const S: usize, Id = (), V = f64>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    knapsacks: Vec<BinaryKnapsack<T, S>>,
+    knapsacks: Vec<BinaryKnapsack<T, S, Id, V>>,
 }
 
-impl<T, const S: usize> BinaryProblemKnapsacks<T, S>
+impl<T, const S: usize, Id, V> BinaryProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
     pub fn new() -> Self {
-        BinaryProblemKnapsacks::<T, S> {
+        BinaryProblemKnapsacks::<T, S, Id, V> {
             knapsacks: Vec::new(),
         }
     }
 
-    pub fn value(&self) -> f64 {
-        let mut value = 0.0;
+    //the total value, saturating at the bounds of the value type
+    pub fn value(&self) -> V {
+        let mut value = V::default();
         for knapsack in &self.knapsacks {
-            value += knapsack.value();
+            value = value.saturating_add(knapsack.value());
         }
 
         value
     }
 
+    //the total value, or KpError::Overflow if it does not fit in the value type
+    pub fn try_value(&self) -> Result<V, KpError> {
+        let mut value = V::default();
+        for knapsack in &self.knapsacks {
+            value = value
+                .checked_add(knapsack.value())
+                .ok_or(KpError::Overflow)?;
+        }
+
+        Ok(value)
+    }
+
     pub fn weights(&self) -> [T; S] {
         let mut weights = [<T as Default>::default(); S];
         for knapsack in &self.knapsacks {
@@ -557,7 +791,7 @@ where
         weights
     }
 
-    pub fn add(&mut self, knapsack: BinaryKnapsack<T, S>) {
+    pub fn add(&mut self, knapsack: BinaryKnapsack<T, S, Id, V>) {
         self.knapsacks.push(knapsack);
     }
 
@@ -565,23 +799,28 @@ where
         self.knapsacks.len()
     }
 
-    pub fn into_iter(self) -> std::vec::IntoIter<BinaryKnapsack<T, S>> {
+    pub fn is_empty(&self) -> bool {
+        self.knapsacks.is_empty()
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> std::vec::IntoIter<BinaryKnapsack<T, S, Id, V>> {
         self.knapsacks.into_iter()
     }
 
-    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, BinaryKnapsack<T, S>> {
+    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, BinaryKnapsack<T, S, Id, V>> {
         self.knapsacks.iter()
     }
 
-    pub fn iter_mut<'a>(&'a mut self) -> std::slice::IterMut<'a, BinaryKnapsack<T, S>> {
+    pub fn iter_mut<'a>(&'a mut self) -> std::slice::IterMut<'a, BinaryKnapsack<T, S, Id, V>> {
         self.knapsacks.iter_mut()
     }
 
-    pub fn to_generic<N>(self) -> BinaryProblemKnapsacks<N, S>
+    pub fn to_generic<N>(self) -> BinaryProblemKnapsacks<N, S, Id, V>
     where
         N: CompatibleProblemType + From<T>,
     {
-        let mut knapsacks = BinaryProblemKnapsacks::<N, S>::new();
+        let mut knapsacks = BinaryProblemKnapsacks::<N, S, Id, V>::new();
         for knapsack in self {
             knapsacks.add(knapsack.to_generic::<N>());
         }
@@ -590,56 +829,61 @@ where
     }
 }
 
-impl<T, const S: usize> IntoIterator for BinaryProblemKnapsacks<T, S>
+impl<T, const S: usize, Id, V> IntoIterator for BinaryProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = BinaryKnapsack<T, S>;
-    type IntoIter = std::vec::IntoIter<BinaryKnapsack<T, S>>;
+    type Item = BinaryKnapsack<T, S, Id, V>;
+    type IntoIter = std::vec::IntoIter<BinaryKnapsack<T, S, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.knapsacks.into_iter()
     }
 }
 
-impl<'a, T, const S: usize> IntoIterator for &'a BinaryProblemKnapsacks<T, S>
+impl<'a, T, const S: usize, Id, V> IntoIterator for &'a BinaryProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = <std::slice::Iter<'a, BinaryKnapsack<T, S>> as Iterator>::Item;
-    type IntoIter = std::slice::Iter<'a, BinaryKnapsack<T, S>>;
+    type Item = <std::slice::Iter<'a, BinaryKnapsack<T, S, Id, V>> as Iterator>::Item;
+    type IntoIter = std::slice::Iter<'a, BinaryKnapsack<T, S, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.knapsacks.as_slice().into_iter()
+        self.knapsacks.as_slice().iter()
     }
 }
 
-impl<'a, T, const S: usize> IntoIterator for &'a mut BinaryProblemKnapsacks<T, S>
+impl<'a, T, const S: usize, Id, V> IntoIterator for &'a mut BinaryProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Item = <std::slice::IterMut<'a, BinaryKnapsack<T, S>> as Iterator>::Item;
-    type IntoIter = std::slice::IterMut<'a, BinaryKnapsack<T, S>>;
+    type Item = <std::slice::IterMut<'a, BinaryKnapsack<T, S, Id, V>> as Iterator>::Item;
+    type IntoIter = std::slice::IterMut<'a, BinaryKnapsack<T, S, Id, V>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.knapsacks.as_mut_slice().into_iter()
+        self.knapsacks.as_mut_slice().iter_mut()
     }
 }
 
-impl<T, const S: usize> std::ops::Index<usize> for BinaryProblemKnapsacks<T, S>
+impl<T, const S: usize, Id, V> std::ops::Index<usize> for BinaryProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    type Output = BinaryKnapsack<T, S>;
+    type Output = BinaryKnapsack<T, S, Id, V>;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.knapsacks[index]
     }
 }
 
-impl<T, const S: usize> std::ops::IndexMut<usize> for BinaryProblemKnapsacks<T, S>
+impl<T, const S: usize, Id, V> std::ops::IndexMut<usize> for BinaryProblemKnapsacks<T, S, Id, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.knapsacks[index]
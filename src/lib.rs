@@ -1,22 +1,47 @@
+pub use dyn_problem::{DimensionSolver, DynBoundedProblem, DynItem, DynKnapsack, MAX_DIMENSIONS};
+pub use dynamic_mode::DynamicMode;
+pub use error::KpError;
 pub use item::{
-    BinaryItem, BinaryProblemItems, Item, ProblemItems, UnboundedItem, UnboundedProblemItems,
+    BinaryItem, BinaryProblemItems, Item, ItemId, ItemKey, ProblemItems, UnboundedItem,
+    UnboundedProblemItems,
 };
 pub use knapsack::{BinaryKnapsack, BinaryProblemKnapsacks, Knapsack, ProblemKnapsacks};
+pub use observer::Observer;
 
+pub use portfolio::Portfolio;
 pub use problem_type::{
     BinaryProblem, BinarySolver, BoundedProblem, BoundedSolver, UnboundedProblem, UnboundedSolver,
 };
+pub use profit_type::ProfitType;
+pub use solution::{Objective, Solution, SolverInfo, Status};
+pub use solve_control::SolveControl;
+pub use solve_stats::SolveStats;
+pub use solver_registry::{DynBoundedSolver, SolverRegistry};
 pub use unbounded_struct::unbounded;
+pub use validation::Issue;
 
+pub mod bounds;
 pub mod compatible_problem_type_trait;
+pub mod dyn_problem;
+pub mod dynamic_mode;
+mod dynamic_tables;
+pub mod error;
 #[macro_use]
 pub mod item;
 mod items_macro;
 pub mod knapsack;
 mod knapsacks_macro;
+pub mod observer;
+pub mod portfolio;
 pub mod problem_type;
+pub mod profit_type;
+pub mod solution;
+pub mod solve_control;
+pub mod solve_stats;
+pub mod solver_registry;
 pub mod unbounded_struct;
+pub mod validation;
 
 pub mod binary_solvers;
 pub mod bounded_solvers;
-//pub mod unboundeded_solvers;
+pub mod unbounded_solvers;
@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+//receives news of a solve while it runs, such as to stream improving solutions to a UI.
+//every method does nothing unless overridden. solvers that cannot report while they run
+//only report the knapsacks they return as an incumbent
+pub trait Observer<K> {
+    //knapsacks better than any found before in the solve
+    fn on_incumbent(&mut self, _incumbent: &K) {}
+
+    //an upper bound on the optimal value
+    fn on_bound(&mut self, _bound: f64) {}
+
+    //the number of iterations done and the time since the solve began. what counts as an
+    //iteration depends on the solver, as with the iteration limit of SolveControl
+    fn on_progress(&mut self, _iterations: usize, _elapsed: Duration) {}
+}
+
+//observes nothing
+impl<K> Observer<K> for () {}
@@ -0,0 +1,216 @@
+use crate::compatible_problem_type_trait::CompatibleProblemType;
+use crate::error::KpError;
+use crate::knapsack::ProblemKnapsacks;
+use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solution::{Solution, SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use crate::solver_registry::DynBoundedSolver;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+//how often the race checks whether the caller cancelled it
+const POLL: Duration = Duration::from_millis(10);
+
+//solver in a portfolio, which must also say whether its knapsacks are optimal
+trait Entrant<T, const S: usize>: DynBoundedSolver<T, S> + SolverInfo
+where
+    T: CompatibleProblemType,
+{
+}
+
+impl<T, const S: usize, N> Entrant<T, S> for N
+where
+    T: CompatibleProblemType,
+    N: DynBoundedSolver<T, S> + SolverInfo,
+{
+}
+
+//races several solvers on the same problem, each in its own thread. the race ends once a
+//solver returns knapsacks it proves optimal or the deadline of the control passes, and the
+//best knapsacks returned by then win. solvers that return an error drop out of the race,
+//and the last error is returned if none return knapsacks. solvers still running are cancelled through the
+//control and left to finish in the background, so solvers that ignore the control may keep
+//their thread busy after the race has ended
+pub struct Portfolio<T, const S: usize>
+where
+    T: CompatibleProblemType,
+{
+    solvers: Vec<Arc<dyn Entrant<T, S>>>,
+}
+
+impl<T, const S: usize> Portfolio<T, S>
+where
+    T: CompatibleProblemType + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            solvers: Vec::new(),
+        }
+    }
+
+    pub fn add<N>(&mut self, solver: N)
+    where
+        N: BoundedSolver<T, S, Output = ProblemKnapsacks<T, S>>
+            + SolverInfo
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.solvers.push(Arc::new(solver));
+    }
+
+    pub fn len(&self) -> usize {
+        self.solvers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solvers.is_empty()
+    }
+
+    //the winning knapsacks, with the name of the solver that returned them as the solver
+    //of the solution and the status and counts it reported. panics where try_solve returns
+    //an error, such as for an invalid problem, an empty portfolio or one whose solvers all
+    //returned errors
+    pub fn solve(&self, problem: BoundedProblem<T, S>) -> Solution<ProblemKnapsacks<T, S>> {
+        self.solve_with(problem, &SolveControl::default())
+    }
+
+    pub fn try_solve(
+        &self,
+        problem: BoundedProblem<T, S>,
+    ) -> Result<Solution<ProblemKnapsacks<T, S>>, KpError> {
+        self.try_solve_with(problem, &SolveControl::default())
+    }
+
+    //race under the limits of the control. if no solver has returned by the deadline, the
+    //first knapsacks returned after it win
+    pub fn solve_with(
+        &self,
+        problem: BoundedProblem<T, S>,
+        control: &SolveControl,
+    ) -> Solution<ProblemKnapsacks<T, S>> {
+        self.try_solve_with(problem, control).unwrap()
+    }
+
+    pub fn try_solve_with(
+        &self,
+        problem: BoundedProblem<T, S>,
+        control: &SolveControl,
+    ) -> Result<Solution<ProblemKnapsacks<T, S>>, KpError> {
+        problem.check_finite()?;
+        if self.solvers.is_empty() {
+            return Err(KpError::InvalidParameter("the portfolio has no solvers"));
+        }
+
+        let start = Instant::now();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = mpsc::channel();
+        for (index, solver) in self.solvers.iter().enumerate() {
+            let solver = Arc::clone(solver);
+            let sender = sender.clone();
+            let control = SolveControl::new(
+                control.deadline,
+                control.iteration_limit,
+                Some(Arc::clone(&cancel)),
+            );
+
+            let problem = BoundedProblem::<T, S> {
+                items: problem.items.clone(),
+                knapsacks: problem.knapsacks.clone(),
+            };
+
+            thread::spawn(move || {
+                let result = solver.dyn_try_solve_stats_with(problem, &control);
+                //the race may have ended already
+                let _ = sender.send((index, result));
+            });
+        }
+
+        drop(sender);
+        let mut best: Option<(usize, ProblemKnapsacks<T, S>, SolveStats)> = None;
+        let mut error: Option<KpError> = None;
+        loop {
+            if control.is_cancelled() {
+                cancel.store(true, Ordering::Relaxed);
+            }
+
+            //once the deadline passes only wait for the first knapsacks
+            let past_deadline = control.remaining().is_some_and(|x| x.is_zero());
+            if past_deadline && best.is_some() {
+                break;
+            }
+
+            let wait = match control.remaining() {
+                Some(remaining) if !remaining.is_zero() => remaining.min(POLL),
+                _ => POLL,
+            };
+
+            //each solver reports whether it finished its search, so knapsacks cut short by
+            //the deadline or an iteration limit are not taken as optimal
+            let (index, output, stats) = match receiver.recv_timeout(wait) {
+                Ok((index, Ok((output, stats)))) => (index, output, stats),
+                Ok((_, Err(e))) => {
+                    error = Some(e);
+                    continue;
+                }
+
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+
+            let optimal = stats.status == Status::Optimal;
+            if optimal
+                || best
+                    .as_ref()
+                    .is_none_or(|(_, best, _)| output.value() > best.value())
+            {
+                best = Some((index, output, stats));
+            }
+
+            if optimal {
+                break;
+            }
+        }
+
+        //stop the solvers still running
+        cancel.store(true, Ordering::Relaxed);
+        let Some((index, output, mut stats)) = best else {
+            return Err(error.expect("every solver in the portfolio panicked"));
+        };
+
+        stats.status = match stats.status {
+            Status::Optimal => Status::Optimal,
+            _ if control.remaining().is_some_and(|x| x.is_zero()) => Status::TimeLimit,
+            _ => Status::Feasible,
+        };
+
+        stats.time = start.elapsed();
+        Ok(Solution::from_stats(
+            output,
+            stats,
+            self.solvers[index].name(),
+        ))
+    }
+}
+
+impl<T, const S: usize> Default for Portfolio<T, S>
+where
+    T: CompatibleProblemType + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const S: usize> SolverInfo for Portfolio<T, S>
+where
+    T: CompatibleProblemType,
+{
+    fn name(&self) -> &'static str {
+        "Portfolio"
+    }
+}
@@ -1,14 +1,27 @@
 use crate::compatible_problem_type_trait::{CompatibleProblemType, UnboundedCompatibility};
-use crate::item::{BinaryProblemItems, ProblemItems, UnboundedItem, UnboundedProblemItems};
-use crate::knapsack::{BinaryProblemKnapsacks, Knapsack, ProblemKnapsacks};
+use crate::error::KpError;
+use crate::item::{
+    BinaryProblemItems, Item, ItemId, ProblemItems, UnboundedItem, UnboundedProblemItems,
+};
+use crate::knapsack::{BinaryKnapsack, BinaryProblemKnapsacks, Knapsack, ProblemKnapsacks};
+use crate::observer::Observer;
+use crate::profit_type::ProfitType;
+use crate::solution::{Objective, Solution, SolverInfo};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use crate::solver_registry::DynBoundedSolver;
 use crate::unbounded_struct::unbounded;
+use crate::validation::{is_finite, Issue};
+use std::collections::HashMap;
+use std::time::Instant;
 
-pub struct BinaryProblem<T, const S: usize>
+pub struct BinaryProblem<T, const S: usize, Id = (), V = f64>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    pub items: BinaryProblemItems<T, S>,
-    pub knapsacks: BinaryProblemKnapsacks<T, S>,
+    pub items: BinaryProblemItems<T, S, Id, V>,
+    pub knapsacks: BinaryProblemKnapsacks<T, S, Id, V>,
 }
 
 pub struct BinaryProblemMut<'a, T, const S: usize>
@@ -19,26 +32,205 @@ where
     pub knapsacks: BinaryProblemKnapsacks<T, S>,
 }
 
-pub trait BinarySolver<T, const S: usize>: Clone + Copy
+pub trait BinarySolver<T, const S: usize, V = f64>: Clone + Copy
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
     type Output;
 
     //Required methods
-    fn solve(self, problem: BinaryProblem<T, S>) -> Self::Output;
+    fn solve(self, problem: BinaryProblem<T, S, (), V>) -> Self::Output;
+
+    //Provided methods
+    fn try_solve(self, problem: BinaryProblem<T, S, (), V>) -> Result<Self::Output, KpError> {
+        self.try_solve_with(problem, &SolveControl::default())
+    }
+
+    fn try_solve_with(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        control: &SolveControl,
+    ) -> Result<Self::Output, KpError> {
+        Ok(self.try_solve_stats_with(problem, control)?.0)
+    }
+
+    //solve under the limits of the control, returning an error instead of panicking when the
+    //problem cannot be solved. solvers that can fail other than on values that are not finite
+    //implement this and have the panicking methods unwrap it
+    fn try_solve_stats_with(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        control: &SolveControl,
+    ) -> Result<(Self::Output, SolveStats), KpError> {
+        problem.check_finite()?;
+        Ok(self.solve_stats_with(problem, control))
+    }
+
+    //solvers that cannot stop early ignore the control
+    fn solve_with(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        _control: &SolveControl,
+    ) -> Self::Output {
+        self.solve(problem)
+    }
+
+    //solvers that cannot report while they run only report the knapsacks they return
+    fn solve_observed(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<Self::Output>,
+    ) -> Self::Output {
+        let output = self.solve_with(problem, control);
+        observer.on_incumbent(&output);
+        output
+    }
+
+    fn solve_stats(self, problem: BinaryProblem<T, S, (), V>) -> (Self::Output, SolveStats) {
+        self.solve_stats_with(problem, &SolveControl::default())
+    }
+
+    //solve under the limits of the control, also returning what the solve proved about
+    //its knapsacks and the counts it kept. solvers that keep no counts only report the
+    //time taken, and solvers that prove nothing report their knapsacks as feasible
+    fn solve_stats_with(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        control: &SolveControl,
+    ) -> (Self::Output, SolveStats) {
+        let start = Instant::now();
+        let output = self.solve_with(problem, control);
+        let mut stats = SolveStats::default();
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+        (output, stats)
+    }
 }
 
-impl<T, const S: usize> BinaryProblem<T, S>
+impl<T, const S: usize, V> BinaryProblem<T, S, (), V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    pub fn using<N>(self, solver: N) -> <N as BinarySolver<T, S>>::Output
+    pub fn using<N>(self, solver: N) -> <N as BinarySolver<T, S, V>>::Output
     where
-        N: BinarySolver<T, S>,
+        N: BinarySolver<T, S, V>,
     {
         solver.solve(self)
     }
+
+    //solve, stopping early with the best solution found once a limit of the control is reached
+    pub fn using_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> <N as BinarySolver<T, S, V>>::Output
+    where
+        N: BinarySolver<T, S, V>,
+    {
+        solver.solve_with(self, control)
+    }
+
+    //solve, reporting incumbents, bounds and progress to the observer as they are found
+    pub fn using_observed<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+        observer: &mut dyn Observer<<N as BinarySolver<T, S, V>>::Output>,
+    ) -> <N as BinarySolver<T, S, V>>::Output
+    where
+        N: BinarySolver<T, S, V>,
+    {
+        solver.solve_observed(self, control, observer)
+    }
+
+    //solve, also returning the counts the solver kept
+    pub fn using_stats<N>(self, solver: N) -> (<N as BinarySolver<T, S, V>>::Output, SolveStats)
+    where
+        N: BinarySolver<T, S, V>,
+    {
+        solver.solve_stats(self)
+    }
+
+    pub fn try_using<N>(self, solver: N) -> Result<<N as BinarySolver<T, S, V>>::Output, KpError>
+    where
+        N: BinarySolver<T, S, V>,
+    {
+        solver.try_solve(self)
+    }
+
+    pub fn try_using_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> Result<<N as BinarySolver<T, S, V>>::Output, KpError>
+    where
+        N: BinarySolver<T, S, V>,
+    {
+        solver.try_solve_with(self, control)
+    }
+
+    //every value, weight, quantity and capacity is a number other than NaN or infinity
+    pub(crate) fn check_finite(&self) -> Result<(), KpError> {
+        for (j, item) in self.items.iter().enumerate() {
+            if !item.value.is_finite() || !is_finite(&item.weights) || !is_finite(&[item.quantity])
+            {
+                return Err(KpError::NonFiniteItem(j));
+            }
+        }
+
+        for (i, knapsack) in self.knapsacks.iter().enumerate() {
+            if !is_finite(&knapsack.capacity) || !is_finite(knapsack.weights()) {
+                return Err(KpError::NonFiniteKnapsack(i));
+            }
+        }
+
+        Ok(())
+    }
+
+    //every issue with the problem that may make solvers fail or give poor answers
+    pub fn validate(&self) -> Vec<Issue> {
+        let items: Vec<(f64, [T; S], Option<T>)> = self
+            .items
+            .iter()
+            .map(|item| (item.value.to_f64(), item.weights, Some(item.quantity)))
+            .collect();
+
+        let knapsacks: Vec<([T; S], [T; S])> = self
+            .knapsacks
+            .iter()
+            .map(|knapsack| (knapsack.capacity, *knapsack.weights()))
+            .collect();
+
+        crate::validation::validate(&items, &knapsacks)
+    }
+
+    //solve, reporting what is known about the quality of the knapsacks
+    pub fn solution<N>(self, solver: N) -> Solution<<N as BinarySolver<T, S, V>>::Output>
+    where
+        N: BinarySolver<T, S, V> + SolverInfo,
+        <N as BinarySolver<T, S, V>>::Output: Objective,
+    {
+        let (output, stats) = solver.solve_stats(self);
+        Solution::from_stats(output, stats, solver.name())
+    }
+
+    //solve under the limits of the control, reporting what is known about the quality of
+    //the knapsacks
+    pub fn solution_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> Solution<<N as BinarySolver<T, S, V>>::Output>
+    where
+        N: BinarySolver<T, S, V> + SolverInfo,
+        <N as BinarySolver<T, S, V>>::Output: Objective,
+    {
+        let (output, stats) = solver.solve_stats_with(self, control);
+        Solution::from_stats(output, stats, solver.name())
+    }
 }
 
 impl<'a, T, const S: usize> BinaryProblemMut<'a, T, S>
@@ -68,13 +260,14 @@ where
         solution
     }
 }
-pub struct BoundedProblem<T, const S: usize, N = T>
+pub struct BoundedProblem<T, const S: usize, N = T, Id = (), V = f64>
 where
     T: CompatibleProblemType,
     N: UnboundedCompatibility,
+    V: ProfitType,
 {
-    pub items: ProblemItems<T, S, N>,
-    pub knapsacks: ProblemKnapsacks<T, S>,
+    pub items: ProblemItems<T, S, N, Id, V>,
+    pub knapsacks: ProblemKnapsacks<T, S, Id, V>,
 }
 
 pub struct BoundedProblemMut<'a, T, const S: usize, N = T>
@@ -86,26 +279,493 @@ where
     pub knapsacks: ProblemKnapsacks<T, S>,
 }
 
-pub trait BoundedSolver<T, const S: usize>: Clone + Copy
+pub trait BoundedSolver<T, const S: usize, V = f64>: Clone + Copy
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
     type Output;
 
     //Required methods
-    fn solve(self, problem: BoundedProblem<T, S>) -> Self::Output;
+    fn solve(self, problem: BoundedProblem<T, S, T, (), V>) -> Self::Output;
+
+    //Provided methods
+    fn try_solve(self, problem: BoundedProblem<T, S, T, (), V>) -> Result<Self::Output, KpError> {
+        self.try_solve_with(problem, &SolveControl::default())
+    }
+
+    fn try_solve_with(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        control: &SolveControl,
+    ) -> Result<Self::Output, KpError> {
+        Ok(self.try_solve_stats_with(problem, control)?.0)
+    }
+
+    //solve under the limits of the control, returning an error instead of panicking when the
+    //problem cannot be solved. solvers that can fail other than on values that are not finite
+    //implement this and have the panicking methods unwrap it
+    fn try_solve_stats_with(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        control: &SolveControl,
+    ) -> Result<(Self::Output, SolveStats), KpError> {
+        problem.check_finite()?;
+        Ok(self.solve_stats_with(problem, control))
+    }
+
+    //solvers that cannot stop early ignore the control
+    fn solve_with(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        _control: &SolveControl,
+    ) -> Self::Output {
+        self.solve(problem)
+    }
+
+    //solvers that cannot report while they run only report the knapsacks they return
+    fn solve_observed(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<Self::Output>,
+    ) -> Self::Output {
+        let output = self.solve_with(problem, control);
+        observer.on_incumbent(&output);
+        output
+    }
+
+    fn solve_stats(self, problem: BoundedProblem<T, S, T, (), V>) -> (Self::Output, SolveStats) {
+        self.solve_stats_with(problem, &SolveControl::default())
+    }
+
+    //solve under the limits of the control, also returning what the solve proved about
+    //its knapsacks and the counts it kept. solvers that keep no counts only report the
+    //time taken, and solvers that prove nothing report their knapsacks as feasible
+    fn solve_stats_with(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        control: &SolveControl,
+    ) -> (Self::Output, SolveStats) {
+        let start = Instant::now();
+        let output = self.solve_with(problem, control);
+        let mut stats = SolveStats::default();
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+        (output, stats)
+    }
 }
 
-impl<T, const S: usize> BoundedProblem<T, S>
+impl<T, const S: usize, V> BoundedProblem<T, S, T, (), V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    pub fn using<N>(self, solver: N) -> <N as BoundedSolver<T, S>>::Output
+    pub fn using<N>(self, solver: N) -> <N as BoundedSolver<T, S, V>>::Output
     where
-        N: BoundedSolver<T, S>,
+        N: BoundedSolver<T, S, V>,
     {
         solver.solve(self)
     }
+
+    //solve, stopping early with the best solution found once a limit of the control is reached
+    pub fn using_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> <N as BoundedSolver<T, S, V>>::Output
+    where
+        N: BoundedSolver<T, S, V>,
+    {
+        solver.solve_with(self, control)
+    }
+
+    //solve, reporting incumbents, bounds and progress to the observer as they are found
+    pub fn using_observed<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+        observer: &mut dyn Observer<<N as BoundedSolver<T, S, V>>::Output>,
+    ) -> <N as BoundedSolver<T, S, V>>::Output
+    where
+        N: BoundedSolver<T, S, V>,
+    {
+        solver.solve_observed(self, control, observer)
+    }
+
+    //solve, also returning the counts the solver kept
+    pub fn using_stats<N>(self, solver: N) -> (<N as BoundedSolver<T, S, V>>::Output, SolveStats)
+    where
+        N: BoundedSolver<T, S, V>,
+    {
+        solver.solve_stats(self)
+    }
+
+    pub fn try_using<N>(self, solver: N) -> Result<<N as BoundedSolver<T, S, V>>::Output, KpError>
+    where
+        N: BoundedSolver<T, S, V>,
+    {
+        solver.try_solve(self)
+    }
+
+    pub fn try_using_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> Result<<N as BoundedSolver<T, S, V>>::Output, KpError>
+    where
+        N: BoundedSolver<T, S, V>,
+    {
+        solver.try_solve_with(self, control)
+    }
+
+    //every issue with the problem that may make solvers fail or give poor answers
+    pub fn validate(&self) -> Vec<Issue> {
+        let items: Vec<(f64, [T; S], Option<T>)> = self
+            .items
+            .iter()
+            .map(|item| (item.value.to_f64(), item.weights, Some(item.quantity)))
+            .collect();
+
+        let knapsacks: Vec<([T; S], [T; S])> = self
+            .knapsacks
+            .iter()
+            .map(|knapsack| (knapsack.capacity, *knapsack.weights()))
+            .collect();
+
+        crate::validation::validate(&items, &knapsacks)
+    }
+
+    //solve, reporting what is known about the quality of the knapsacks
+    pub fn solution<N>(self, solver: N) -> Solution<<N as BoundedSolver<T, S, V>>::Output>
+    where
+        N: BoundedSolver<T, S, V> + SolverInfo,
+        <N as BoundedSolver<T, S, V>>::Output: Objective,
+    {
+        let (output, stats) = solver.solve_stats(self);
+        Solution::from_stats(output, stats, solver.name())
+    }
+
+    //solve under the limits of the control, reporting what is known about the quality of
+    //the knapsacks
+    pub fn solution_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> Solution<<N as BoundedSolver<T, S, V>>::Output>
+    where
+        N: BoundedSolver<T, S, V> + SolverInfo,
+        <N as BoundedSolver<T, S, V>>::Output: Objective,
+    {
+        let (output, stats) = solver.solve_stats_with(self, control);
+        Solution::from_stats(output, stats, solver.name())
+    }
+}
+
+//solvers chosen at runtime take f64 values
+impl<T, const S: usize> BoundedProblem<T, S>
+where
+    T: CompatibleProblemType,
+{
+    //solve with a solver chosen at runtime, such as one from a SolverRegistry
+    pub fn using_dyn(self, solver: &dyn DynBoundedSolver<T, S>) -> ProblemKnapsacks<T, S> {
+        solver.dyn_solve(self)
+    }
+}
+
+impl<T, const S: usize, V> BoundedProblem<T, S, T, (), V>
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+{
+    //every value, weight, quantity and capacity is a number other than NaN or infinity
+    pub(crate) fn check_finite(&self) -> Result<(), KpError> {
+        for (j, item) in self.items.iter().enumerate() {
+            if !item.value.is_finite() || !is_finite(&item.weights) || !is_finite(&[item.quantity])
+            {
+                return Err(KpError::NonFiniteItem(j));
+            }
+        }
+
+        for (i, knapsack) in self.knapsacks.iter().enumerate() {
+            if !is_finite(&knapsack.capacity) || !is_finite(knapsack.weights()) {
+                return Err(KpError::NonFiniteKnapsack(i));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+//items with ids that copies of an item may have come from, by their value and weights, with
+//the quantity of each left to give out. the items of unbounded problems never run out
+type Sources<T, const S: usize, Id> = HashMap<(u64, [u64; S]), Vec<(Id, Option<T>)>>;
+
+fn source_key<T, const S: usize>(value: f64, weights: [T; S]) -> (u64, [u64; S])
+where
+    T: CompatibleProblemType,
+{
+    (value.to_bits(), weights.map(T::type_to_key))
+}
+
+//ids taken off the items of a problem and what each of its knapsacks held, to be given back
+//to the copies the solver placed
+struct IdMap<T, const S: usize, Id>
+where
+    T: CompatibleProblemType,
+{
+    sources: Sources<T, S, Id>,
+    held: Vec<Sources<T, S, Id>>,
+}
+
+impl<T, const S: usize, Id> IdMap<T, S, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    fn new() -> Self {
+        Self {
+            sources: HashMap::new(),
+            held: Vec::new(),
+        }
+    }
+
+    //a problem item, of which there are unlimited copies if the quantity is None
+    fn source(&mut self, value: f64, weights: [T; S], id: Id, quantity: Option<T>) {
+        self.sources
+            .entry(source_key(value, weights))
+            .or_default()
+            .push((id, quantity));
+    }
+
+    //the items a knapsack holds, in the order of the knapsacks, returned without their ids
+    fn hold<I>(&mut self, items: I) -> Vec<Item<T, S>>
+    where
+        I: IntoIterator<Item = Item<T, S, T, Id>>,
+    {
+        let mut contents: Sources<T, S, Id> = HashMap::new();
+        let mut stripped = Vec::new();
+        for item in items {
+            stripped.push(Item::new(item.value, item.weights, item.quantity));
+            contents
+                .entry(source_key(item.value, item.weights))
+                .or_default()
+                .push((item.id, Some(item.quantity)));
+        }
+
+        self.held.push(contents);
+        stripped
+    }
+
+    //the copies of an item placed in knapsack i, each given the id of an item it could have
+    //come from: first the items already in that knapsack and then the problem items in the
+    //order they were added
+    fn give(&mut self, i: usize, item: Item<T, S>) -> Vec<Item<T, S, T, Id>> {
+        let key = source_key(item.value, item.weights);
+        let mut left = item.quantity;
+        let mut identified = Vec::new();
+        for source in [self.held[i].get_mut(&key), self.sources.get_mut(&key)]
+            .into_iter()
+            .flatten()
+        {
+            for (id, available) in source.iter_mut() {
+                let quantity = match available {
+                    Some(available) if *available < left => *available,
+                    _ => left,
+                };
+
+                if quantity <= T::default() {
+                    continue;
+                }
+
+                if let Some(available) = available {
+                    *available -= quantity;
+                }
+
+                left -= quantity;
+                identified.push(Item::new(item.value, item.weights, quantity).with_id(id.clone()));
+            }
+        }
+
+        identified
+    }
+}
+
+//the knapsacks of a problem with ids, with the ids removed and what each knapsack held kept
+//in the map to be given back
+fn strip_ids<T, const S: usize, Id>(
+    knapsacks: ProblemKnapsacks<T, S, Id>,
+    ids: &mut IdMap<T, S, Id>,
+) -> ProblemKnapsacks<T, S>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    let mut stripped = ProblemKnapsacks::<T, S>::new();
+    for knapsack in knapsacks {
+        let mut without_ids = Knapsack::<T, S>::new(knapsack.capacity);
+        for item in ids.hold(knapsack) {
+            without_ids.add(item);
+        }
+
+        stripped.add(without_ids);
+    }
+
+    stripped
+}
+
+fn give_back_ids<T, const S: usize, Id>(
+    solved: ProblemKnapsacks<T, S>,
+    ids: &mut IdMap<T, S, Id>,
+) -> ProblemKnapsacks<T, S, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    let mut identified = ProblemKnapsacks::<T, S, Id>::new();
+    for (i, knapsack) in solved.into_iter().enumerate() {
+        let mut with_ids = Knapsack::<T, S, Id>::new(knapsack.capacity);
+        for item in knapsack {
+            for item in ids.give(i, item) {
+                with_ids.add(item);
+            }
+        }
+
+        identified.add(with_ids);
+    }
+
+    identified
+}
+
+//items with ids are solved with their ids removed, as solvers only tell items apart by their
+//value and weights, and the ids are given back to the copies placed
+impl<T, const S: usize, Id> BinaryProblem<T, S, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    pub fn using_ids<N>(self, solver: N) -> BinaryProblemKnapsacks<T, S, Id>
+    where
+        N: BinarySolver<T, S, Output = BinaryProblemKnapsacks<T, S>>,
+    {
+        self.solve_ids(|problem| Ok(solver.solve(problem))).unwrap()
+    }
+
+    pub fn try_using_ids<N>(self, solver: N) -> Result<BinaryProblemKnapsacks<T, S, Id>, KpError>
+    where
+        N: BinarySolver<T, S, Output = BinaryProblemKnapsacks<T, S>>,
+    {
+        self.solve_ids(|problem| solver.try_solve(problem))
+    }
+
+    fn solve_ids<F>(self, solve: F) -> Result<BinaryProblemKnapsacks<T, S, Id>, KpError>
+    where
+        F: FnOnce(BinaryProblem<T, S>) -> Result<BinaryProblemKnapsacks<T, S>, KpError>,
+    {
+        let mut ids = IdMap::new();
+        let mut items = BinaryProblemItems::<T, S>::new();
+        for item in self.items {
+            items.add(Item::new(item.value, item.weights, item.quantity));
+            ids.source(item.value, item.weights, item.id, Some(item.quantity));
+        }
+
+        let mut knapsacks = BinaryProblemKnapsacks::<T, S>::new();
+        for knapsack in self.knapsacks {
+            let mut stripped = BinaryKnapsack::<T, S>::new(knapsack.capacity);
+            for item in ids.hold(knapsack) {
+                stripped.add(item);
+            }
+
+            knapsacks.add(stripped);
+        }
+
+        let solved = solve(items.insert_into(knapsacks))?;
+        let mut identified = BinaryProblemKnapsacks::<T, S, Id>::new();
+        for (i, knapsack) in solved.into_iter().enumerate() {
+            let mut with_ids = BinaryKnapsack::<T, S, Id>::new(knapsack.capacity);
+            for item in knapsack {
+                for item in ids.give(i, item) {
+                    with_ids.add(item);
+                }
+            }
+
+            identified.add(with_ids);
+        }
+
+        Ok(identified)
+    }
+}
+
+impl<T, const S: usize, Id> BoundedProblem<T, S, T, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    pub fn using_ids<N>(self, solver: N) -> ProblemKnapsacks<T, S, Id>
+    where
+        N: BoundedSolver<T, S, Output = ProblemKnapsacks<T, S>>,
+    {
+        self.solve_ids(|problem| Ok(solver.solve(problem))).unwrap()
+    }
+
+    pub fn try_using_ids<N>(self, solver: N) -> Result<ProblemKnapsacks<T, S, Id>, KpError>
+    where
+        N: BoundedSolver<T, S, Output = ProblemKnapsacks<T, S>>,
+    {
+        self.solve_ids(|problem| solver.try_solve(problem))
+    }
+
+    fn solve_ids<F>(self, solve: F) -> Result<ProblemKnapsacks<T, S, Id>, KpError>
+    where
+        F: FnOnce(BoundedProblem<T, S>) -> Result<ProblemKnapsacks<T, S>, KpError>,
+    {
+        let mut ids = IdMap::new();
+        let mut items = ProblemItems::<T, S>::new();
+        for item in self.items {
+            items.add(Item::new(item.value, item.weights, item.quantity));
+            ids.source(item.value, item.weights, item.id, Some(item.quantity));
+        }
+
+        let knapsacks = strip_ids(self.knapsacks, &mut ids);
+        let solved = solve(items.insert_into(knapsacks))?;
+        Ok(give_back_ids(solved, &mut ids))
+    }
+}
+
+impl<T, const S: usize, Id> BoundedProblem<T, S, unbounded, Id>
+where
+    T: CompatibleProblemType,
+    Id: ItemId,
+{
+    pub fn using_ids<N>(self, solver: N) -> ProblemKnapsacks<T, S, Id>
+    where
+        N: UnboundedSolver<T, S, Output = ProblemKnapsacks<T, S>>,
+    {
+        self.solve_ids(|problem| Ok(solver.solve(problem))).unwrap()
+    }
+
+    pub fn try_using_ids<N>(self, solver: N) -> Result<ProblemKnapsacks<T, S, Id>, KpError>
+    where
+        N: UnboundedSolver<T, S, Output = ProblemKnapsacks<T, S>>,
+    {
+        self.solve_ids(|problem| solver.try_solve(problem))
+    }
+
+    fn solve_ids<F>(self, solve: F) -> Result<ProblemKnapsacks<T, S, Id>, KpError>
+    where
+        F: FnOnce(UnboundedProblem<T, S>) -> Result<ProblemKnapsacks<T, S>, KpError>,
+    {
+        let mut ids = IdMap::new();
+        let mut items = UnboundedProblemItems::<T, S>::new();
+        for item in self.items {
+            items.add(Item::new(item.value, item.weights, unbounded));
+            ids.source(item.value, item.weights, item.id, None);
+        }
+
+        let knapsacks = strip_ids(self.knapsacks, &mut ids);
+        let solved = solve(items.insert_into(knapsacks))?;
+        Ok(give_back_ids(solved, &mut ids))
+    }
 }
 
 impl<'a, T, const S: usize> BoundedProblemMut<'a, T, S>
@@ -134,63 +794,306 @@ where
     }
 }
 
-impl<T, const S: usize, N> BinarySolver<T, S> for N
+fn to_bounded<T, const S: usize, V>(
+    problem: BinaryProblem<T, S, (), V>,
+) -> BoundedProblem<T, S, T, (), V>
 where
     T: CompatibleProblemType,
-    N: BoundedSolver<T, S>,
+    V: ProfitType,
 {
-    type Output = <N as BoundedSolver<T, S>>::Output;
+    let mut items = ProblemItems::<T, S, T, (), V>::new();
+    for item in problem.items {
+        items.add(item);
+    }
 
-    fn solve(self, problem: BinaryProblem<T, S>) -> Self::Output {
-        let bounded_problem = BoundedProblem::<T, S> {
-            items: {
-                let mut problem_items = ProblemItems::<T, S>::new();
-                for item in problem.items {
-                    problem_items.add(item);
-                }
+    let mut knapsacks = ProblemKnapsacks::<T, S, (), V>::new();
+    for knapsack in problem.knapsacks {
+        let mut converted = Knapsack::<T, S, (), V>::new(knapsack.capacity);
+        for item in knapsack {
+            converted.add(item);
+        }
 
-                problem_items
-            },
-            knapsacks: {
-                let mut problem_knapsacks = ProblemKnapsacks::<T, S>::new();
-                for knapsack in problem.knapsacks {
-                    problem_knapsacks.add(Knapsack::<T, S>::new(knapsack.capacity));
-                    for (i, item) in knapsack.into_iter().enumerate() {
-                        problem_knapsacks[i].add(item);
-                    }
-                }
+        knapsacks.add(converted);
+    }
+
+    items.insert_into(knapsacks)
+}
+
+impl<T, const S: usize, V, N> BinarySolver<T, S, V> for N
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+    N: BoundedSolver<T, S, V>,
+{
+    type Output = <N as BoundedSolver<T, S, V>>::Output;
+
+    fn solve(self, problem: BinaryProblem<T, S, (), V>) -> Self::Output {
+        <N as BoundedSolver<T, S, V>>::solve(self, to_bounded(problem))
+    }
+
+    fn try_solve(self, problem: BinaryProblem<T, S, (), V>) -> Result<Self::Output, KpError> {
+        <N as BoundedSolver<T, S, V>>::try_solve(self, to_bounded(problem))
+    }
+
+    fn try_solve_with(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        control: &SolveControl,
+    ) -> Result<Self::Output, KpError> {
+        <N as BoundedSolver<T, S, V>>::try_solve_with(self, to_bounded(problem), control)
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        control: &SolveControl,
+    ) -> Result<(Self::Output, SolveStats), KpError> {
+        <N as BoundedSolver<T, S, V>>::try_solve_stats_with(self, to_bounded(problem), control)
+    }
+
+    fn solve_with(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        control: &SolveControl,
+    ) -> Self::Output {
+        <N as BoundedSolver<T, S, V>>::solve_with(self, to_bounded(problem), control)
+    }
+
+    fn solve_observed(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<Self::Output>,
+    ) -> Self::Output {
+        <N as BoundedSolver<T, S, V>>::solve_observed(self, to_bounded(problem), control, observer)
+    }
 
-                problem_knapsacks
-            },
-        };
+    fn solve_stats(self, problem: BinaryProblem<T, S, (), V>) -> (Self::Output, SolveStats) {
+        <N as BoundedSolver<T, S, V>>::solve_stats(self, to_bounded(problem))
+    }
 
-        <N as BoundedSolver<T, S>>::solve(self, bounded_problem)
+    fn solve_stats_with(
+        self,
+        problem: BinaryProblem<T, S, (), V>,
+        control: &SolveControl,
+    ) -> (Self::Output, SolveStats) {
+        <N as BoundedSolver<T, S, V>>::solve_stats_with(self, to_bounded(problem), control)
     }
 }
 
-pub type UnboundedProblem<T, const S: usize> = BoundedProblem<T, S, unbounded>;
+pub type UnboundedProblem<T, const S: usize, V = f64> = BoundedProblem<T, S, unbounded, (), V>;
 pub type UnboundedProblemMut<'a, T, const S: usize> = BoundedProblemMut<'a, T, S, unbounded>;
 
-pub trait UnboundedSolver<T, const S: usize>: Clone + Copy
+pub trait UnboundedSolver<T, const S: usize, V = f64>: Clone + Copy
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
     type Output;
 
     //Required methods
-    fn solve(self, problem: UnboundedProblem<T, S>) -> Self::Output;
+    fn solve(self, problem: UnboundedProblem<T, S, V>) -> Self::Output;
+
+    //Provided methods
+    fn try_solve(self, problem: UnboundedProblem<T, S, V>) -> Result<Self::Output, KpError> {
+        self.try_solve_with(problem, &SolveControl::default())
+    }
+
+    fn try_solve_with(
+        self,
+        problem: UnboundedProblem<T, S, V>,
+        control: &SolveControl,
+    ) -> Result<Self::Output, KpError> {
+        Ok(self.try_solve_stats_with(problem, control)?.0)
+    }
+
+    //solve under the limits of the control, returning an error instead of panicking when the
+    //problem cannot be solved. solvers that can fail other than on values that are not finite
+    //implement this and have the panicking methods unwrap it
+    fn try_solve_stats_with(
+        self,
+        problem: UnboundedProblem<T, S, V>,
+        control: &SolveControl,
+    ) -> Result<(Self::Output, SolveStats), KpError> {
+        problem.check_finite()?;
+        Ok(self.solve_stats_with(problem, control))
+    }
+
+    //solvers that cannot stop early ignore the control
+    fn solve_with(
+        self,
+        problem: UnboundedProblem<T, S, V>,
+        _control: &SolveControl,
+    ) -> Self::Output {
+        self.solve(problem)
+    }
+
+    //solvers that cannot report while they run only report the knapsacks they return
+    fn solve_observed(
+        self,
+        problem: UnboundedProblem<T, S, V>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<Self::Output>,
+    ) -> Self::Output {
+        let output = self.solve_with(problem, control);
+        observer.on_incumbent(&output);
+        output
+    }
+
+    fn solve_stats(self, problem: UnboundedProblem<T, S, V>) -> (Self::Output, SolveStats) {
+        self.solve_stats_with(problem, &SolveControl::default())
+    }
+
+    //solve under the limits of the control, also returning what the solve proved about
+    //its knapsacks and the counts it kept. solvers that keep no counts only report the
+    //time taken, and solvers that prove nothing report their knapsacks as feasible
+    fn solve_stats_with(
+        self,
+        problem: UnboundedProblem<T, S, V>,
+        control: &SolveControl,
+    ) -> (Self::Output, SolveStats) {
+        let start = Instant::now();
+        let output = self.solve_with(problem, control);
+        let mut stats = SolveStats::default();
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+        (output, stats)
+    }
 }
 
-impl<T, const S: usize> UnboundedProblem<T, S>
+impl<T, const S: usize, V> UnboundedProblem<T, S, V>
 where
     T: CompatibleProblemType,
+    V: ProfitType,
 {
-    pub fn using<N>(self, solver: N) -> <N as UnboundedSolver<T, S>>::Output
+    pub fn using<N>(self, solver: N) -> <N as UnboundedSolver<T, S, V>>::Output
     where
-        N: UnboundedSolver<T, S>,
+        N: UnboundedSolver<T, S, V>,
     {
         solver.solve(self)
     }
+
+    //solve, stopping early with the best solution found once a limit of the control is reached
+    pub fn using_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> <N as UnboundedSolver<T, S, V>>::Output
+    where
+        N: UnboundedSolver<T, S, V>,
+    {
+        solver.solve_with(self, control)
+    }
+
+    //solve, reporting incumbents, bounds and progress to the observer as they are found
+    pub fn using_observed<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+        observer: &mut dyn Observer<<N as UnboundedSolver<T, S, V>>::Output>,
+    ) -> <N as UnboundedSolver<T, S, V>>::Output
+    where
+        N: UnboundedSolver<T, S, V>,
+    {
+        solver.solve_observed(self, control, observer)
+    }
+
+    //solve, also returning the counts the solver kept
+    pub fn using_stats<N>(self, solver: N) -> (<N as UnboundedSolver<T, S, V>>::Output, SolveStats)
+    where
+        N: UnboundedSolver<T, S, V>,
+    {
+        solver.solve_stats(self)
+    }
+
+    pub fn try_using<N>(self, solver: N) -> Result<<N as UnboundedSolver<T, S, V>>::Output, KpError>
+    where
+        N: UnboundedSolver<T, S, V>,
+    {
+        solver.try_solve(self)
+    }
+
+    pub fn try_using_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> Result<<N as UnboundedSolver<T, S, V>>::Output, KpError>
+    where
+        N: UnboundedSolver<T, S, V>,
+    {
+        solver.try_solve_with(self, control)
+    }
+
+    //every value, weight, quantity and capacity is a number other than NaN or infinity
+    pub(crate) fn check_finite(&self) -> Result<(), KpError> {
+        for (j, item) in self.items.iter().enumerate() {
+            if !item.value.is_finite() || !is_finite(&item.weights) {
+                return Err(KpError::NonFiniteItem(j));
+            }
+        }
+
+        for (i, knapsack) in self.knapsacks.iter().enumerate() {
+            if !is_finite(&knapsack.capacity) || !is_finite(knapsack.weights()) {
+                return Err(KpError::NonFiniteKnapsack(i));
+            }
+        }
+
+        Ok(())
+    }
+
+    //no item with value is free to take, which would leave the problem without a best solution
+    pub(crate) fn check_bounded(&self) -> Result<(), KpError> {
+        let zero = <T as Default>::default();
+        for (j, item) in self.items.iter().enumerate() {
+            if item.value > V::default() && item.weights.iter().all(|x| *x == zero) {
+                return Err(KpError::UnboundedItem(j));
+            }
+        }
+
+        Ok(())
+    }
+
+    //every issue with the problem that may make solvers fail or give poor answers
+    pub fn validate(&self) -> Vec<Issue> {
+        let items: Vec<(f64, [T; S], Option<T>)> = self
+            .items
+            .iter()
+            .map(|item| (item.value.to_f64(), item.weights, None))
+            .collect();
+
+        let knapsacks: Vec<([T; S], [T; S])> = self
+            .knapsacks
+            .iter()
+            .map(|knapsack| (knapsack.capacity, *knapsack.weights()))
+            .collect();
+
+        crate::validation::validate(&items, &knapsacks)
+    }
+
+    //solve, reporting what is known about the quality of the knapsacks
+    pub fn solution<N>(self, solver: N) -> Solution<<N as UnboundedSolver<T, S, V>>::Output>
+    where
+        N: UnboundedSolver<T, S, V> + SolverInfo,
+        <N as UnboundedSolver<T, S, V>>::Output: Objective,
+    {
+        let (output, stats) = solver.solve_stats(self);
+        Solution::from_stats(output, stats, solver.name())
+    }
+
+    //solve under the limits of the control, reporting what is known about the quality of
+    //the knapsacks
+    pub fn solution_with<N>(
+        self,
+        solver: N,
+        control: &SolveControl,
+    ) -> Solution<<N as UnboundedSolver<T, S, V>>::Output>
+    where
+        N: UnboundedSolver<T, S, V> + SolverInfo,
+        <N as UnboundedSolver<T, S, V>>::Output: Objective,
+    {
+        let (output, stats) = solver.solve_stats_with(self, control);
+        Solution::from_stats(output, stats, solver.name())
+    }
 }
 
 impl<'a, T, const S: usize> UnboundedProblemMut<'a, T, S>
@@ -201,49 +1104,91 @@ where
         self,
         solver: impl UnboundedSolver<T, S, Output = ProblemKnapsacks<T, S>>,
     ) -> ProblemKnapsacks<T, S> {
-        let solution = solver.solve(UnboundedProblem::<T, S> {
+        solver.solve(UnboundedProblem::<T, S> {
             items: self.items.clone(),
             knapsacks: self.knapsacks,
-        });
+        })
+    }
+}
 
-        solution
+fn to_unbounded<T, const S: usize, V>(
+    problem: BoundedProblem<T, S, T, (), V>,
+) -> UnboundedProblem<T, S, V>
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+{
+    let mut items = ProblemItems::<T, S, unbounded, (), V>::new();
+    for item in problem.items {
+        items.add(Item::new(item.value, item.weights, unbounded));
     }
+
+    items.insert_into(problem.knapsacks)
 }
 
-impl<T, const S: usize, N> BoundedSolver<T, S> for N
+impl<T, const S: usize, V, N> BoundedSolver<T, S, V> for N
 where
     T: CompatibleProblemType,
-    N: UnboundedSolver<T, S>,
+    V: ProfitType,
+    N: UnboundedSolver<T, S, V>,
 {
-    type Output = <N as UnboundedSolver<T, S>>::Output;
+    type Output = <N as UnboundedSolver<T, S, V>>::Output;
 
-    fn solve(self, problem: BoundedProblem<T, S>) -> Self::Output {
-        let unbounded_problem = UnboundedProblem::<T, S> {
-            items: {
-                let mut problem_items = UnboundedProblemItems::<T, S>::new();
-                for item in problem.items {
-                    problem_items.add(UnboundedItem::<T, S> {
-                        value: item.value,
-                        weights: item.weights,
-                        quantity: unbounded,
-                    });
-                }
+    fn solve(self, problem: BoundedProblem<T, S, T, (), V>) -> Self::Output {
+        <N as UnboundedSolver<T, S, V>>::solve(self, to_unbounded(problem))
+    }
 
-                problem_items
-            },
-            knapsacks: {
-                let mut problem_knapsacks = ProblemKnapsacks::<T, S>::new();
-                for knapsack in problem.knapsacks {
-                    problem_knapsacks.add(Knapsack::<T, S>::new(knapsack.capacity));
-                    for (i, item) in knapsack.into_iter().enumerate() {
-                        problem_knapsacks[i].add(item);
-                    }
-                }
+    fn try_solve(self, problem: BoundedProblem<T, S, T, (), V>) -> Result<Self::Output, KpError> {
+        <N as UnboundedSolver<T, S, V>>::try_solve(self, to_unbounded(problem))
+    }
+
+    fn try_solve_with(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        control: &SolveControl,
+    ) -> Result<Self::Output, KpError> {
+        <N as UnboundedSolver<T, S, V>>::try_solve_with(self, to_unbounded(problem), control)
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        control: &SolveControl,
+    ) -> Result<(Self::Output, SolveStats), KpError> {
+        <N as UnboundedSolver<T, S, V>>::try_solve_stats_with(self, to_unbounded(problem), control)
+    }
+
+    fn solve_with(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        control: &SolveControl,
+    ) -> Self::Output {
+        <N as UnboundedSolver<T, S, V>>::solve_with(self, to_unbounded(problem), control)
+    }
+
+    fn solve_observed(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        control: &SolveControl,
+        observer: &mut dyn Observer<Self::Output>,
+    ) -> Self::Output {
+        <N as UnboundedSolver<T, S, V>>::solve_observed(
+            self,
+            to_unbounded(problem),
+            control,
+            observer,
+        )
+    }
 
-                problem_knapsacks
-            },
-        };
+    fn solve_stats(self, problem: BoundedProblem<T, S, T, (), V>) -> (Self::Output, SolveStats) {
+        <N as UnboundedSolver<T, S, V>>::solve_stats(self, to_unbounded(problem))
+    }
 
-        <N as UnboundedSolver<T, S>>::solve(self, unbounded_problem)
+    fn solve_stats_with(
+        self,
+        problem: BoundedProblem<T, S, T, (), V>,
+        control: &SolveControl,
+    ) -> (Self::Output, SolveStats) {
+        <N as UnboundedSolver<T, S, V>>::solve_stats_with(self, to_unbounded(problem), control)
     }
 }
@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, SubAssign};
+
+//types the value of an item can be. f64 is used unless another is given, while integer types
+//keep sums of values exact. other types, such as a rational number, may implement it too.
+//the items, knapsacks, problems and solver traits take any value type. the binary and bounded
+//Dynamic solvers implement the traits for each of the types here, the other solvers for f64
+pub trait ProfitType:
+    Copy + Default + PartialOrd + Debug + Add<Output = Self> + AddAssign + SubAssign
+{
+    // Required methods
+    fn type_to_key(value: Self) -> u64;
+
+    //value of the given number of copies, or None if it cannot be held, such as a fraction of
+    //an integer value or a product that overflows
+    fn times(self, quantity: f64) -> Option<Self>;
+
+    fn to_f64(self) -> f64;
+
+    // Provided methods
+    fn is_finite(self) -> bool {
+        true
+    }
+
+    //the sum, or None if it overflows
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    //the sum, saturating at the bounds of the type
+    fn saturating_add(self, other: Self) -> Self {
+        self + other
+    }
+
+    //value of the given number of copies, saturating at the bounds of the type, or None if it
+    //cannot be held at all, such as a fraction of an integer value
+    fn saturating_times(self, quantity: f64) -> Option<Self> {
+        self.times(quantity)
+    }
+
+    //the difference, or None if it overflows
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        let mut value = self;
+        value -= other;
+        Some(value)
+    }
+
+    //the value as a whole number of at least 0, or None if it is not one or does not fit
+    fn to_u64(self) -> Option<u64> {
+        let value = self.to_f64();
+        if value >= 0.0 && value.fract() == 0.0 && value < u64::MAX as f64 {
+            Some(value as u64)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! impl_ProfitType_for_integers {
+    ( $( $type:ty ),* ) => {
+        $(
+            impl ProfitType for $type {
+                fn type_to_key(value: Self) -> u64 {
+                    value as u64
+                }
+
+                fn times(self, quantity: f64) -> Option<Self> {
+                    if quantity.fract() != 0.0 {
+                        return None;
+                    }
+
+                    //every integer type fits in i128, which whole quantities out of range
+                    //saturate at
+                    self.checked_mul(<$type>::try_from(quantity as i128).ok()?)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$type>::checked_add(self, other)
+                }
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$type>::saturating_add(self, other)
+                }
+
+                fn saturating_times(self, quantity: f64) -> Option<Self> {
+                    if quantity.fract() != 0.0 {
+                        return None;
+                    }
+
+                    let product = (self as i128).saturating_mul(quantity as i128);
+                    Some(<$type>::try_from(product).unwrap_or(if product > 0 {
+                        <$type>::MAX
+                    } else {
+                        <$type>::MIN
+                    }))
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$type>::checked_sub(self, other)
+                }
+
+                fn to_u64(self) -> Option<u64> {
+                    u64::try_from(self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_ProfitType_for_integers!(u64, u32, i64, i32);
+
+macro_rules! impl_ProfitType_for_floats {
+    ( $( $type:ty ),* ) => {
+        $(
+            impl ProfitType for $type {
+                fn type_to_key(value: Self) -> u64 {
+                    value.to_bits() as u64
+                }
+
+                fn times(self, quantity: f64) -> Option<Self> {
+                    Some(self * quantity as Self)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn is_finite(self) -> bool {
+                    <$type>::is_finite(self)
+                }
+            }
+        )*
+    }
+}
+
+impl_ProfitType_for_floats!(f64, f32);
@@ -0,0 +1,121 @@
+use crate::compatible_problem_type_trait::CompatibleProblemType;
+use crate::knapsack::{BinaryProblemKnapsacks, ProblemKnapsacks};
+use crate::profit_type::ProfitType;
+use crate::solve_stats::SolveStats;
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Status {
+    //proven to be the best solution
+    Optimal,
+    //fits in the knapsacks but may not be the best solution
+    #[default]
+    Feasible,
+    //no solution that fits in the knapsacks was found
+    Infeasible,
+    //the solver stopped at its time limit
+    TimeLimit,
+}
+
+//knapsacks a solver can return, so a solution can be compared against a bound. values of
+//other types are converted to f64
+pub trait Objective {
+    fn value(&self) -> f64;
+}
+
+impl<T, const S: usize, V> Objective for ProblemKnapsacks<T, S, (), V>
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+{
+    fn value(&self) -> f64 {
+        ProblemKnapsacks::value(self).to_f64()
+    }
+}
+
+impl<T, const S: usize, V> Objective for BinaryProblemKnapsacks<T, S, (), V>
+where
+    T: CompatibleProblemType,
+    V: ProfitType,
+{
+    fn value(&self) -> f64 {
+        BinaryProblemKnapsacks::value(self).to_f64()
+    }
+}
+
+//name of a solver, given to the solutions it returns. what a solver proved about its
+//knapsacks is reported by the solve itself, in the status of its SolveStats
+pub trait SolverInfo {
+    fn name(&self) -> &'static str;
+}
+
+//knapsacks returned by a solver along with how good they are known to be
+#[derive(Clone, Debug)]
+pub struct Solution<K> {
+    pub knapsacks: K,
+    pub status: Status,
+    pub bound: Option<f64>, //upper bound on the optimal value
+    pub gap: Option<f64>,   //relative distance of the knapsacks from the bound
+    pub time: Duration,
+    pub solver: &'static str,
+    pub stats: SolveStats, //counts the solver kept, if it was solved through one
+}
+
+impl<K> Solution<K>
+where
+    K: Objective,
+{
+    //an optimal solution is its own bound
+    pub fn new(
+        knapsacks: K,
+        status: Status,
+        bound: Option<f64>,
+        time: Duration,
+        solver: &'static str,
+    ) -> Self {
+        let bound = match status {
+            Status::Optimal => Some(bound.unwrap_or(knapsacks.value())),
+            _ => bound,
+        };
+
+        Self {
+            gap: bound.map(|x| crate::bounds::gap(&knapsacks, x)),
+            knapsacks: knapsacks,
+            status: status,
+            bound: bound,
+            time: time,
+            solver: solver,
+            stats: SolveStats::default(),
+        }
+    }
+
+    //the status, bound and time reported by the solve, with its counts kept as the stats
+    pub fn from_stats(knapsacks: K, stats: SolveStats, solver: &'static str) -> Self {
+        let mut solution = Self::new(knapsacks, stats.status, stats.bound, stats.time, solver);
+        solution.stats = stats;
+        solution
+    }
+
+    pub fn value(&self) -> f64 {
+        self.knapsacks.value()
+    }
+
+    //replace the bound, such as with one from the bounds module
+    pub fn with_bound(mut self, bound: f64) -> Self {
+        self.gap = Some(crate::bounds::gap(&self.knapsacks, bound));
+        self.bound = Some(bound);
+        self
+    }
+
+    pub fn into_knapsacks(self) -> K {
+        self.knapsacks
+    }
+}
+
+impl<K> std::ops::Deref for Solution<K> {
+    type Target = K;
+
+    fn deref(&self) -> &K {
+        &self.knapsacks
+    }
+}
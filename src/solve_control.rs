@@ -0,0 +1,61 @@
+use crate::solution::Status;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+//limits on a solve. solvers that search stop once any of them is reached and return the
+//best solution found so far, while the rest ignore them.
+//the iteration limit counts nodes, iterations or generations depending on the solver
+#[derive(Clone, Debug, Default)]
+pub struct SolveControl {
+    pub deadline: Option<Instant>,
+    pub iteration_limit: Option<usize>,
+    pub cancel: Option<Arc<AtomicBool>>, //stops the solve once set to true
+}
+
+impl SolveControl {
+    pub fn new(
+        deadline: Option<Instant>,
+        iteration_limit: Option<usize>,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Self {
+        Self {
+            deadline: deadline,
+            iteration_limit: iteration_limit,
+            cancel: cancel,
+        }
+    }
+
+    //stop once the time limit has passed from now
+    pub fn time_limit(time_limit: Duration) -> Self {
+        Self::new(Some(Instant::now() + time_limit), None, None)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|x| x.load(Ordering::Relaxed))
+    }
+
+    //time left until the deadline, if there is one
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|x| x.saturating_duration_since(Instant::now()))
+    }
+
+    //whether a solver that has done the given number of iterations should stop
+    pub fn should_stop(&self, iterations: usize) -> bool {
+        self.iteration_limit.is_some_and(|x| iterations >= x)
+            || self.deadline.is_some_and(|x| Instant::now() >= x)
+            || self.is_cancelled()
+    }
+
+    //status of knapsacks from a search the control stopped early, which may not be optimal
+    pub(crate) fn stopped_status(&self) -> Status {
+        if self.deadline.is_some_and(|x| Instant::now() >= x) {
+            Status::TimeLimit
+        } else {
+            Status::Feasible
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::solution::Status;
+use std::time::{Duration, Instant};
+
+//counts kept while solving, for comparing solvers. counts a solver does not keep are 0.
+//the status and bound are what the solve proved about the knapsacks it returned.
+//there is no count of LP iterations, as neither minilp nor the good_lp backends expose theirs
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SolveStats {
+    pub status: Status,
+    pub bound: Option<f64>, //upper bound on the optimal value, if the solver found one
+    pub time: Duration,
+    pub phases: Vec<(&'static str, Duration)>, //wall time of each phase in the order run
+    pub states: usize,                         //states allocated by the dynamic programming tables
+    pub variables: usize,                      //variables of the linear program or model
+    pub constraints: usize,                    //constraints of the linear program or model
+    pub rejected: usize, //copies of items left out because they fit in no knapsack
+}
+
+impl SolveStats {
+    //record a phase that began at start and ends now
+    pub fn phase(&mut self, name: &'static str, start: Instant) {
+        self.phases.push((name, start.elapsed()));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::bounded_solvers;
+use crate::compatible_problem_type_trait::CompatibleProblemType;
+use crate::error::KpError;
+use crate::knapsack::ProblemKnapsacks;
+use crate::problem_type::{BoundedProblem, BoundedSolver};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use indexmap::IndexMap;
+
+//object safe form of BoundedSolver, so solvers chosen at runtime can be stored as
+//Box<dyn DynBoundedSolver<T, S>>. implemented for every bounded solver that returns
+//knapsacks. the methods are prefixed so they do not clash with those of BoundedSolver
+pub trait DynBoundedSolver<T, const S: usize>: Send + Sync
+where
+    T: CompatibleProblemType,
+{
+    fn dyn_solve(&self, problem: BoundedProblem<T, S>) -> ProblemKnapsacks<T, S>;
+
+    fn dyn_try_solve(
+        &self,
+        problem: BoundedProblem<T, S>,
+    ) -> Result<ProblemKnapsacks<T, S>, KpError>;
+
+    fn dyn_solve_with(
+        &self,
+        problem: BoundedProblem<T, S>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<T, S>;
+
+    fn dyn_solve_stats_with(
+        &self,
+        problem: BoundedProblem<T, S>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<T, S>, SolveStats);
+
+    fn dyn_try_solve_stats_with(
+        &self,
+        problem: BoundedProblem<T, S>,
+        control: &SolveControl,
+    ) -> Result<(ProblemKnapsacks<T, S>, SolveStats), KpError>;
+}
+
+impl<T, const S: usize, N> DynBoundedSolver<T, S> for N
+where
+    T: CompatibleProblemType,
+    N: BoundedSolver<T, S, Output = ProblemKnapsacks<T, S>> + Send + Sync,
+{
+    fn dyn_solve(&self, problem: BoundedProblem<T, S>) -> ProblemKnapsacks<T, S> {
+        self.solve(problem)
+    }
+
+    fn dyn_try_solve(
+        &self,
+        problem: BoundedProblem<T, S>,
+    ) -> Result<ProblemKnapsacks<T, S>, KpError> {
+        self.try_solve(problem)
+    }
+
+    fn dyn_solve_with(
+        &self,
+        problem: BoundedProblem<T, S>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<T, S> {
+        self.solve_with(problem, control)
+    }
+
+    fn dyn_solve_stats_with(
+        &self,
+        problem: BoundedProblem<T, S>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<T, S>, SolveStats) {
+        self.solve_stats_with(problem, control)
+    }
+
+    fn dyn_try_solve_stats_with(
+        &self,
+        problem: BoundedProblem<T, S>,
+        control: &SolveControl,
+    ) -> Result<(ProblemKnapsacks<T, S>, SolveStats), KpError> {
+        self.try_solve_stats_with(problem, control)
+    }
+}
+
+//solvers stored by name, such as to pick one from a configuration file.
+//names are kept in the order they were first registered
+pub struct SolverRegistry<T, const S: usize>
+where
+    T: CompatibleProblemType,
+{
+    solvers: IndexMap<String, Box<dyn DynBoundedSolver<T, S>>>,
+}
+
+impl<T, const S: usize> SolverRegistry<T, S>
+where
+    T: CompatibleProblemType,
+{
+    pub fn new() -> Self {
+        Self {
+            solvers: IndexMap::new(),
+        }
+    }
+
+    //store the solver under the name, replacing any solver already there
+    pub fn register(&mut self, name: &str, solver: impl DynBoundedSolver<T, S> + 'static) {
+        self.solvers.insert(name.to_string(), Box::new(solver));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn DynBoundedSolver<T, S>> {
+        self.solvers.get(name).map(|x| x.as_ref())
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn DynBoundedSolver<T, S>>> {
+        self.solvers.shift_remove(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.solvers.contains_key(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.solvers.keys().map(|x| x.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.solvers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solvers.is_empty()
+    }
+}
+
+impl<T, const S: usize> Default for SolverRegistry<T, S>
+where
+    T: CompatibleProblemType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//the built-in solvers for problems of each type, under their names in snake case.
+//solvers with their own settings use the defaults, and the metaheuristics and good_lp
+//solvers are only registered when their feature is enabled
+impl<const S: usize> SolverRegistry<f64, S> {
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register("auto", bounded_solvers::Auto::default());
+        registry.register("generalized_greedy", bounded_solvers::GeneralizedGreedy);
+        registry.register("theoretical_greedy", bounded_solvers::TheoreticalGreedy);
+        #[cfg(feature = "metaheuristics")]
+        registry.register(
+            "simulated_annealing",
+            bounded_solvers::SimulatedAnnealing::default(),
+        );
+        #[cfg(feature = "metaheuristics")]
+        registry.register("tabu_search", bounded_solvers::TabuSearch::default());
+        #[cfg(feature = "cbc")]
+        registry.register("cbc", bounded_solvers::CBC);
+        #[cfg(feature = "highs")]
+        registry.register("highs", bounded_solvers::HiGHS);
+        #[cfg(feature = "cplex")]
+        registry.register("cplex", bounded_solvers::CPLEX);
+        registry
+    }
+}
+
+impl<const S: usize> SolverRegistry<u32, S> {
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register("auto", bounded_solvers::Auto::default());
+        registry.register("dynamic", bounded_solvers::Dynamic::default());
+        registry
+    }
+}
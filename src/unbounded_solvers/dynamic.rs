@@ -0,0 +1,157 @@
+use crate::error::KpError;
+use crate::item::{Item, UnboundedProblemItems};
+use crate::knapsack::{Knapsack, ProblemKnapsacks};
+use crate::problem_type::{UnboundedProblem, UnboundedSolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::Instant;
+
+//items are unlimited so each knapsack can be filled independently.
+//the number of cells of the memo is added to states
+fn fill<const S: usize>(
+    items: &UnboundedProblemItems<u32, S>,
+    knapsack: &mut Knapsack<u32, S>,
+    states: &mut usize,
+) -> Result<(), KpError> {
+    //find the remaining capacity and the strides of the flattened memo.
+    //unlike the bounded variant there is no item axis; each cell only depends
+    //on cells of smaller capacity, so items can be reused freely.
+    let mut capacity = [0_usize; S];
+    let mut stride = [0_usize; S];
+    let mut size = 1;
+    for r in 0..S {
+        let cap = usize::try_from(knapsack.capacity[r]).map_err(|_| KpError::Overflow)?;
+        let weight = usize::try_from(knapsack.weights()[r]).map_err(|_| KpError::Overflow)?;
+        capacity[r] = cap.saturating_sub(weight);
+        stride[r] = size;
+        size = size.checked_mul(capacity[r] + 1).ok_or(KpError::Overflow)?;
+    }
+
+    //offset of each item in the flattened memo. items without weight have no
+    //value, as they are rejected otherwise, so they are ignored
+    let mut offset = vec![0_usize; items.len()];
+    for (j, item) in items.iter().enumerate() {
+        for r in 0..S {
+            offset[j] += item.weights[r] as usize * stride[r];
+        }
+    }
+
+    *states += size;
+    let mut memo = vec![0.0_f64; size];
+    let mut choice = vec![usize::MAX; size];
+    let mut index = [0_usize; S];
+    for cell in 0..size {
+        for (j, item) in items.iter().enumerate() {
+            if offset[j] == 0 {
+                continue;
+            }
+
+            //excess_weight similar to w_1 > c_1 V w_2 > c_2 V ... lazily evaluated
+            let mut excess_weight = false;
+            for r in 0..S {
+                if item.weights[r] as usize > index[r] {
+                    excess_weight = true;
+                    break;
+                }
+            }
+
+            if !excess_weight {
+                let ref_value = memo[cell - offset[j]] + item.value;
+                if ref_value > memo[cell] {
+                    memo[cell] = ref_value;
+                    choice[cell] = j;
+                }
+            }
+        }
+
+        //'increment' index
+        for r in 0..S {
+            index[r] += 1;
+            if index[r] > capacity[r] {
+                index[r] = 0;
+            } else {
+                break;
+            }
+        }
+    }
+
+    //backtrack by following the item chosen at each cell
+    let mut item_quantity = vec![0_u32; items.len()];
+    let mut cell = size - 1;
+    while choice[cell] != usize::MAX {
+        item_quantity[choice[cell]] += 1;
+        cell -= offset[choice[cell]];
+    }
+
+    for (j, quantity) in item_quantity.iter().enumerate() {
+        if *quantity > 0 {
+            knapsack.add(Item::<u32, S> {
+                value: items[j].value,
+                weights: items[j].weights,
+                quantity: *quantity,
+                id: (),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+//algorithm implemented for types that can be cast into usize (indexing)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Dynamic;
+impl<const S: usize> UnboundedSolver<u32, S> for Dynamic {
+    type Output = ProblemKnapsacks<u32, S>;
+
+    fn solve(self, problem: UnboundedProblem<u32, S>) -> ProblemKnapsacks<u32, S> {
+        self.try_solve(problem).unwrap()
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: UnboundedProblem<u32, S>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<u32, S>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(
+        self,
+        problem: UnboundedProblem<u32, S>,
+        _control: &SolveControl,
+    ) -> Result<(ProblemKnapsacks<u32, S>, SolveStats), KpError> {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let output = self.try_solve_stats(problem, &mut stats)?;
+        stats.time = start.elapsed();
+        Ok((output, stats))
+    }
+}
+
+impl Dynamic {
+    fn try_solve_stats<const S: usize>(
+        self,
+        mut problem: UnboundedProblem<u32, S>,
+        stats: &mut SolveStats,
+    ) -> Result<ProblemKnapsacks<u32, S>, KpError> {
+        problem.check_finite()?;
+        problem.check_bounded()?;
+
+        let start = Instant::now();
+        for knapsack in problem.knapsacks.iter_mut() {
+            fill(&problem.items, knapsack, &mut stats.states)?;
+        }
+
+        stats.phase("memo", start);
+        stats.status = Status::Optimal;
+
+        Ok(problem.knapsacks)
+    }
+}
+
+impl SolverInfo for Dynamic {
+    fn name(&self) -> &'static str {
+        "Dynamic"
+    }
+}
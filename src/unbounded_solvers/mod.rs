@@ -0,0 +1,5 @@
+mod dynamic;
+mod mtu2;
+
+pub use dynamic::Dynamic;
+pub use mtu2::MTU2;
@@ -0,0 +1,330 @@
+use crate::error::KpError;
+use crate::item::Item;
+use crate::knapsack::ProblemKnapsacks;
+use crate::problem_type::{UnboundedProblem, UnboundedSolver};
+use crate::solution::{SolverInfo, Status};
+use crate::solve_control::SolveControl;
+use crate::solve_stats::SolveStats;
+use std::time::Instant;
+
+#[derive(Clone, Copy)]
+struct ItemInfo {
+    pub j: usize, //index
+    pub p: f64,   //profit
+    pub w: f64,   //weight
+}
+
+struct BranchAndBound<'a> {
+    items: &'a [ItemInfo],
+    min_w: Vec<f64>, //smallest weight from each index onwards
+    x: Vec<usize>,   //current solution
+    best_x: Vec<usize>,
+    z: f64, //incumbent value
+    control: &'a SolveControl,
+    nodes: usize,
+    stopped: bool,
+}
+
+impl<'a> BranchAndBound<'a> {
+    fn new(items: &'a [ItemInfo], z: f64, control: &'a SolveControl, nodes: usize) -> Self {
+        let mut min_w = vec![f64::INFINITY; items.len() + 1];
+        for j in (0..items.len()).rev() {
+            min_w[j] = min_w[j + 1].min(items[j].w);
+        }
+
+        Self {
+            items: items,
+            min_w: min_w,
+            x: vec![0; items.len()],
+            best_x: vec![0; items.len()],
+            z: z,
+            control: control,
+            nodes: nodes,
+            stopped: false,
+        }
+    }
+
+    //depth first search over the number of copies of each item (MTU1).
+    //copies are tried from the most to the fewest so the first leaf is the greedy solution.
+    fn branch(&mut self, j: usize, c: f64, v: f64) {
+        self.nodes += 1;
+        if self.stopped || self.control.should_stop(self.nodes) {
+            self.stopped = true;
+            return;
+        }
+
+        if j == self.items.len() || c < self.min_w[j] {
+            if v > self.z {
+                self.z = v;
+                self.best_x.copy_from_slice(&self.x);
+            }
+
+            return;
+        }
+
+        if v + upper_bound(&self.items[j..], c) <= self.z {
+            return;
+        }
+
+        let item = self.items[j];
+        let e_next = match self.items.get(j + 1) {
+            Some(next) => next.p / next.w,
+            None => 0.0,
+        };
+
+        let max = (c / item.w).floor() as usize;
+        for count in (0..=max).rev() {
+            let rem = (c - count as f64 * item.w).max(0.0);
+            let val = v + count as f64 * item.p;
+
+            //the remaining items are less efficient, so using fewer copies of this one
+            //can only lower the bound further
+            if val + rem * e_next <= self.z {
+                break;
+            }
+
+            self.x[j] = count;
+            self.branch(j + 1, rem, val);
+        }
+
+        self.x[j] = 0;
+    }
+}
+
+//U3 bound of Martello and Toth on items sorted by decreasing efficiency
+fn upper_bound(items: &[ItemInfo], c: f64) -> f64 {
+    if items.is_empty() {
+        return 0.0;
+    }
+
+    let (p1, w1) = (items[0].p, items[0].w);
+    let x1 = (c / w1).floor();
+    let c1 = c - x1 * w1;
+    if items.len() == 1 {
+        return x1 * p1;
+    }
+
+    let (p2, w2) = (items[1].p, items[1].w);
+    let x2 = (c1 / w2).floor();
+    let c2 = c1 - x2 * w2;
+    let z = x1 * p1 + x2 * p2;
+    let e3 = if items.len() > 2 {
+        items[2].p / items[2].w
+    } else {
+        0.0
+    };
+
+    let u0 = z + c2 * e3;
+
+    //bound when at least one more copy of the second item is used, which requires
+    //removing k copies of the first
+    let k = ((w2 - c2) / w1).ceil();
+    if k > x1 {
+        return u0;
+    }
+
+    let u1 = z + (c2 + k * w1) * p2 / w2 - k * p1;
+    u0.max(u1)
+}
+
+//item i dominates item j if floor(w_j / w_i) copies of i are worth at least as much as j
+fn remove_dominated(items: &mut Vec<ItemInfo>) {
+    items.sort_by(|x, y| x.w.total_cmp(&y.w));
+    let mut kept: Vec<ItemInfo> = Vec::with_capacity(items.len());
+    'item: for item in items.iter() {
+        for other in kept.iter() {
+            if (item.w / other.w).floor() * other.p >= item.p {
+                continue 'item;
+            }
+        }
+
+        kept.push(*item);
+    }
+
+    *items = kept;
+}
+
+fn sort_by_efficiency(items: &mut [ItemInfo]) {
+    items.sort_by(|x, y| (y.p / y.w).total_cmp(&(x.p / x.w)));
+}
+
+//returns the number of copies of each item in the optimal solution, or in the best solution
+//found if the control stops the search, along with whether it did
+fn mtu2(profits: &[f64], weights: &[f64], c: f64, control: &SolveControl) -> (Vec<usize>, bool) {
+    let mut quantity = vec![0_usize; profits.len()];
+
+    //items with no weight have no value once the problem is checked, so they are ignored
+    let mut items: Vec<ItemInfo> = Vec::with_capacity(profits.len());
+    for j in 0..profits.len() {
+        if profits[j] > 0.0 && weights[j] > 0.0 && weights[j] <= c {
+            items.push(ItemInfo {
+                j: j,
+                p: profits[j],
+                w: weights[j],
+            });
+        }
+    }
+
+    if items.is_empty() {
+        return (quantity, false);
+    }
+
+    //dominated items never improve a solution, so they are removed from the whole problem
+    //before the core of the most efficient items is taken
+    remove_dominated(&mut items);
+    sort_by_efficiency(&mut items);
+    let all = items.clone();
+    let v = usize::max(100, items.len() / 100).min(items.len());
+    let mut rest = items.split_off(v);
+    let mut core = items;
+
+    let mut z = 0.0;
+    let mut best_x: Vec<usize> = Vec::new();
+    let mut nodes = 0;
+    let stopped = loop {
+        let mut bb = BranchAndBound::new(&core, z, control, nodes);
+        bb.branch(0, c, 0.0);
+        nodes = bb.nodes;
+        let stopped = bb.stopped;
+        if bb.z > z || best_x.is_empty() {
+            z = bb.z;
+            best_x = bb.best_x;
+        }
+
+        if stopped {
+            break true;
+        }
+
+        //any item outside the core that could still improve the solution is moved into it.
+        //the bound of a solution with at least one copy of the item is taken over every
+        //item, as the core alone may hold too few items for its bound to be valid
+        let mut extended = false;
+        let mut i = 0;
+        while i < rest.len() {
+            let item = rest[i];
+            if item.p + upper_bound(&all, c - item.w) > z {
+                core.push(rest.swap_remove(i));
+                extended = true;
+            } else {
+                i += 1;
+            }
+        }
+
+        if !extended {
+            break false;
+        }
+
+        //remember the incumbent so the next search starts from it
+        let mut incumbent: Vec<(usize, usize)> = Vec::with_capacity(core.len());
+        for (item, count) in core.iter().zip(best_x.iter()) {
+            incumbent.push((item.j, *count));
+        }
+
+        sort_by_efficiency(&mut core);
+        best_x = vec![0; core.len()];
+        for (j, count) in incumbent {
+            if let Some(pos) = core.iter().position(|x| x.j == j) {
+                best_x[pos] = count;
+            }
+        }
+    };
+
+    for (item, count) in core.iter().zip(best_x.iter()) {
+        quantity[item.j] = *count;
+    }
+
+    (quantity, stopped)
+}
+
+macro_rules! mtu2_impl {
+    ( $( $type:ty ),* ) => {
+        $(
+impl UnboundedSolver<$type, 1> for MTU2 {
+    type Output = ProblemKnapsacks<$type, 1>;
+
+    fn solve(self, problem: UnboundedProblem<$type, 1>) -> ProblemKnapsacks<$type, 1> {
+        self.solve_with(problem, &SolveControl::default())
+    }
+
+    fn solve_with(
+        self,
+        problem: UnboundedProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<$type, 1> {
+        self.solve_stats_with(problem, control).0
+    }
+
+    fn solve_stats_with(
+        self,
+        problem: UnboundedProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> (ProblemKnapsacks<$type, 1>, SolveStats) {
+        self.try_solve_stats_with(problem, control).unwrap()
+    }
+
+    fn try_solve_stats_with(
+        self,
+        mut problem: UnboundedProblem<$type, 1>,
+        control: &SolveControl,
+    ) -> Result<(ProblemKnapsacks<$type, 1>, SolveStats), KpError> {
+        problem.check_finite()?;
+        problem.check_bounded()?;
+
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let items = problem.items;
+        let knapsack = &mut problem.knapsacks[0];
+
+        let mut profits: Vec<f64> = Vec::with_capacity(items.len());
+        let mut weights: Vec<f64> = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            profits.push(item.value);
+            weights.push(item.weights[0] as f64);
+        }
+
+        let c = if knapsack.capacity[0] > knapsack.weights()[0] {
+            (knapsack.capacity[0] - knapsack.weights()[0]) as f64
+        } else {
+            0.0
+        };
+
+        let (quantity, stopped) = mtu2(&profits, &weights, c, control);
+        for (j, count) in quantity.iter().enumerate() {
+            if *count > 0 {
+                knapsack.add(Item::<$type, 1> {
+                    value: items[j].value,
+                    weights: items[j].weights,
+                    quantity: *count as $type,
+                    id: (),
+                });
+            }
+        }
+
+        if stopped {
+            stats.status = control.stopped_status();
+        } else if problem.knapsacks.len() <= 1 {
+            //only the first knapsack is filled
+            stats.status = Status::Optimal;
+        }
+
+        stats.phase("solve", start);
+        stats.time = start.elapsed();
+        Ok((problem.knapsacks, stats))
+    }
+}
+        )*
+    }
+}
+
+//exact algorithm of Martello and Toth for single knapsacks with one dimension.
+//solves a core of the most efficient undominated items by branch and bound, then extends
+//the core with any remaining item whose bound over every item exceeds the incumbent.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MTU2;
+mtu2_impl!(u32, f64);
+
+impl SolverInfo for MTU2 {
+    fn name(&self) -> &'static str {
+        "MTU2"
+    }
+}
@@ -62,11 +62,7 @@ where
     T: UnboundedCompatibility,
 {
     fn eq(&self, _rhs: &T) -> bool {
-        if T::is_unbounded() {
-            true
-        } else {
-            false
-        }
+        T::is_unbounded()
     }
 }
 
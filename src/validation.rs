@@ -0,0 +1,160 @@
+use crate::compatible_problem_type_trait::CompatibleProblemType;
+use std::fmt;
+
+//something about a problem that may make solvers fail or give poor answers.
+//items and knapsacks are referred to by their index in the order they were added
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Issue {
+    //a value, weight or quantity of the item is NaN or infinite
+    NonFiniteItem(usize),
+    //a capacity or weight of the knapsack is NaN or infinite
+    NonFiniteKnapsack(usize),
+    NegativeWeight { item: usize, dimension: usize },
+    NegativeQuantity(usize),
+    NegativeCapacity { knapsack: usize, dimension: usize },
+    //nothing with weight in this dimension can be packed in the knapsack
+    ZeroCapacity { knapsack: usize, dimension: usize },
+    //the item has neither value nor weight, so it cannot be ranked by efficiency
+    EmptyItem(usize),
+    //a single copy of the item does not fit in the remaining capacity of any knapsack
+    FitsNowhere(usize),
+    //the weight of every copy of the item is too large for the type of the problem
+    Overflow { item: usize, dimension: usize },
+    //the unbounded item has value but no weight, so any number of copies fit
+    UnboundedItem(usize),
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::NonFiniteItem(j) => write!(f, "item {j} has a number that is not finite"),
+            Issue::NonFiniteKnapsack(i) => {
+                write!(f, "knapsack {i} has a number that is not finite")
+            }
+            Issue::NegativeWeight { item, dimension } => {
+                write!(
+                    f,
+                    "item {item} has a negative weight in dimension {dimension}"
+                )
+            }
+            Issue::NegativeQuantity(j) => write!(f, "item {j} has a negative quantity"),
+            Issue::NegativeCapacity {
+                knapsack,
+                dimension,
+            } => write!(
+                f,
+                "knapsack {knapsack} has a negative capacity in dimension {dimension}"
+            ),
+            Issue::ZeroCapacity {
+                knapsack,
+                dimension,
+            } => write!(
+                f,
+                "knapsack {knapsack} has no capacity in dimension {dimension}"
+            ),
+            Issue::EmptyItem(j) => write!(f, "item {j} has neither value nor weight"),
+            Issue::FitsNowhere(j) => write!(f, "item {j} does not fit in any knapsack"),
+            Issue::Overflow { item, dimension } => write!(
+                f,
+                "the weight of item {item} in dimension {dimension} may overflow"
+            ),
+            Issue::UnboundedItem(j) => {
+                write!(f, "item {j} has value but no weight and is unbounded")
+            }
+        }
+    }
+}
+
+pub(crate) fn is_finite<T, const S: usize>(numbers: &[T; S]) -> bool
+where
+    T: CompatibleProblemType,
+{
+    numbers.iter().all(|x| Into::<f64>::into(*x).is_finite())
+}
+
+//the checks shared by every kind of problem. quantity is None for unbounded items
+pub(crate) fn validate<T, const S: usize>(
+    items: &[(f64, [T; S], Option<T>)],
+    knapsacks: &[([T; S], [T; S])],
+) -> Vec<Issue>
+where
+    T: CompatibleProblemType,
+{
+    let zero = <T as Default>::default();
+    let mut issues: Vec<Issue> = Vec::new();
+    let mut remaining: Vec<[f64; S]> = Vec::with_capacity(knapsacks.len());
+    for (i, (capacity, weights)) in knapsacks.iter().enumerate() {
+        if !is_finite(capacity) || !is_finite(weights) {
+            issues.push(Issue::NonFiniteKnapsack(i));
+            continue;
+        }
+
+        let mut remaining_i = [0.0; S];
+        for r in 0..S {
+            if capacity[r] < zero {
+                issues.push(Issue::NegativeCapacity {
+                    knapsack: i,
+                    dimension: r,
+                });
+            } else if capacity[r] == zero {
+                issues.push(Issue::ZeroCapacity {
+                    knapsack: i,
+                    dimension: r,
+                });
+            }
+
+            remaining_i[r] = Into::<f64>::into(capacity[r]) - Into::<f64>::into(weights[r]);
+        }
+
+        remaining.push(remaining_i);
+    }
+
+    for (j, (value, weights, quantity)) in items.iter().enumerate() {
+        if !value.is_finite() || !is_finite(weights) || quantity.is_some_and(|x| !is_finite(&[x])) {
+            issues.push(Issue::NonFiniteItem(j));
+            continue;
+        }
+
+        for r in 0..S {
+            if weights[r] < zero {
+                issues.push(Issue::NegativeWeight {
+                    item: j,
+                    dimension: r,
+                });
+            }
+        }
+
+        if quantity.is_some_and(|x| x < zero) {
+            issues.push(Issue::NegativeQuantity(j));
+        }
+
+        if *value == 0.0 && weights.iter().all(|x| *x == zero) {
+            issues.push(Issue::EmptyItem(j));
+        }
+
+        if quantity.is_none() && *value > 0.0 && weights.iter().all(|x| *x == zero) {
+            issues.push(Issue::UnboundedItem(j));
+        }
+
+        let fits = remaining
+            .iter()
+            .any(|c| (0..S).all(|r| Into::<f64>::into(weights[r]) <= c[r]));
+        if !fits {
+            issues.push(Issue::FitsNowhere(j));
+        }
+
+        if let Some(quantity) = quantity {
+            for r in 0..S {
+                let product = Into::<f64>::into(weights[r]) * Into::<f64>::into(*quantity);
+                if product > T::max_value() {
+                    issues.push(Issue::Overflow {
+                        item: j,
+                        dimension: r,
+                    });
+                }
+            }
+        }
+    }
+
+    issues
+}
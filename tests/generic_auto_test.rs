@@ -0,0 +1,179 @@
+#[allow(unused_imports)]
+use generic_data::Problems;
+use kpsolver::bounded_solvers::{self, Auto};
+use kpsolver::{DynamicMode, SolveControl, Status};
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+
+const MIP: bool = cfg!(any(feature = "cbc", feature = "highs", feature = "cplex"));
+
+selective_tests! {
+    fn auto_u32_test(Auto::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, Auto),
+            default_multi_constraint!(u32, Auto),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let problem = || fixtures::small::<u32>([2, 1, 1], &[10, 10]);
+
+            //the joint table takes (1 + 4) * 11 * 11 * 8 = 4840 bytes for the 4 copies and each
+            //table 440 bytes
+            let auto = Auto::new(10000, 0);
+            let choice = auto.explain_u32(&problem());
+            assert_eq!(choice.solver, "Dynamic");
+            assert!(choice.reason.contains("4840 bytes"));
+            assert_eq!(
+                problem().using(auto).value(),
+                problem()
+                    .using(bounded_solvers::Dynamic::new(DynamicMode::Joint))
+                    .value()
+            );
+
+            assert_eq!(problem().using(auto).value(), 15.0);
+
+            //the status is that of the solver picked
+            assert_eq!(problem().solution(auto).status, Status::Optimal);
+
+            let auto = Auto::new(1000, 0);
+            assert_eq!(auto.explain_u32(&problem()).solver, "Dynamic");
+            assert_eq!(
+                problem().using(auto).value(),
+                problem()
+                    .using(bounded_solvers::Dynamic::new(DynamicMode::Sequential))
+                    .value()
+            );
+
+            assert_eq!(problem().solution(auto).status, Status::Feasible);
+        }
+    }
+}
+
+selective_tests! {
+    fn auto_u32_greedy_test(Auto::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, Auto),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //no table fits, so the problem is solved as f64
+            let problem = || fixtures::small::<u32>([2, 1, 1], &[10, 10]);
+            let auto = Auto::new(100, 0);
+            let choice = auto.explain_u32(&problem());
+            assert_eq!(choice.solver, "Generalized Greedy");
+            assert_eq!(
+                choice.to_string(),
+                format!("{}: {}", choice.solver, choice.reason)
+            );
+
+            let solution = problem().using(auto);
+            for knapsack in solution.iter() {
+                assert!(knapsack.weights()[0] <= 10);
+            }
+
+            assert!(solution.value() > 0.0);
+            assert_eq!(problem().solution(auto).status, Status::Feasible);
+
+            //a single knapsack falls back to the heuristic if even the sparse table is over the
+            //budget
+            let single = fixtures::small::<u32>([2, 1, 1], &[10]);
+            let auto = Auto::new(0, 0);
+            let choice = auto.explain_u32(&single);
+            assert_eq!(choice.solver, "Dynamic");
+            assert!(choice.reason.contains("Generalized Greedy"));
+            let solution = single.solution(auto);
+            assert_eq!(solution.status, Status::Feasible);
+            assert!(solution.weights()[0] <= 10);
+        }
+    }
+}
+
+selective_tests! {
+    fn auto_exact_test(Auto::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(f64, Auto),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //a single knapsack of single copies is a binary problem
+            let auto = Auto::default();
+            let binary = || fixtures::sample_fractional(&[295.0]);
+            let choice = auto.explain_f64(&binary());
+            assert_eq!(choice.solver, "Minknap");
+            let solution = binary().solution(auto);
+            assert_eq!(solution.status, Status::Optimal);
+            assert_eq!(solution.value(), 298.0);
+
+            //several knapsacks of single copies go to MTM, whose status is forwarded when it is
+            //stopped
+            let binary_multiple = || fixtures::sample_fractional(&[150.5, 100.5]);
+            let choice = auto.explain_f64(&binary_multiple());
+            assert_eq!(choice.solver, "MTM");
+            assert!(choice.reason.contains("10 items for 2 knapsacks"));
+            let solution = binary_multiple().solution(auto);
+            assert_eq!(solution.status, Status::Optimal);
+            assert_eq!(solution.value(), 252.0);
+
+            let control = SolveControl::new(None, Some(1), None);
+            let solution = binary_multiple().solution_with(auto, &control);
+            assert_eq!(solution.status, Status::Feasible);
+            for knapsack in solution.iter() {
+                assert!(knapsack.weights()[0] <= knapsack.capacity[0]);
+            }
+
+            //items with as many copies as fit make an unbounded problem
+            let choice = auto.explain_f64(&fixtures::copies_as_fit());
+            assert_eq!(choice.solver, "MTU2");
+            let solution = fixtures::copies_as_fit().solution(auto);
+            assert_eq!(solution.status, Status::Optimal);
+            assert_eq!(solution.value(), 24.0);
+        }
+    }
+}
+
+//whole numbers are solved exactly by Dynamic
+#[test]
+fn auto_f64_whole_test() {
+    let auto = Auto::default();
+    let whole = || fixtures::small::<f64>([2, 1, 1], &[10]);
+    let choice = auto.explain_f64(&whole());
+    assert_eq!(choice.solver, "Dynamic");
+    assert!(choice.reason.starts_with("every number is a whole number"));
+    assert_eq!(whole().using(auto).value(), 8.0);
+}
+
+//fractional numbers fall back to a greedy solver without a linear program solver
+#[test]
+fn auto_f64_fractional_test() {
+    if MIP {
+        return;
+    }
+
+    let auto = Auto::default();
+    let choice = auto.explain_f64(&fixtures::small_fractional(&[10.0]));
+    assert_eq!(choice.solver, "Theoretical Greedy");
+    assert_eq!(
+        fixtures::small_fractional(&[10.0]).using(auto).value(),
+        fixtures::small_fractional(&[10.0])
+            .using(bounded_solvers::TheoreticalGreedy)
+            .value()
+    );
+
+    let choice = auto.explain_f64(&fixtures::small_fractional(&[10.0, 7.5]));
+    assert_eq!(choice.solver, "Generalized Greedy");
+    assert!(choice.reason.contains("2 knapsacks"));
+    assert_eq!(
+        fixtures::small_fractional(&[10.0, 7.5]).using(auto).value(),
+        fixtures::small_fractional(&[10.0, 7.5])
+            .using(bounded_solvers::GeneralizedGreedy)
+            .value()
+    );
+
+    //invalid problems are reported rather than solved
+    let mut problem = fixtures::small_fractional(&[10.0]);
+    problem.knapsacks.iter_mut().next().unwrap().capacity[0] = f64::NAN;
+    assert!(problem.try_using(auto).is_err());
+}
@@ -1,13 +1,13 @@
 use generic_data::Problems;
 #[allow(unused_imports)]
-use kpsolver::{binary_solvers, bounded_solvers};
+use kpsolver::{binary_solvers, bounded_solvers, DynamicMode};
 
 #[macro_use]
 mod generic_data;
 
 #[cfg(all(feature = "cplex", feature = "highs",))]
 selective_tests! {
-    fn binary_dynamic_test(binary_solvers::Dynamic, <f64 as PartialEq>::eq, 1.0) {
+    fn binary_dynamic_test(binary_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
         DEFAULT: {
             default_simple_binary!(u32, binary_solvers::Dynamic),
             default_multi_constraint_binary!(u32, binary_solvers::Dynamic),
@@ -19,7 +19,7 @@ selective_tests! {
                 let (solution, optimal_solution) =
                 generic_data::random_test::binary_random_test_from_u32::<_, _, _, _, 1>
                 (
-                    binary_solvers::Dynamic,
+                    binary_solvers::Dynamic::default(),
                     binary_solvers::CPLEX,
                     100,
                     1,
@@ -39,7 +39,7 @@ selective_tests! {
             let (solution, optimal_solution) =
             generic_data::random_test::binary_random_test_from_u32::<_, _, _, _, 2>
             (
-                binary_solvers::Dynamic,
+                binary_solvers::Dynamic::default(),
                 binary_solvers::HiGHS,
                 100,
                 1,
@@ -58,7 +58,7 @@ selective_tests! {
             let (solution, optimal_solution) =
             generic_data::random_test::binary_random_test_from_u32::<_, _, _, _, 3>
             (
-                binary_solvers::Dynamic,
+                binary_solvers::Dynamic::default(),
                 binary_solvers::CPLEX,
                 10,
                 1,
@@ -203,3 +203,484 @@ selective_tests! {
         CUSTOM: {}
     }
 }
+
+selective_tests! {
+    fn binary_branch_and_bound_test(binary_solvers::BranchAndBound, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::BranchAndBound),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            assert_eq!(
+                generic_data::default_simple::random_sample_1::<f64, _>(
+                    binary_solvers::BranchAndBound
+                )
+                .value(),
+                302.0
+            );
+
+            //compare against the dynamic solver
+            for _ in 0..100 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, u32, _, _, 1>
+                (
+                    binary_solvers::BranchAndBound,
+                    binary_solvers::Dynamic::default(),
+                    50,
+                    1,
+                    0.0,
+                    100.0,
+                    [0; 1],
+                    [100; 1],
+                    [500; 1],
+                    [1000; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+
+            //large test
+            {
+            let (solution, optimal_solution) =
+            generic_data::random_test::binary_random_test_from_u32::<u32, u32, _, _, 1>
+            (
+                binary_solvers::BranchAndBound,
+                binary_solvers::Dynamic::default(),
+                500,
+                1,
+                0.0,
+                100.0,
+                [0; 1],
+                [100; 1],
+                [2500; 1],
+                [5000; 1],
+            );
+
+            assert_eq!(solution.value(), optimal_solution.value());
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn binary_minknap_test(binary_solvers::Minknap, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Minknap),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            assert_eq!(
+                generic_data::default_simple::random_sample_1::<f64, _>(binary_solvers::Minknap)
+                    .value(),
+                302.0
+            );
+
+            //compare against the dynamic solver
+            for _ in 0..100 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, u32, _, _, 1>
+                (
+                    binary_solvers::Minknap,
+                    binary_solvers::Dynamic::default(),
+                    50,
+                    1,
+                    0.0,
+                    100.0,
+                    [0; 1],
+                    [100; 1],
+                    [500; 1],
+                    [1000; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+
+            //large number of items with large capacities, compare against branch and bound
+            {
+            let (solution, optimal_solution) =
+            generic_data::random_test::binary_random_test_from_u32::<f64, f64, _, _, 1>
+            (
+                binary_solvers::Minknap,
+                binary_solvers::BranchAndBound,
+                100000,
+                1,
+                0.0,
+                100000.0,
+                [0; 1],
+                [100000; 1],
+                [1000000000; 1],
+                [2000000000; 1],
+            );
+
+            assert_eq!(solution.value(), optimal_solution.value());
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn binary_mtm_test(binary_solvers::MTM, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(f64, binary_solvers::MTM),
+            default_multi_knapsack_binary!(f64, binary_solvers::MTM),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //a single knapsack, compare against the dynamic solver
+            for _ in 0..100 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<u32, u32, _, _, 1>
+                (
+                    binary_solvers::MTM,
+                    binary_solvers::Dynamic::default(),
+                    50,
+                    1,
+                    0.0,
+                    100.0,
+                    [0; 1],
+                    [100; 1],
+                    [500; 1],
+                    [1000; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+
+            //multiple knapsacks, must do at least as well as the greedy solver
+            for _ in 0..20 {
+                {
+                let (solution, greedy_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, f64, _, _, 1>
+                (
+                    binary_solvers::MTM,
+                    binary_solvers::GeneralizedGreedy,
+                    20,
+                    3,
+                    0.0,
+                    100.0,
+                    [1; 1],
+                    [100; 1],
+                    [100; 1],
+                    [200; 1],
+                );
+
+                assert!(solution.value() >= greedy_solution.value());
+                }
+            }
+
+            //items may have no weight, which the greedy solver ranks by value alone
+            for _ in 0..20 {
+                {
+                let (solution, greedy_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, f64, _, _, 1>
+                (
+                    binary_solvers::MTM,
+                    binary_solvers::GeneralizedGreedy,
+                    20,
+                    3,
+                    0.0,
+                    100.0,
+                    [0; 1],
+                    [100; 1],
+                    [100; 1],
+                    [200; 1],
+                );
+
+                assert!(solution.value() >= greedy_solution.value());
+                }
+            }
+
+            #[cfg(feature = "highs")]
+            for _ in 0..20 {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, f64, _, _, 1>
+                (
+                    binary_solvers::MTM,
+                    binary_solvers::HiGHS,
+                    20,
+                    3,
+                    0.0,
+                    100.0,
+                    [0; 1],
+                    [100; 1],
+                    [100; 1],
+                    [200; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn binary_dynamic_joint_test(
+        binary_solvers::Dynamic::new(DynamicMode::Joint),
+        <f64 as PartialEq>::eq,
+        1.0
+    ) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Dynamic),
+            default_multi_constraint_binary!(u32, binary_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //multiple knapsacks, compare against MTM
+            for _ in 0..20 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<u32, u32, _, _, 1>
+                (
+                    binary_solvers::Dynamic::new(DynamicMode::Joint),
+                    binary_solvers::MTM,
+                    10,
+                    2,
+                    0.0,
+                    100.0,
+                    [0; 1],
+                    [50; 1],
+                    [50; 1],
+                    [100; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn binary_dynamic_sequential_test(
+        binary_solvers::Dynamic::new(DynamicMode::Sequential),
+        <f64 as PartialOrd>::ge,
+        0.9
+    ) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Dynamic),
+            default_multi_knapsack_binary!(u32, binary_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {}
+    }
+}
+
+selective_tests! {
+    fn binary_dynamic_budget_test(
+        binary_solvers::Dynamic {
+            mode: DynamicMode::Joint,
+            memory_budget: 1 << 20,
+        },
+        <f64 as PartialEq>::eq,
+        1.0
+    ) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Dynamic),
+            default_multi_constraint_binary!(u32, binary_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //single knapsack, the dense memo takes at least 31 * 5001 * 8 bytes and the profit
+            //indexed memo at most 31 * 271 * 8 bytes
+            for _ in 0..20 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<u32, u32, _, _, 1>
+                (
+                    binary_solvers::Dynamic {
+                        mode: DynamicMode::Joint,
+                        memory_budget: 100000,
+                    },
+                    binary_solvers::Dynamic::new(DynamicMode::Joint),
+                    30,
+                    1,
+                    0.0,
+                    10.0,
+                    [1; 1],
+                    [1000; 1],
+                    [5000; 1],
+                    [10000; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+
+            //multiple knapsacks, the dense memo takes at least 11 * 51 * 51 * 8 bytes so the
+            //sparse memo is used
+            for _ in 0..20 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<u32, u32, _, _, 1>
+                (
+                    binary_solvers::Dynamic {
+                        mode: DynamicMode::Joint,
+                        memory_budget: 200000,
+                    },
+                    binary_solvers::Dynamic::new(DynamicMode::Joint),
+                    10,
+                    2,
+                    0.0,
+                    100.0,
+                    [0; 1],
+                    [50; 1],
+                    [50; 1],
+                    [100; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn binary_meet_in_the_middle_test(binary_solvers::MeetInTheMiddle, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::MeetInTheMiddle),
+            default_multi_constraint_binary!(u32, binary_solvers::MeetInTheMiddle),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //compare against the dynamic solver
+            for _ in 0..50 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, u32, _, _, 1>
+                (
+                    binary_solvers::MeetInTheMiddle,
+                    binary_solvers::Dynamic::default(),
+                    20,
+                    1,
+                    0.0,
+                    100.0,
+                    [0; 1],
+                    [100; 1],
+                    [500; 1],
+                    [1000; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+
+            //test 3 dimensions
+            for _ in 0..20 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, u32, _, _, 3>
+                (
+                    binary_solvers::MeetInTheMiddle,
+                    binary_solvers::Dynamic::default(),
+                    10,
+                    1,
+                    0.0,
+                    100.0,
+                    [0; 3],
+                    [10; 3],
+                    [10; 3],
+                    [20; 3],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+
+            //large capacities, compare against minknap
+            for _ in 0..5 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, f64, _, _, 1>
+                (
+                    binary_solvers::MeetInTheMiddle,
+                    binary_solvers::Minknap,
+                    30,
+                    1,
+                    0.0,
+                    1000.0,
+                    [100_000_000; 1],
+                    [1_000_000_000; 1],
+                    [2_000_000_000; 1],
+                    [4_000_000_000; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "metaheuristics")]
+selective_tests! {
+    fn binary_chu_beasley_test(binary_solvers::ChuBeasley::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(f64, binary_solvers::ChuBeasley),
+            default_multi_constraint_binary!(f64, binary_solvers::ChuBeasley),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //5 dimensions, must do at least as well as the theoretical greedy solver
+            for seed in 0..10 {
+                {
+                let (solution, greedy_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, f64, _, _, 5>
+                (
+                    binary_solvers::ChuBeasley::new(50, 2000, seed),
+                    binary_solvers::TheoreticalGreedy,
+                    50,
+                    1,
+                    1.0,
+                    100.0,
+                    [1; 5],
+                    [100; 5],
+                    [500; 5],
+                    [1000; 5],
+                );
+
+                assert!(solution.value() >= greedy_solution.value());
+                }
+            }
+
+            //compare against the dynamic solver
+            for seed in 0..10 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::binary_random_test_from_u32::<f64, u32, _, _, 2>
+                (
+                    binary_solvers::ChuBeasley::new(50, 2000, seed),
+                    binary_solvers::Dynamic::default(),
+                    20,
+                    1,
+                    0.0,
+                    100.0,
+                    [1; 2],
+                    [20; 2],
+                    [50; 2],
+                    [100; 2],
+                );
+
+                assert!(solution.value() >= optimal_solution.value() * 0.95);
+                }
+            }
+
+            //the same seed gives the same solution
+            let solution = generic_data::default_multi_constraint::random_sample_2::<f64, _>(
+                binary_solvers::ChuBeasley::new(10, 100, 7),
+            );
+            let other_solution = generic_data::default_multi_constraint::random_sample_2::<f64, _>(
+                binary_solvers::ChuBeasley::new(10, 100, 7),
+            );
+
+            assert_eq!(solution.value(), other_solution.value());
+            assert_eq!(solution[0].weights(), other_solution[0].weights());
+        }
+    }
+}
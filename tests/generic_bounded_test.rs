@@ -1,13 +1,16 @@
 use generic_data::Problems;
 #[allow(unused_imports)]
-use kpsolver::{binary_solvers, bounded_solvers};
+use kpsolver::{
+    binary_solvers, bounded_solvers, items, knapsacks, BoundedProblem, BoundedSolver, DynamicMode,
+    KpError, ProblemKnapsacks, Solution, SolveControl, Status,
+};
 
 #[macro_use]
 mod generic_data;
 
 #[cfg(all(feature = "cplex", feature = "highs",))]
 selective_tests! {
-    fn bounded_dynamic_test(bounded_solvers::Dynamic, <f64 as PartialEq>::eq, 1.0) {
+    fn bounded_dynamic_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
         DEFAULT: {
             default_simple!(u32, bounded_solvers::Dynamic),
             default_multi_constraint!(u32, bounded_solvers::Dynamic),
@@ -20,7 +23,7 @@ selective_tests! {
                 let (solution, optimal_solution) =
                 generic_data::random_test::bounded_random_test_from_u32::<_, _, _, _, 1>
                 (
-                    bounded_solvers::Dynamic,
+                    bounded_solvers::Dynamic::default(),
                     bounded_solvers::CPLEX,
                     100,
                     1,
@@ -43,7 +46,7 @@ selective_tests! {
             let (solution, optimal_solution) =
             generic_data::random_test::bounded_random_test_from_u32::<_, _, _, _, 1>
             (
-                bounded_solvers::Dynamic,
+                bounded_solvers::Dynamic::default(),
                 bounded_solvers::HiGHS,
                 2000,
                 1,
@@ -65,7 +68,7 @@ selective_tests! {
             let (solution, optimal_solution) =
             generic_data::random_test::bounded_random_test_from_u32::<_, _, _, _, 2>
             (
-                bounded_solvers::Dynamic,
+                bounded_solvers::Dynamic::default(),
                 bounded_solvers::CPLEX,
                 100,
                 1,
@@ -87,7 +90,7 @@ selective_tests! {
             let (solution, optimal_solution) =
             generic_data::random_test::bounded_random_test_from_u32::<_, _, _, _, 3>
             (
-                bounded_solvers::Dynamic,
+                bounded_solvers::Dynamic::default(),
                 bounded_solvers::CPLEX,
                 10,
                 1,
@@ -243,3 +246,423 @@ selective_tests! {
         CUSTOM: {}
     }
 }
+
+selective_tests! {
+    fn bounded_dynamic_joint_test(
+        bounded_solvers::Dynamic::new(DynamicMode::Joint),
+        <f64 as PartialEq>::eq,
+        1.0
+    ) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+            default_multi_constraint!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //multiple knapsacks, must do at least as well as filling them in sequence
+            for _ in 0..20 {
+                {
+                let (solution, sequential_solution) =
+                generic_data::random_test::bounded_random_test_from_u32::<u32, u32, _, _, 1>
+                (
+                    bounded_solvers::Dynamic::new(DynamicMode::Joint),
+                    bounded_solvers::Dynamic::new(DynamicMode::Sequential),
+                    5,
+                    2,
+                    0.0,
+                    100.0,
+                    [1; 1],
+                    [50; 1],
+                    1,
+                    3,
+                    [50; 1],
+                    [100; 1],
+                );
+
+                assert!(solution.value() >= sequential_solution.value());
+                }
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn bounded_dynamic_budget_test(
+        bounded_solvers::Dynamic {
+            mode: DynamicMode::Joint,
+            memory_budget: 1 << 20,
+        },
+        <f64 as PartialEq>::eq,
+        1.0
+    ) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+            default_multi_constraint!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //single knapsack, the dense memo takes at least 6 * 5001 * 8 bytes and the profit
+            //indexed memo at most 11 * 91 * 8 bytes
+            for _ in 0..20 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::bounded_random_test_from_u32::<u32, u32, _, _, 1>
+                (
+                    bounded_solvers::Dynamic {
+                        mode: DynamicMode::Joint,
+                        memory_budget: 100000,
+                    },
+                    bounded_solvers::Dynamic::new(DynamicMode::Joint),
+                    5,
+                    1,
+                    0.0,
+                    10.0,
+                    [1; 1],
+                    [1000; 1],
+                    1,
+                    3,
+                    [5000; 1],
+                    [10000; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+
+            //multiple knapsacks, the dense memo takes at least 6 * 51 * 51 * 8 bytes so the
+            //sparse memo is used
+            for _ in 0..20 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::bounded_random_test_from_u32::<u32, u32, _, _, 1>
+                (
+                    bounded_solvers::Dynamic {
+                        mode: DynamicMode::Joint,
+                        memory_budget: 100000,
+                    },
+                    bounded_solvers::Dynamic::new(DynamicMode::Joint),
+                    5,
+                    2,
+                    0.0,
+                    100.0,
+                    [1; 1],
+                    [50; 1],
+                    1,
+                    3,
+                    [50; 1],
+                    [100; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+        }
+    }
+}
+
+//solves with FPTAS, keeping the bound it reports
+#[derive(Clone, Copy)]
+struct FptasSolution(bounded_solvers::Fptas);
+impl BoundedSolver<f64, 1> for FptasSolution {
+    type Output = Solution<ProblemKnapsacks<f64, 1>>;
+
+    fn solve(self, problem: BoundedProblem<f64, 1>) -> Self::Output {
+        problem.solution(self.0)
+    }
+}
+
+#[test]
+fn bounded_fptas_test() {
+    //within (1 - epsilon) of the optimum, and the bound is never below it
+    for epsilon in [0.5, 0.1, 0.01] {
+        for _ in 0..20 {
+            let (solution, optimal_solution) =
+                generic_data::random_test::bounded_random_test_from_u32::<f64, u32, _, _, 1>(
+                    FptasSolution(bounded_solvers::Fptas::new(epsilon)),
+                    bounded_solvers::Dynamic::default(),
+                    20,
+                    1,
+                    0.0,
+                    100.0,
+                    [1; 1],
+                    [100; 1],
+                    1,
+                    5,
+                    [100; 1],
+                    [1000; 1],
+                );
+
+            let bound = solution.bound.unwrap();
+            assert!(solution.value() >= (1.0 - epsilon) * optimal_solution.value());
+            assert!(bound >= optimal_solution.value());
+            assert!(solution.value() <= bound);
+        }
+    }
+
+    let problem = || {
+        items! {
+            items<f64, 1>:
+                10.0, [5.0], 2.0;
+                7.0,  [3.0], 1.0;
+        }
+
+        knapsacks! {
+            knapsacks<f64, 1>:
+                [10.0];
+                [10.0];
+        }
+
+        items.insert_into(knapsacks)
+    };
+
+    //only the first knapsack is filled, so there is no bound on the problem
+    let solution = problem().solution(bounded_solvers::Fptas::new(0.1));
+    assert_eq!(solution.value(), 20.0);
+    assert_eq!(solution.bound, None);
+
+    //stopped before the dynamic program, the greedy solution is returned by decreasing
+    //efficiency
+    let control = SolveControl::new(None, Some(0), None);
+    let knapsacks = problem().using_with(bounded_solvers::Fptas::new(0.1), &control);
+    assert_eq!(knapsacks.value(), 17.0);
+
+    assert_eq!(
+        problem().try_using(bounded_solvers::Fptas::new(0.0)).err(),
+        Some(KpError::InvalidParameter("epsilon must be positive"))
+    );
+}
+
+#[test]
+fn bounded_nemhauser_ullmann_test() {
+    //the list must give the optimum for the problem's capacity and be free of dominated pairs
+    for _ in 0..20 {
+        let (pareto_list, optimal_solution) =
+            generic_data::random_test::bounded_random_test_from_u32::<u32, u32, _, _, 2>(
+                bounded_solvers::NemhauserUllmann,
+                bounded_solvers::Dynamic::default(),
+                8,
+                1,
+                0.0,
+                100.0,
+                [1; 2],
+                [20; 2],
+                1,
+                3,
+                [20; 2],
+                [40; 2],
+            );
+
+        let capacity = optimal_solution[0].capacity;
+        assert_eq!(
+            pareto_list.knapsacks(capacity).value(),
+            optimal_solution.value()
+        );
+        assert_eq!(
+            pareto_list.best(capacity).unwrap().1,
+            optimal_solution.value()
+        );
+
+        let pairs: Vec<([u32; 2], f64)> = pareto_list.iter().collect();
+        for (i, x) in pairs.iter().enumerate() {
+            for y in pairs[i + 1..].iter() {
+                assert!(x.1 <= y.1);
+                assert!(!(y.0[0] <= x.0[0] && y.0[1] <= x.0[1]));
+            }
+        }
+    }
+
+    //binary problems, materialised for several capacities
+    for _ in 0..20 {
+        let (pareto_list, optimal_solution) =
+            generic_data::random_test::binary_random_test_from_u32::<u32, u32, _, _, 1>(
+                bounded_solvers::NemhauserUllmann,
+                binary_solvers::Dynamic::default(),
+                20,
+                1,
+                0.0,
+                100.0,
+                [1; 1],
+                [50; 1],
+                [100; 1],
+                [200; 1],
+            );
+
+        let capacity = optimal_solution[0].capacity;
+        assert_eq!(
+            pareto_list.knapsacks(capacity).value(),
+            optimal_solution.value()
+        );
+
+        //larger capacities never give less
+        let mut value = 0.0;
+        for capacity in [0, 50, 150, 400] {
+            let solution = pareto_list.knapsacks([capacity]);
+            assert!(solution.value() >= value);
+            assert!(solution[0].weights()[0] <= capacity);
+            value = solution.value();
+        }
+    }
+
+    let problem = || {
+        items! {
+            items<f64, 1>:
+                10.0, [5.0], 2.0;
+                7.0,  [3.0], 1.0;
+        }
+
+        knapsacks! {
+            knapsacks<f64, 1>:
+                [10.0];
+        }
+
+        items.insert_into(knapsacks)
+    };
+
+    //the second copy of the first item shifts the lone copy onto itself, which is not kept
+    let (pareto_list, stats) = problem().using_stats(bounded_solvers::NemhauserUllmann);
+    assert_eq!(pareto_list.len(), 6);
+    assert_eq!(stats.states, 6);
+
+    //stopped after the first copy, the list only knows of that copy
+    let control = SolveControl::new(None, Some(1), None);
+    let pareto_list = problem().using_with(bounded_solvers::NemhauserUllmann, &control);
+    assert_eq!(pareto_list.len(), 2);
+
+    let solution = pareto_list.solution([10.0]);
+    assert_eq!(solution.value(), 10.0);
+    assert_eq!(solution.status, Status::Feasible);
+}
+
+#[cfg(feature = "metaheuristics")]
+selective_tests! {
+    fn bounded_simulated_annealing_test(
+        bounded_solvers::SimulatedAnnealing::default(),
+        <f64 as PartialOrd>::ge,
+        0.9
+    ) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::SimulatedAnnealing),
+            default_multi_constraint!(f64, bounded_solvers::SimulatedAnnealing),
+            default_multi_knapsack!(f64, bounded_solvers::SimulatedAnnealing),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //must do at least as well as the greedy solver it starts from
+            for seed in 0..10 {
+                {
+                let (solution, greedy_solution) =
+                generic_data::random_test::bounded_random_test_from_u32::<f64, f64, _, _, 2>
+                (
+                    bounded_solvers::SimulatedAnnealing::new(10000, None, seed),
+                    bounded_solvers::GeneralizedGreedy,
+                    30,
+                    3,
+                    1.0,
+                    100.0,
+                    [1; 2],
+                    [50; 2],
+                    1,
+                    3,
+                    [100; 2],
+                    [200; 2],
+                );
+
+                assert!(solution.value() >= greedy_solution.value());
+                }
+            }
+
+            //stops once the time limit has passed
+            let start = std::time::Instant::now();
+            generic_data::default_multi_knapsack::random_sample_1::<f64, _>(
+                bounded_solvers::SimulatedAnnealing::new(
+                    usize::MAX,
+                    Some(std::time::Duration::from_millis(100)),
+                    0,
+                ),
+            );
+
+            assert!(start.elapsed() < std::time::Duration::from_secs(5));
+        }
+    }
+}
+
+#[cfg(feature = "metaheuristics")]
+selective_tests! {
+    fn bounded_tabu_search_test(
+        bounded_solvers::TabuSearch::default(),
+        <f64 as PartialOrd>::ge,
+        0.9
+    ) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::TabuSearch),
+            default_multi_constraint!(f64, bounded_solvers::TabuSearch),
+            default_multi_knapsack!(f64, bounded_solvers::TabuSearch),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //must do at least as well as the greedy solver it starts from
+            for _ in 0..10 {
+                {
+                let (solution, greedy_solution) =
+                generic_data::random_test::bounded_random_test_from_u32::<f64, f64, _, _, 2>
+                (
+                    bounded_solvers::TabuSearch::new(100, None, 7),
+                    bounded_solvers::GeneralizedGreedy,
+                    30,
+                    3,
+                    1.0,
+                    100.0,
+                    [1; 2],
+                    [50; 2],
+                    1,
+                    3,
+                    [100; 2],
+                    [200; 2],
+                );
+
+                assert!(solution.value() >= greedy_solution.value());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "metaheuristics")]
+#[test]
+fn bounded_improve_test() {
+    //improving a solution keeps what the knapsacks held and never makes it worse
+    let problem = || {
+        items! {
+            items<f64, 1>:
+                30.0, [30.0], 2.0;
+                50.0, [36.0], 3.0;
+                35.0, [24.0], 1.0;
+                10.0, [48.0], 4.0;
+        }
+
+        knapsacks! {
+            knapsacks<f64, 1>:
+                [100.0];
+                [100.0];
+        }
+
+        knapsacks[0].add(kpsolver::Item::<f64, 1>::new(5.0, [10.0], 1.0));
+        items.insert_into(knapsacks)
+    };
+
+    let mut initial_solution = problem().knapsacks;
+    initial_solution[1].add(kpsolver::Item::<f64, 1>::new(10.0, [48.0], 2.0));
+    for solution in [
+        bounded_solvers::SimulatedAnnealing::default().improve(problem(), initial_solution.clone()),
+        bounded_solvers::TabuSearch::default().improve(problem(), initial_solution.clone()),
+    ] {
+        assert!(solution.value() >= initial_solution.value());
+        assert_eq!(solution[0].get_item((5.0, [10.0])).unwrap().quantity, 1.0);
+        for knapsack in solution.iter() {
+            assert!(knapsack.weights()[0] <= knapsack.capacity[0]);
+        }
+
+        //optimal: every copy of the second and third items and 1 of the first
+        assert_eq!(solution.value(), 5.0 + 50.0 * 3.0 + 35.0 + 30.0);
+    }
+}
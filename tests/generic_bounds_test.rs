@@ -0,0 +1,95 @@
+use kpsolver::{
+    bounded_solvers, bounds, items, knapsacks, BoundedProblem, BoundedSolver, DynamicMode,
+};
+
+#[macro_use]
+mod generic_data;
+
+//returns every bound of a problem so they can be compared against a solver
+#[derive(Clone, Copy, Debug)]
+struct Bounds;
+impl<const S: usize> BoundedSolver<f64, S> for Bounds {
+    type Output = [f64; 3];
+
+    fn solve(self, problem: BoundedProblem<f64, S>) -> [f64; 3] {
+        [
+            bounds::linear(&problem).unwrap(),
+            bounds::lagrangian(&problem).unwrap(),
+            bounds::surrogate(&problem).unwrap(),
+        ]
+    }
+}
+
+#[test]
+fn bounds_test() {
+    //single knapsack with one dimension
+    for _ in 0..50 {
+        let ([linear, lagrangian, surrogate], optimal_solution) =
+            generic_data::random_test::bounded_random_test_from_u32::<f64, u32, _, _, 1>(
+                Bounds,
+                bounded_solvers::Dynamic::default(),
+                20,
+                1,
+                0.0,
+                100.0,
+                [1; 1],
+                [100; 1],
+                1,
+                5,
+                [100; 1],
+                [1000; 1],
+            );
+
+        let optimum = optimal_solution.value();
+        assert!(linear >= optimum - 1e-6);
+        assert!(lagrangian >= optimum - 1e-6 && lagrangian <= linear + 1e-6);
+        assert!(surrogate >= optimum - 1e-6 && surrogate <= linear + 1e-6);
+    }
+
+    //multiple knapsacks with 2 dimensions
+    for _ in 0..20 {
+        let ([linear, lagrangian, surrogate], optimal_solution) =
+            generic_data::random_test::bounded_random_test_from_u32::<f64, u32, _, _, 2>(
+                Bounds,
+                bounded_solvers::Dynamic::new(DynamicMode::Joint),
+                5,
+                2,
+                0.0,
+                100.0,
+                [1; 2],
+                [5; 2],
+                1,
+                3,
+                [5; 2],
+                [10; 2],
+            );
+
+        let optimum = optimal_solution.value();
+        assert!(linear >= optimum - 1e-6);
+        assert!(lagrangian >= optimum - 1e-6 && lagrangian <= linear + 1e-6);
+        assert!(surrogate >= optimum - 1e-6 && surrogate <= linear + 1e-6);
+        assert!(bounds::gap(&optimal_solution, linear) >= 0.0);
+        assert!(bounds::gap(&optimal_solution, linear) < 1.0);
+    }
+
+    //the Dantzig bound of a known problem
+    let linear = generic_data::default_simple::random_sample_1::<f64, _>(Bounds)[0];
+    assert!((linear - (10.0 + 87.0 + 85.0 + 61.0 + 47.0 + 50.0 * 58.0 / 72.0)).abs() < 1e-6);
+
+    //with a single dimension nothing is relaxed, so the Lagrangian bound is the optimum
+    items! {
+        items<f64, 1>:
+            10.0, [5.0], 1.0;
+            7.0,  [4.0], 1.0;
+            5.0,  [3.0], 1.0;
+    }
+
+    knapsacks! {
+        knapsacks<f64, 1>:
+            [7.0];
+    }
+
+    let problem = items.insert_into(knapsacks);
+    assert!((bounds::linear(&problem).unwrap() - 13.5).abs() < 1e-6);
+    assert!((bounds::lagrangian(&problem).unwrap() - 12.0).abs() < 1e-6);
+}
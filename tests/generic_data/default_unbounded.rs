@@ -0,0 +1,82 @@
+use kpsolver::compatible_problem_type_trait::CompatibleProblemType;
+#[allow(unused_imports)]
+use kpsolver::{items, knapsacks, unbounded, UnboundedSolver};
+
+#[allow(dead_code)]
+pub fn random_sample_1<T, S>(solver: S) -> <S as UnboundedSolver<T, 1>>::Output
+where
+    T: CompatibleProblemType + From<u32>,
+    S: UnboundedSolver<T, 1>,
+{
+    items! {
+        items<u32, 1, unbounded>:
+            /* Value */ /* Weights */
+            1.0,        [1];
+            6.0,        [2];
+            10.0,       [3];
+            16.0,       [5];
+    }
+
+    knapsacks! {
+        knapsacks<u32, 1>:
+            [7];
+    }
+
+    items
+        .to_generic::<T>()
+        .insert_into(knapsacks.to_generic::<T>())
+        .using(solver)
+}
+
+#[allow(dead_code)]
+pub fn random_sample_2<T, S>(solver: S) -> <S as UnboundedSolver<T, 2>>::Output
+where
+    T: CompatibleProblemType + From<u32>,
+    S: UnboundedSolver<T, 2>,
+{
+    items! {
+        items<u32, 2, unbounded>:
+            /* Value */ /* Weights */
+            3.0,        [2, 1];
+            4.0,        [1, 3];
+            5.0,        [3, 3];
+    }
+
+    knapsacks! {
+        knapsacks<u32, 2>:
+            [7, 7];
+    }
+
+    items
+        .to_generic::<T>()
+        .insert_into(knapsacks.to_generic::<T>())
+        .using(solver)
+}
+
+#[allow(unused_macros)]
+macro_rules! default_unbounded {
+    ($type:ty, $solver:ty) => {
+        [
+            (
+                $crate::generic_data::Problems::<$type, $solver>::Bounded1Tuple(
+                    $crate::generic_data::default_unbounded::random_sample_1::<$type, $solver>,
+                ),
+                22.0,
+            ),
+        ]
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! default_unbounded_multi_constraint {
+    ($type:ty, $solver:ty) => {
+        [
+            (
+                $crate::generic_data::Problems::<$type, $solver>::Bounded2Tuple(
+                    $crate::generic_data::default_unbounded::random_sample_2::<$type, $solver>,
+                ),
+                13.0,
+            ),
+        ]
+    };
+}
@@ -0,0 +1,377 @@
+use kpsolver::compatible_problem_type_trait::CompatibleProblemType;
+use kpsolver::{
+    items, knapsacks, BinaryKnapsack, BinaryProblem, BinaryProblemItems, BinaryProblemKnapsacks,
+    BoundedProblem, DynBoundedProblem, DynItem, DynKnapsack, Item, Knapsack, ProblemItems,
+    ProblemKnapsacks,
+};
+
+//problems the tests of a feature solve more than once, rather than to compare solvers.
+//the items of default_simple::random_sample_1, given other quantities and knapsacks below
+#[allow(dead_code)]
+const SAMPLE: [(f64, u32); 10] = [
+    (55.0, 95),
+    (10.0, 4),
+    (47.0, 60),
+    (5.0, 32),
+    (4.0, 23),
+    (50.0, 72),
+    (8.0, 80),
+    (61.0, 62),
+    (85.0, 65),
+    (87.0, 46),
+];
+
+#[allow(dead_code)]
+pub fn sample<T>(quantities: [u32; 10], capacities: &[u32]) -> BoundedProblem<T, 1>
+where
+    T: CompatibleProblemType + From<u32>,
+{
+    let mut items = ProblemItems::<T, 1>::new();
+    for ((value, weight), quantity) in SAMPLE.into_iter().zip(quantities) {
+        items.add(Item::new(value, [T::from(weight)], T::from(quantity)));
+    }
+
+    let mut knapsacks = ProblemKnapsacks::<T, 1>::new();
+    for capacity in capacities {
+        knapsacks.add(Knapsack::new([T::from(*capacity)]));
+    }
+
+    items.insert_into(knapsacks)
+}
+
+//the sample with several copies of some items for two knapsacks
+#[allow(dead_code)]
+pub fn sample_copies<T>() -> BoundedProblem<T, 1>
+where
+    T: CompatibleProblemType + From<u32>,
+{
+    sample([2, 1, 1, 3, 1, 1, 2, 1, 1, 2], &[150, 145])
+}
+
+#[allow(dead_code)]
+pub fn sample_binary<T>(capacities: &[u32]) -> BinaryProblem<T, 1>
+where
+    T: CompatibleProblemType + From<u32>,
+{
+    let mut items = BinaryProblemItems::<T, 1>::new();
+    for (value, weight) in SAMPLE {
+        items.add(Item::binary(value, [T::from(weight)]));
+    }
+
+    let mut knapsacks = BinaryProblemKnapsacks::<T, 1>::new();
+    for capacity in capacities {
+        knapsacks.add(BinaryKnapsack::new([T::from(*capacity)]));
+    }
+
+    items.insert_into(knapsacks)
+}
+
+//the sample with a second dimension
+#[allow(dead_code)]
+pub fn sample_binary_2<T>(capacities: &[[u32; 2]]) -> BinaryProblem<T, 2>
+where
+    T: CompatibleProblemType + From<u32>,
+{
+    let second = [12, 60, 30, 5, 41, 20, 3, 70, 25, 50];
+    let mut items = BinaryProblemItems::<T, 2>::new();
+    for ((value, weight), other) in SAMPLE.into_iter().zip(second) {
+        items.add(Item::binary(value, [T::from(weight), T::from(other)]));
+    }
+
+    let mut knapsacks = BinaryProblemKnapsacks::<T, 2>::new();
+    for capacity in capacities {
+        knapsacks.add(BinaryKnapsack::new(capacity.map(T::from)));
+    }
+
+    items.insert_into(knapsacks)
+}
+
+//the sample with half a unit added to every weight, so it is not solved as whole numbers
+#[allow(dead_code)]
+pub fn sample_fractional(capacities: &[f64]) -> BoundedProblem<f64, 1> {
+    let mut items = ProblemItems::<f64, 1>::new();
+    for (value, weight) in SAMPLE {
+        items.add(Item::new(value, [weight as f64 + 0.5], 1.0));
+    }
+
+    let mut knapsacks = ProblemKnapsacks::<f64, 1>::new();
+    for capacity in capacities {
+        knapsacks.add(Knapsack::new([*capacity]));
+    }
+
+    items.insert_into(knapsacks)
+}
+
+//three items small enough that every table of the dynamic solvers can be counted by hand
+#[allow(dead_code)]
+pub fn small<T>(quantities: [u32; 3], capacities: &[u32]) -> BoundedProblem<T, 1>
+where
+    T: CompatibleProblemType + From<u32>,
+{
+    let mut items = ProblemItems::<T, 1>::new();
+    for ((value, weight), quantity) in [(3.0, 4), (4.0, 5), (5.0, 6)].into_iter().zip(quantities) {
+        items.add(Item::new(value, [T::from(weight)], T::from(quantity)));
+    }
+
+    let mut knapsacks = ProblemKnapsacks::<T, 1>::new();
+    for capacity in capacities {
+        knapsacks.add(Knapsack::new([T::from(*capacity)]));
+    }
+
+    items.insert_into(knapsacks)
+}
+
+#[allow(dead_code)]
+pub fn small_binary(capacities: &[u32]) -> BinaryProblem<u32, 1> {
+    let mut items = BinaryProblemItems::<u32, 1>::new();
+    for (value, weight) in [(3.0, 4), (4.0, 5), (5.0, 6)] {
+        items.add(Item::binary(value, [weight]));
+    }
+
+    let mut knapsacks = BinaryProblemKnapsacks::<u32, 1>::new();
+    for capacity in capacities {
+        knapsacks.add(BinaryKnapsack::new([*capacity]));
+    }
+
+    items.insert_into(knapsacks)
+}
+
+//the small items with the weight of the first made fractional
+#[allow(dead_code)]
+pub fn small_fractional(capacities: &[f64]) -> BoundedProblem<f64, 1> {
+    let mut items = ProblemItems::<f64, 1>::new();
+    items.add(Item::new(3.0, [4.5], 2.0));
+    items.add(Item::new(4.0, [5.0], 1.0));
+    items.add(Item::new(5.0, [6.0], 1.0));
+
+    let mut knapsacks = ProblemKnapsacks::<f64, 1>::new();
+    for capacity in capacities {
+        knapsacks.add(Knapsack::new([*capacity]));
+    }
+
+    items.insert_into(knapsacks)
+}
+
+//the dense memo has a row per copy of an item and a column per unit of capacity
+#[allow(dead_code)]
+pub fn copies() -> BoundedProblem<u32, 1> {
+    items! {
+        items<u32, 1>:
+            10.0, [5], 1;
+            7.0,  [3], 2;
+            4.0,  [2], 1;
+    }
+
+    knapsacks! {
+        knapsacks<u32, 1>:
+            [10];
+    }
+
+    items.insert_into(knapsacks)
+}
+
+//items with as many copies as fit in the knapsack
+#[allow(dead_code)]
+pub fn copies_as_fit() -> BoundedProblem<f64, 1> {
+    items! {
+        items<f64, 1>:
+            10.0, [5.5], 3.0;
+            7.0,  [4.5], 3.0;
+            3.0,  [2.5], 6.0;
+    }
+
+    knapsacks! {
+        knapsacks<f64, 1>:
+            [15.0];
+    }
+
+    items.insert_into(knapsacks)
+}
+
+//the first item has the given value, and the first two fit together in the given capacity
+#[allow(dead_code)]
+pub fn rows(value: f64, capacity: u32) -> BoundedProblem<u32, 1> {
+    items! {
+        items<u32, 1>:
+            value, [5], 1;
+            7.0,   [4], 1;
+            20.0,  [6], 1;
+    }
+
+    knapsacks! {
+        knapsacks<u32, 1>:
+            [capacity];
+    }
+
+    items.insert_into(knapsacks)
+}
+
+#[allow(dead_code)]
+pub fn rows_binary(value: f64, capacity: u32) -> BinaryProblem<u32, 1> {
+    let mut items = BinaryProblemItems::<u32, 1>::new();
+    items.add(Item::binary(value, [5]));
+    items.add(Item::binary(7.0, [4]));
+    items.add(Item::binary(20.0, [6]));
+
+    let mut knapsacks = BinaryProblemKnapsacks::<u32, 1>::new();
+    knapsacks.add(BinaryKnapsack::new([capacity]));
+    items.insert_into(knapsacks)
+}
+
+#[allow(dead_code)]
+pub fn two_dimensions(capacity: [u32; 2]) -> BoundedProblem<u32, 2> {
+    items! {
+        items<u32, 2>:
+            2.0,  [2, 2],  7;
+            5.0,  [5, 2],  7;
+            10.0, [10, 2], 7;
+    }
+
+    let mut knapsacks = ProblemKnapsacks::<u32, 2>::new();
+    knapsacks.add(Knapsack::new(capacity));
+    items.insert_into(knapsacks)
+}
+
+#[allow(dead_code)]
+const THREE_DIMENSIONS: [(f64, [f64; 3], f64); 4] = [
+    (4.0, [3.0, 1.0, 2.0], 2.0),
+    (5.0, [4.0, 2.0, 1.0], 1.0),
+    (7.0, [5.0, 3.0, 3.0], 1.0),
+    (2.0, [1.0, 1.0, 1.0], 3.0),
+];
+
+#[allow(dead_code)]
+pub fn three_dimensions() -> BoundedProblem<f64, 3> {
+    let mut items = ProblemItems::<f64, 3>::new();
+    for (value, weights, quantity) in THREE_DIMENSIONS {
+        items.add(Item::new(value, weights, quantity));
+    }
+
+    knapsacks! {
+        knapsacks<f64, 3>:
+            [10.0, 6.0, 6.0];
+            [5.0, 5.0, 5.0];
+    }
+
+    items.insert_into(knapsacks)
+}
+
+//the same problem with its dimensions given at runtime
+#[allow(dead_code)]
+pub fn three_dimensions_dyn() -> DynBoundedProblem<f64> {
+    let items = THREE_DIMENSIONS
+        .iter()
+        .map(|(value, weights, quantity)| DynItem::new(*value, weights.to_vec(), *quantity))
+        .collect();
+
+    let knapsacks = vec![
+        DynKnapsack::new(vec![10.0, 6.0, 6.0]),
+        DynKnapsack::new(vec![5.0, 5.0, 5.0]),
+    ];
+
+    DynBoundedProblem::new(items, knapsacks)
+}
+
+//the first two items have the same value and weights, told apart by their names
+#[allow(dead_code)]
+pub const PRODUCTS: [(&str, f64, [f64; 2], f64); 4] = [
+    ("bolt", 4.0, [3.0, 1.0], 2.0),
+    ("screw", 4.0, [3.0, 1.0], 2.0),
+    ("nut", 5.0, [4.0, 2.0], 1.0),
+    ("washer", 2.0, [1.0, 1.0], 3.0),
+];
+
+#[allow(dead_code)]
+pub const PRODUCT_CAPACITIES: [[f64; 2]; 2] = [[10.0, 5.0], [6.0, 4.0]];
+
+#[allow(dead_code)]
+pub fn products() -> BoundedProblem<f64, 2, f64, &'static str> {
+    let mut items = ProblemItems::<f64, 2, f64, &str>::new();
+    for (name, value, weights, quantity) in PRODUCTS {
+        items.add(Item::new(value, weights, quantity).with_id(name));
+    }
+
+    let mut knapsacks = ProblemKnapsacks::<f64, 2, &str>::new();
+    for capacity in PRODUCT_CAPACITIES {
+        knapsacks.add(Knapsack::new(capacity));
+    }
+
+    items.insert_into(knapsacks)
+}
+
+#[allow(dead_code)]
+pub fn products_binary() -> BinaryProblem<u32, 1, &'static str> {
+    let mut items = BinaryProblemItems::<u32, 1, &str>::new();
+    items.add(Item::binary(5.0, [3]).with_id("first"));
+    items.add(Item::binary(5.0, [3]).with_id("second"));
+    items.add(Item::binary(4.0, [2]).with_id("third"));
+
+    let mut knapsacks = BinaryProblemKnapsacks::<u32, 1, &str>::new();
+    knapsacks.add(BinaryKnapsack::new([6]));
+    items.insert_into(knapsacks)
+}
+
+//the smallest whole number f64 can not tell apart from the one after it
+#[allow(dead_code)]
+pub const BIG: u64 = 1 << 53;
+
+#[allow(dead_code)]
+pub fn exact_u64() -> BoundedProblem<u32, 1, u32, (), u64> {
+    let mut items = ProblemItems::<u32, 1, u32, (), u64>::new();
+    items.add(Item::new(BIG + 1, [2], 1));
+    items.add(Item::new(BIG, [2], 1));
+    items.add(Item::new(1, [1], 1));
+
+    let mut knapsacks = ProblemKnapsacks::<u32, 1, (), u64>::new();
+    knapsacks.add(Knapsack::new([2]));
+    knapsacks.add(Knapsack::new([3]));
+    items.insert_into(knapsacks)
+}
+
+#[allow(dead_code)]
+pub fn exact_i64_binary() -> BinaryProblem<u32, 1, (), i64> {
+    let mut items = BinaryProblemItems::<u32, 1, (), i64>::new();
+    items.add(Item::binary(BIG as i64 + 1, [2]));
+    items.add(Item::binary(BIG as i64, [2]));
+    items.add(Item::binary(-1, [1]));
+
+    let mut knapsacks = BinaryProblemKnapsacks::<u32, 1, (), i64>::new();
+    knapsacks.add(BinaryKnapsack::new([2]));
+    knapsacks.add(BinaryKnapsack::new([3]));
+    items.insert_into(knapsacks)
+}
+
+//the best of 19 for a value of 1, with the weights and values scaled by those given
+#[allow(dead_code)]
+pub fn scaled_i64(weight: u32, value: i64) -> BoundedProblem<u32, 1, u32, (), i64> {
+    let mut items = ProblemItems::<u32, 1, u32, (), i64>::new();
+    items.add(Item::new(7 * value, [3 * weight], 2));
+    items.add(Item::new(5 * value, [2 * weight], 3));
+    items.add(Item::new(value, [weight], 4));
+
+    let mut knapsacks = ProblemKnapsacks::<u32, 1, (), i64>::new();
+    knapsacks.add(Knapsack::new([8 * weight]));
+    items.insert_into(knapsacks)
+}
+
+//strongly correlated items spread over several knapsacks, too many for a linear program
+//solver to prove the best in well under a second
+#[allow(dead_code)]
+pub fn correlated(count: usize, knapsacks: usize) -> BoundedProblem<f64, 1> {
+    let mut items = ProblemItems::<f64, 1>::new();
+    let mut total = 0.0;
+    for j in 0..count {
+        let weight = (20 + (37 * j + 11 * j * j) % 81) as f64;
+        items.add(Item::new(weight + 10.0, [weight], 1.0));
+        total += weight;
+    }
+
+    //together the knapsacks hold about half of the weight
+    let capacity = (total / (2 * knapsacks) as f64).floor();
+    let mut problem_knapsacks = ProblemKnapsacks::<f64, 1>::new();
+    for i in 0..knapsacks {
+        problem_knapsacks.add(Knapsack::new([capacity + i as f64]));
+    }
+
+    items.insert_into(problem_knapsacks)
+}
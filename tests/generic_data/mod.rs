@@ -7,6 +7,9 @@ pub mod default_simple;
 pub mod default_multi_constraint;
 #[macro_use]
 pub mod default_multi_knapsack;
+#[macro_use]
+pub mod default_unbounded;
+pub mod fixtures;
 pub mod random_test;
 
 #[allow(dead_code)]
@@ -23,7 +26,7 @@ where
 
 macro_rules! selective_tests {
     (
-    fn $test_name:ident($solver:path, $cmp:expr, $scale:literal) {
+    fn $test_name:ident($solver:expr, $cmp:expr, $scale:literal) {
         DEFAULT: { $($default_arr:expr),* $(,)? }
         IGNORE: { $($ignore_fn:expr),* $(,)? }
         CUSTOM: { $($custom_call:tt)* }
@@ -0,0 +1,214 @@
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{
+    bounded_solvers, BoundedProblem, DynBoundedProblem, DynItem, DynKnapsack, Item, Knapsack,
+    KpError, SolveControl, MAX_DIMENSIONS,
+};
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+
+selective_tests! {
+    fn dyn_problem_generalized_greedy_test(bounded_solvers::GeneralizedGreedy, <f64 as PartialOrd>::ge, 0.5) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::GeneralizedGreedy),
+            default_multi_constraint!(f64, bounded_solvers::GeneralizedGreedy),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //the same problem with its dimensions given at runtime
+            assert_eq!(fixtures::three_dimensions_dyn().dimensions(), 3);
+            assert_eq!(
+                DynBoundedProblem::from(fixtures::three_dimensions()),
+                fixtures::three_dimensions_dyn()
+            );
+
+            //solving either gives the same knapsacks
+            let expected: Vec<DynKnapsack<f64>> = fixtures::three_dimensions()
+                .using(bounded_solvers::GeneralizedGreedy)
+                .into_iter()
+                .map(DynKnapsack::from)
+                .collect();
+
+            let knapsacks =
+                fixtures::three_dimensions_dyn().using(bounded_solvers::GeneralizedGreedy);
+            assert_eq!(knapsacks, expected);
+            for knapsack in knapsacks.iter() {
+                for r in 0..3 {
+                    assert!(knapsack.weights()[r] <= knapsack.capacity[r]);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "metaheuristics")]
+selective_tests! {
+    fn dyn_problem_tabu_search_test(
+        bounded_solvers::TabuSearch::default(),
+        <f64 as PartialOrd>::ge,
+        0.9
+    ) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::TabuSearch),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            assert_eq!(
+                fixtures::three_dimensions_dyn()
+                    .try_using(bounded_solvers::TabuSearch::default())
+                    .unwrap()
+                    .iter()
+                    .map(|x| x.value())
+                    .sum::<f64>(),
+                fixtures::three_dimensions()
+                    .using(bounded_solvers::TabuSearch::default())
+                    .value()
+            );
+        }
+    }
+}
+
+//conversions back to the const generic types check the dimensions
+#[test]
+fn dyn_problem_conversion_test() {
+    let converted = BoundedProblem::<f64, 3>::try_from(fixtures::three_dimensions_dyn()).unwrap();
+    assert_eq!(converted.items.len(), 4);
+    assert_eq!(converted.knapsacks.len(), 2);
+    assert_eq!(
+        BoundedProblem::<f64, 2>::try_from(fixtures::three_dimensions_dyn()).err(),
+        Some(KpError::ItemDimensions(0))
+    );
+
+    let item = DynItem::new(1.0, vec![1.0, 2.0], 1.0);
+    assert_eq!(
+        Item::<f64, 2>::try_from(item.clone()),
+        Ok(Item::new(1.0, [1.0, 2.0], 1.0))
+    );
+    assert_eq!(
+        Item::<f64, 3>::try_from(item.clone()),
+        Err(KpError::ItemDimensions(0))
+    );
+
+    let mut knapsack = DynKnapsack::new(vec![5.0, 5.0, 5.0]);
+    assert!(!knapsack.add(item));
+    assert!(knapsack.add(DynItem::binary(1.0, vec![1.0, 2.0, 3.0])));
+    assert_eq!(knapsack.weights(), &[1.0, 2.0, 3.0]);
+    assert!(Knapsack::<f64, 3>::try_from(knapsack.clone()).is_ok());
+
+    let mut shrunk = knapsack.clone();
+    shrunk.capacity[2] = 2.0;
+    assert_eq!(
+        Knapsack::<f64, 3>::try_from(shrunk).err(),
+        Some(KpError::KnapsackDimensions(0))
+    );
+
+    knapsack.capacity.pop();
+    assert_eq!(
+        Knapsack::<f64, 2>::try_from(knapsack).err(),
+        Some(KpError::KnapsackDimensions(0))
+    );
+}
+
+//weights too large for the type do not fit rather than wrapping around
+#[test]
+fn dyn_knapsack_overflow_test() {
+    let mut knapsack = DynKnapsack::new(vec![u32::MAX]);
+    assert!(knapsack.add(DynItem::binary(1.0, vec![u32::MAX - 1])));
+    assert!(!knapsack.add(DynItem::new(1.0, vec![2], 1)));
+    assert!(!knapsack.add(DynItem::new(1.0, vec![1 << 16], 1 << 16)));
+    assert_eq!(knapsack.weights(), &[u32::MAX - 1]);
+}
+
+//items and knapsacks must agree on the dimensions
+#[test]
+fn dyn_problem_dimensions_test() {
+    let mut mismatched = fixtures::three_dimensions_dyn();
+    mismatched.items[2].weights.pop();
+    assert_eq!(
+        mismatched.try_using(bounded_solvers::GeneralizedGreedy),
+        Err(KpError::ItemDimensions(2))
+    );
+
+    let mut mismatched = fixtures::three_dimensions_dyn();
+    mismatched.knapsacks[1].capacity.push(1.0);
+    assert_eq!(
+        mismatched.try_using(bounded_solvers::GeneralizedGreedy),
+        Err(KpError::KnapsackDimensions(1))
+    );
+
+    let mut mismatched = fixtures::three_dimensions_dyn();
+    mismatched.knapsacks[1].capacity.push(1.0);
+    assert_eq!(
+        mismatched.using_with(bounded_solvers::GeneralizedGreedy, &SolveControl::default()),
+        Err(KpError::KnapsackDimensions(1))
+    );
+
+    let unsupported = DynBoundedProblem::new(
+        Vec::new(),
+        vec![DynKnapsack::new(vec![1.0; MAX_DIMENSIONS + 1])],
+    );
+
+    assert_eq!(
+        unsupported.try_using(bounded_solvers::GeneralizedGreedy),
+        Err(KpError::TooManyDimensions(MAX_DIMENSIONS))
+    );
+
+    let empty = DynBoundedProblem::<f64>::new(Vec::new(), Vec::new());
+    assert_eq!(
+        empty.try_using(bounded_solvers::GeneralizedGreedy),
+        Err(KpError::UnsupportedDimensions(0))
+    );
+}
+
+//solver errors are reported as they are for the const generic types
+#[test]
+fn dyn_problem_error_test() {
+    let mut non_finite = fixtures::three_dimensions_dyn();
+    non_finite.items[1].value = f64::NAN;
+    assert_eq!(
+        non_finite
+            .clone()
+            .try_using(bounded_solvers::GeneralizedGreedy),
+        Err(KpError::NonFiniteItem(1))
+    );
+
+    assert_eq!(
+        non_finite.using_with(bounded_solvers::GeneralizedGreedy, &SolveControl::default()),
+        Err(KpError::NonFiniteItem(1))
+    );
+}
+
+selective_tests! {
+    fn dyn_problem_dynamic_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_multi_constraint!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let optimum = fixtures::two_dimensions([20, 8])
+                .using(bounded_solvers::Dynamic::default())
+                .value();
+            let knapsacks = DynBoundedProblem::from(fixtures::two_dimensions([20, 8]))
+                .using(bounded_solvers::Dynamic::default());
+            assert_eq!(knapsacks.len(), 1);
+            assert_eq!(knapsacks[0].value(), optimum);
+            assert_eq!(optimum, 20.0);
+
+            //as are errors of the solver under a control
+            let budget = bounded_solvers::Dynamic {
+                memory_budget: 0,
+                ..bounded_solvers::Dynamic::DEFAULT
+            };
+
+            assert_eq!(
+                DynBoundedProblem::from(fixtures::two_dimensions([20, 8]))
+                    .using_with(budget, &SolveControl::default()),
+                Err(KpError::MemoryBudget)
+            );
+        }
+    }
+}
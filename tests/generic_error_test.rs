@@ -0,0 +1,168 @@
+use kpsolver::{
+    binary_solvers, bounded_solvers, items, items_binary, knapsacks, knapsacks_binary, unbounded,
+    unbounded_solvers, BinaryItem, BinaryKnapsack, DynamicMode, KpError, SolveControl,
+};
+
+#[test]
+fn error_test() {
+    //numbers that are not finite are reported by every solver
+    #[cfg(feature = "metaheuristics")]
+    {
+        items! {
+            items<f64, 1>:
+                10.0,     [5.0], 1.0;
+                f64::NAN, [5.0], 1.0;
+        }
+
+        knapsacks! {
+            knapsacks<f64, 1>:
+                [10.0];
+        }
+
+        assert_eq!(
+            items
+                .insert_into(knapsacks)
+                .try_using(bounded_solvers::TabuSearch::default())
+                .err(),
+            Some(KpError::NonFiniteItem(1))
+        );
+    }
+
+    items_binary! {
+        items<f64, 1>:
+            10.0, 5.0;
+    }
+
+    knapsacks_binary! {
+        knapsacks<f64, 1>:
+            f64::INFINITY;
+    }
+
+    assert_eq!(
+        items
+            .insert_into(knapsacks)
+            .try_using(binary_solvers::Minknap)
+            .err(),
+        Some(KpError::NonFiniteKnapsack(0))
+    );
+
+    //items without weight are ranked by their value alone rather than failing to rank
+    items! {
+        items<f64, 1>:
+            10.0, [5.0], 1.0;
+            0.0,  [0.0], 1.0;
+            3.0,  [0.0], 2.0;
+    }
+
+    knapsacks! {
+        knapsacks<f64, 1>:
+            [10.0];
+    }
+
+    let solution = items
+        .insert_into(knapsacks)
+        .try_using(bounded_solvers::GeneralizedGreedy)
+        .unwrap();
+
+    assert_eq!(solution.value(), 16.0);
+
+    //valid problems are solved as usual
+    items! {
+        items<f64, 1>:
+            10.0, [5.0], 2.0;
+            6.0,  [4.0], 1.0;
+    }
+
+    knapsacks! {
+        knapsacks<f64, 1>:
+            [10.0];
+    }
+
+    let solution = items
+        .insert_into(knapsacks)
+        .try_using(bounded_solvers::TheoreticalGreedy)
+        .unwrap();
+
+    assert_eq!(solution.value(), 20.0);
+
+    //an unbounded item with value but no weight leaves no best solution
+    items! {
+        items<u32, 1, unbounded>:
+            10.0, [5];
+            1.0,  [0];
+    }
+
+    knapsacks! {
+        knapsacks<u32, 1>:
+            [10];
+    }
+
+    assert_eq!(
+        items
+            .insert_into(knapsacks)
+            .try_using(unbounded_solvers::Dynamic)
+            .err(),
+        Some(KpError::UnboundedItem(1))
+    );
+
+    //while one without value is left out
+    items! {
+        items<u32, 1, unbounded>:
+            10.0, [5];
+            0.0,  [0];
+    }
+
+    knapsacks! {
+        knapsacks<u32, 1>:
+            [10];
+    }
+
+    let solution = items
+        .insert_into(knapsacks)
+        .try_using(unbounded_solvers::Dynamic)
+        .unwrap();
+
+    assert_eq!(solution.value(), 20.0);
+
+    //solving under a control returns the error rather than panicking
+    items! {
+        items<u32, 1>:
+            10.0, [5], 2;
+            6.0,  [4], 1;
+    }
+
+    knapsacks! {
+        knapsacks<u32, 1>:
+            [10];
+    }
+
+    let solver = bounded_solvers::Dynamic {
+        mode: DynamicMode::Joint,
+        memory_budget: 0,
+    };
+
+    let control = SolveControl::time_limit(std::time::Duration::from_secs(1));
+    assert_eq!(
+        items
+            .insert_into(knapsacks)
+            .try_using_with(solver, &control)
+            .err(),
+        Some(KpError::MemoryBudget)
+    );
+
+    //meet in the middle enumerates the subsets of at most 25 items in each half
+    let mut items = items_binary!(u32, 1);
+    for j in 0..51 {
+        items.add(BinaryItem::<u32, 1>::binary(j as f64 + 1.0, [1]));
+    }
+
+    let mut knapsacks = knapsacks_binary!(u32, 1);
+    knapsacks.add(BinaryKnapsack::<u32, 1>::new([200]));
+    assert_eq!(
+        items
+            .insert_into(knapsacks)
+            .try_using(binary_solvers::MeetInTheMiddle)
+            .err(),
+        Some(KpError::TooManyItems(50))
+    );
+}
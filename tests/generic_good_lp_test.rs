@@ -0,0 +1,107 @@
+#![cfg(any(feature = "cbc", feature = "highs", feature = "cplex"))]
+
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{
+    bounded_solvers, BoundedSolver, KpError, ProblemKnapsacks, SolveControl, SolverInfo, Status,
+};
+use std::time::{Duration, Instant};
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+
+//without a deadline the backend solves to optimality
+fn optimal_check<N>(solver: N)
+where
+    N: BoundedSolver<f64, 1, Output = ProblemKnapsacks<f64, 1>> + SolverInfo,
+{
+    let optimum = fixtures::sample_copies::<u32>()
+        .using(bounded_solvers::Dynamic::default())
+        .value();
+
+    let solution = fixtures::sample_copies::<f64>().solution_with(solver, &SolveControl::default());
+    assert_eq!(solution.status, Status::Optimal);
+    assert_eq!(solution.value(), optimum);
+}
+
+//a problem too large to prove optimal by the deadline returns the best knapsacks found by
+//then, or an error from the backend if it found none, and how long the solve took
+fn deadline_check<N>(solver: N) -> Duration
+where
+    N: BoundedSolver<f64, 1, Output = ProblemKnapsacks<f64, 1>>,
+{
+    let control = SolveControl::time_limit(Duration::from_millis(200));
+    let start = Instant::now();
+    match solver.try_solve_stats_with(fixtures::correlated(400, 8), &control) {
+        Ok((knapsacks, stats)) => {
+            assert!(matches!(stats.status, Status::Optimal | Status::TimeLimit));
+            for knapsack in knapsacks.iter() {
+                assert!(knapsack.weights()[0] <= knapsack.capacity[0]);
+            }
+        }
+        Err(error) => assert!(matches!(error, KpError::Backend(_))),
+    }
+
+    return start.elapsed();
+}
+
+#[cfg(feature = "cbc")]
+selective_tests! {
+    fn good_lp_cbc_test(bounded_solvers::CBC, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::CBC),
+            default_multi_constraint!(f64, bounded_solvers::CBC),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            optimal_check(bounded_solvers::CBC);
+
+            //the deadline is passed on as the seconds CBC may search for
+            assert!(deadline_check(bounded_solvers::CBC) < Duration::from_secs(5));
+        }
+    }
+}
+
+#[cfg(feature = "highs")]
+selective_tests! {
+    fn good_lp_highs_test(bounded_solvers::HiGHS, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::HiGHS),
+            default_multi_constraint!(f64, bounded_solvers::HiGHS),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            optimal_check(bounded_solvers::HiGHS);
+
+            //the deadline is passed on as the time limit of HiGHS
+            assert!(deadline_check(bounded_solvers::HiGHS) < Duration::from_secs(5));
+        }
+    }
+}
+
+#[cfg(feature = "cplex")]
+selective_tests! {
+    fn good_lp_cplex_test(bounded_solvers::CPLEX, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::CPLEX),
+            default_multi_constraint!(f64, bounded_solvers::CPLEX),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            optimal_check(bounded_solvers::CPLEX);
+
+            //CPLEX can not be given the deadline, so it solves to optimality and reports the
+            //limit it ran past
+            let control = SolveControl::time_limit(Duration::ZERO);
+            let solution = fixtures::sample_copies::<f64>()
+                .solution_with(bounded_solvers::CPLEX, &control);
+            assert_eq!(solution.status, Status::TimeLimit);
+            for knapsack in solution.iter() {
+                assert!(knapsack.weights()[0] <= knapsack.capacity[0]);
+            }
+        }
+    }
+}
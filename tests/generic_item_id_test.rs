@@ -0,0 +1,214 @@
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{
+    binary_solvers, bounded_solvers, unbounded, unbounded_solvers, DynBoundedProblem, DynItem,
+    DynKnapsack, Item, Knapsack, KpError, ProblemItems, ProblemKnapsacks,
+};
+use std::collections::HashMap;
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+use generic_data::fixtures::{PRODUCTS, PRODUCT_CAPACITIES};
+
+//items with the same value and weights are kept apart by their ids
+#[test]
+fn item_id_distinct_test() {
+    assert_eq!(fixtures::products().items.len(), 4);
+
+    let mut anonymous = ProblemItems::<f64, 2>::new();
+    for (_, value, weights, quantity) in PRODUCTS {
+        anonymous.add(Item::new(value, weights, quantity));
+    }
+
+    assert_eq!(anonymous.len(), 3);
+}
+
+selective_tests! {
+    fn item_id_generalized_greedy_test(bounded_solvers::GeneralizedGreedy, <f64 as PartialOrd>::ge, 0.5) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::GeneralizedGreedy),
+            default_multi_constraint!(f64, bounded_solvers::GeneralizedGreedy),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //ids are kept through the solver and into the knapsacks
+            let mut anonymous = ProblemItems::<f64, 2>::new();
+            for (_, value, weights, quantity) in PRODUCTS {
+                anonymous.add(Item::new(value, weights, quantity));
+            }
+
+            let mut knapsacks = ProblemKnapsacks::<f64, 2>::new();
+            for capacity in PRODUCT_CAPACITIES {
+                knapsacks.add(Knapsack::new(capacity));
+            }
+
+            let expected = anonymous
+                .insert_into(knapsacks)
+                .using(bounded_solvers::GeneralizedGreedy);
+
+            let solution = fixtures::products().using_ids(bounded_solvers::GeneralizedGreedy);
+            assert_eq!(solution.value(), expected.value());
+            assert_eq!(solution.weights(), expected.weights());
+
+            let mut packed: HashMap<&str, f64> = HashMap::new();
+            for knapsack in solution.iter() {
+                for item in knapsack.iter() {
+                    *packed.entry(item.id).or_default() += item.quantity;
+                }
+            }
+
+            for (name, value, weights, quantity) in PRODUCTS {
+                let count = packed.get(name).copied().unwrap_or_default();
+                assert!(count <= quantity);
+                for knapsack in solution.iter() {
+                    for item in knapsack.iter().filter(|x| x.id == name) {
+                        assert_eq!((item.value, item.weights), (value, weights));
+                    }
+                }
+            }
+
+            //copies of interchangeable items are taken from the first added
+            assert_eq!(packed.get("bolt"), Some(&2.0));
+
+            //items already in a knapsack keep their ids
+            let mut problem_held = fixtures::products();
+            let mut knapsack = Knapsack::<f64, 2, &str>::new([10.0, 5.0]);
+            knapsack.add(Item::new(4.0, [3.0, 1.0], 1.0).with_id("screw"));
+            problem_held.knapsacks[0] = knapsack;
+            let solution = problem_held.using_ids(bounded_solvers::GeneralizedGreedy);
+            assert!(solution[0]
+                .iter()
+                .any(|x| x.id == "screw" && x.quantity >= 1.0));
+
+            let mut invalid = fixtures::products();
+            invalid.items[1].value = f64::NAN;
+            assert_eq!(
+                invalid
+                    .try_using_ids(bounded_solvers::GeneralizedGreedy)
+                    .err(),
+                Some(KpError::NonFiniteItem(1))
+            );
+        }
+    }
+}
+
+selective_tests! {
+    fn item_id_dynamic_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //ids may be any payload that can be hashed
+            let mut items = ProblemItems::<u32, 1, u32, (u32, String)>::new();
+            items.add(Item::new(3.0, [2], 1).with_id((7, "first".to_string())));
+            items.add(Item::new(3.0, [2], 1).with_id((8, "second".to_string())));
+            let mut knapsacks = ProblemKnapsacks::<u32, 1, (u32, String)>::new();
+            knapsacks.add(Knapsack::new([4]));
+            let solution = items
+                .insert_into(knapsacks)
+                .using_ids(bounded_solvers::Dynamic::default());
+
+            assert_eq!(solution.value(), 6.0);
+            assert_eq!(solution[0].len(), 2);
+        }
+    }
+}
+
+selective_tests! {
+    fn item_id_binary_dynamic_test(binary_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //each copy placed is given the id of a different item
+            let solution = fixtures::products_binary().using_ids(binary_solvers::Dynamic::default());
+            assert_eq!(solution.value(), 10.0);
+
+            let mut ids: Vec<&str> = solution[0].iter().map(|x| x.id).collect();
+            ids.sort();
+            assert_eq!(ids, ["first", "second"]);
+
+            let mut invalid = fixtures::products_binary();
+            invalid.items[2].value = f64::NAN;
+            assert_eq!(
+                invalid
+                    .try_using_ids(binary_solvers::Dynamic::default())
+                    .err(),
+                Some(KpError::NonFiniteItem(2))
+            );
+        }
+    }
+}
+
+selective_tests! {
+    fn item_id_unbounded_dynamic_test(unbounded_solvers::Dynamic, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_unbounded!(u32, unbounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let mut items = ProblemItems::<u32, 1, unbounded, &str>::new();
+            items.add(Item::new(5.0, [3], unbounded).with_id("first"));
+            items.add(Item::new(5.0, [3], unbounded).with_id("second"));
+            items.add(Item::new(3.0, [2], unbounded).with_id("third"));
+            assert_eq!(items.len(), 3);
+
+            let mut knapsacks = ProblemKnapsacks::<u32, 1, &str>::new();
+            knapsacks.add(Knapsack::new([9]));
+
+            //copies of an unbounded item never run out, so all are given the first id
+            let solution = items
+                .insert_into(knapsacks)
+                .using_ids(unbounded_solvers::Dynamic);
+
+            assert_eq!(solution.value(), 15.0);
+            assert_eq!(solution[0].len(), 1);
+            assert_eq!((solution[0][0].id, solution[0][0].quantity), ("first", 3));
+        }
+    }
+}
+
+selective_tests! {
+    fn item_id_dyn_problem_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_multi_constraint!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let items = vec![
+                DynItem::binary(5.0, vec![3, 1]).with_id("first"),
+                DynItem::binary(5.0, vec![3, 1]).with_id("second"),
+                DynItem::binary(4.0, vec![2, 1]).with_id("third"),
+            ];
+
+            //items held keep their ids through the conversion and the solver
+            let mut knapsack = DynKnapsack::<u32, &str>::new(vec![9, 4]);
+            assert!(knapsack.add(DynItem::binary(4.0, vec![2, 1]).with_id("held")));
+            assert!(knapsack.add(DynItem::binary(4.0, vec![2, 1]).with_id("third")));
+            assert_eq!(knapsack.len(), 2);
+
+            let knapsacks = DynBoundedProblem::new(items, vec![knapsack])
+                .using_ids(bounded_solvers::Dynamic::default());
+            assert_eq!(knapsacks[0].value(), 17.0);
+
+            let mut ids: Vec<&str> = knapsacks[0].iter().map(|x| x.id).collect();
+            ids.sort();
+            assert_eq!(ids, ["first", "held", "third"]);
+
+            let mismatched = DynBoundedProblem::new(
+                vec![DynItem::binary(1.0, vec![1]).with_id("first")],
+                vec![DynKnapsack::<u32, &str>::new(vec![1, 1])],
+            );
+
+            assert_eq!(
+                mismatched.try_using_ids(bounded_solvers::Dynamic::default()),
+                Err(KpError::ItemDimensions(0))
+            );
+        }
+    }
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "metaheuristics")]
+
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{binary_solvers, bounded_solvers, Objective, Observer, SolveControl};
+use std::time::Duration;
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+
+#[derive(Default)]
+struct Recorder {
+    incumbents: Vec<f64>,
+    bounds: Vec<f64>,
+    iterations: usize,
+}
+
+impl<K: Objective> Observer<K> for Recorder {
+    fn on_incumbent(&mut self, incumbent: &K) {
+        self.incumbents.push(incumbent.value());
+    }
+
+    fn on_bound(&mut self, bound: f64) {
+        self.bounds.push(bound);
+    }
+
+    fn on_progress(&mut self, iterations: usize, _elapsed: Duration) {
+        self.iterations = iterations;
+    }
+}
+
+selective_tests! {
+    fn observer_tabu_search_test(
+        bounded_solvers::TabuSearch::default(),
+        <f64 as PartialOrd>::ge,
+        0.9
+    ) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::TabuSearch),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //local searches report every improvement, ending with the knapsacks they return
+            let mut recorder = Recorder::default();
+            let solution = fixtures::sample_copies::<f64>().using_observed(
+                bounded_solvers::TabuSearch::default(),
+                &SolveControl::default(),
+                &mut recorder,
+            );
+
+            assert!(recorder.incumbents.windows(2).all(|x| x[0] < x[1]));
+            assert_eq!(recorder.incumbents.last(), Some(&solution.value()));
+            assert!(recorder.iterations > 0);
+
+            //the unit type observes nothing
+            let solution = fixtures::sample_copies::<f64>().using_observed(
+                bounded_solvers::TabuSearch::default(),
+                &SolveControl::default(),
+                &mut (),
+            );
+            assert_eq!(
+                solution.value(),
+                fixtures::sample_copies::<f64>()
+                    .using(bounded_solvers::TabuSearch::default())
+                    .value()
+            );
+        }
+    }
+}
+
+selective_tests! {
+    fn observer_simulated_annealing_test(
+        bounded_solvers::SimulatedAnnealing::default(),
+        <f64 as PartialOrd>::ge,
+        0.9
+    ) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::SimulatedAnnealing),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let mut recorder = Recorder::default();
+            let solution = fixtures::sample_copies::<f64>().using_observed(
+                bounded_solvers::SimulatedAnnealing::default(),
+                &SolveControl::default(),
+                &mut recorder,
+            );
+
+            assert!(recorder.incumbents.windows(2).all(|x| x[0] < x[1]));
+            assert_eq!(recorder.incumbents.last(), Some(&solution.value()));
+            assert!(recorder.iterations > 0);
+        }
+    }
+}
+
+selective_tests! {
+    fn observer_chu_beasley_test(binary_solvers::ChuBeasley::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(f64, binary_solvers::ChuBeasley),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //Chu-Beasley also reports the bound of the linear relaxation
+            let mut recorder = Recorder::default();
+            let solution = fixtures::sample_binary_2::<f64>(&[[295, 150]]).using_observed(
+                binary_solvers::ChuBeasley::new(20, 500, 0),
+                &SolveControl::default(),
+                &mut recorder,
+            );
+
+            assert!(recorder.incumbents.windows(2).all(|x| x[0] < x[1]));
+            assert_eq!(recorder.incumbents.last(), Some(&solution.value()));
+            assert!(recorder.bounds.iter().all(|x| *x >= solution.value()));
+            assert_eq!(recorder.bounds.len(), 1);
+
+            //but not when it only fills the first of several knapsacks
+            let two_knapsacks = || fixtures::sample_binary_2::<f64>(&[[295, 150], [100, 100]]);
+            let mut recorder = Recorder::default();
+            let (_, stats) =
+                two_knapsacks().using_stats(binary_solvers::ChuBeasley::new(20, 500, 0));
+            two_knapsacks().using_observed(
+                binary_solvers::ChuBeasley::new(20, 500, 0),
+                &SolveControl::default(),
+                &mut recorder,
+            );
+            assert_eq!(stats.bound, None);
+            assert!(recorder.bounds.is_empty());
+        }
+    }
+}
+
+selective_tests! {
+    fn observer_generalized_greedy_test(bounded_solvers::GeneralizedGreedy, <f64 as PartialOrd>::ge, 0.5) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::GeneralizedGreedy),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //other solvers report the knapsacks they return
+            let mut recorder = Recorder::default();
+            let solution = fixtures::sample_copies::<f64>().using_observed(
+                bounded_solvers::GeneralizedGreedy,
+                &SolveControl::default(),
+                &mut recorder,
+            );
+
+            assert_eq!(recorder.incumbents, vec![solution.value()]);
+            assert!(recorder.bounds.is_empty());
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use kpsolver::{
+    bounded_solvers, BoundedProblem, BoundedSolver, KpError, Portfolio, ProblemKnapsacks,
+    SolveControl, SolverInfo, Status,
+};
+use std::time::Duration;
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+
+//searches until it is stopped without improving on the knapsacks it was given
+#[derive(Clone, Copy)]
+struct Idle;
+
+impl BoundedSolver<u32, 1> for Idle {
+    type Output = ProblemKnapsacks<u32, 1>;
+
+    fn solve(self, problem: BoundedProblem<u32, 1>) -> ProblemKnapsacks<u32, 1> {
+        self.solve_with(problem, &SolveControl::default())
+    }
+
+    fn solve_with(
+        self,
+        problem: BoundedProblem<u32, 1>,
+        control: &SolveControl,
+    ) -> ProblemKnapsacks<u32, 1> {
+        while !control.should_stop(0) {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        problem.knapsacks
+    }
+}
+
+impl SolverInfo for Idle {
+    fn name(&self) -> &'static str {
+        "Idle"
+    }
+}
+
+//an optimal solution ends the race without a deadline
+#[test]
+fn portfolio_optimal_test() {
+    let optimum = fixtures::sample::<u32>([1; 10], &[295])
+        .using(bounded_solvers::Dynamic::default())
+        .value();
+
+    let mut portfolio = Portfolio::<u32, 1>::new();
+    portfolio.add(Idle);
+    portfolio.add(bounded_solvers::Dynamic::default());
+    assert_eq!(portfolio.len(), 2);
+
+    let solution = portfolio.solve(fixtures::sample([1; 10], &[295]));
+    assert_eq!(solution.value(), optimum);
+    assert_eq!(solution.status, Status::Optimal);
+    assert_eq!(solution.solver, "Dynamic");
+
+    //an exact solver stopped by the iteration limit is not taken as optimal
+    let solution = portfolio.solve_with(
+        fixtures::sample([1; 10], &[295]),
+        &SolveControl::new(None, Some(0), None),
+    );
+    assert_eq!(solution.value(), 0.0);
+    assert_eq!(solution.status, Status::Feasible);
+}
+
+//otherwise the best knapsacks returned by the deadline win
+#[cfg(feature = "metaheuristics")]
+#[test]
+fn portfolio_deadline_test() {
+    let problem = || fixtures::sample::<f64>([1; 10], &[295]);
+    let optimum = fixtures::sample::<u32>([1; 10], &[295])
+        .using(bounded_solvers::Dynamic::default())
+        .value();
+
+    let mut portfolio = Portfolio::<f64, 1>::new();
+    portfolio.add(bounded_solvers::GeneralizedGreedy);
+    portfolio.add(bounded_solvers::TabuSearch::default());
+    portfolio.add(bounded_solvers::SimulatedAnnealing::new(
+        usize::MAX,
+        None,
+        0,
+    ));
+
+    let start = std::time::Instant::now();
+    let solution = portfolio.solve_with(
+        problem(),
+        &SolveControl::time_limit(Duration::from_millis(200)),
+    );
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(solution.status, Status::TimeLimit);
+    assert!(solution.value() <= optimum);
+    assert!(solution.weights()[0] <= 295.0);
+    assert!(solution.value() >= problem().using(bounded_solvers::GeneralizedGreedy).value());
+}
+
+//if no solver returns by the deadline the first knapsacks returned win
+#[test]
+fn portfolio_idle_test() {
+    let mut portfolio = Portfolio::<u32, 1>::new();
+    portfolio.add(Idle);
+    let solution = portfolio.solve_with(
+        fixtures::sample([1; 10], &[295]),
+        &SolveControl::time_limit(Duration::ZERO),
+    );
+    assert_eq!(solution.value(), 0.0);
+    assert_eq!(solution.solver, "Idle");
+}
+
+//solvers that return errors drop out, and the last error is returned if all of them do
+#[test]
+fn portfolio_error_test() {
+    let mut portfolio = Portfolio::<u32, 1>::new();
+    portfolio.add(bounded_solvers::Dynamic {
+        memory_budget: 0,
+        ..bounded_solvers::Dynamic::DEFAULT
+    });
+    assert_eq!(
+        portfolio.try_solve(fixtures::sample([1; 10], &[295])).err(),
+        Some(KpError::MemoryBudget)
+    );
+
+    portfolio.add(bounded_solvers::Dynamic::default());
+    assert_eq!(
+        portfolio.solve(fixtures::sample([1; 10], &[295])).value(),
+        fixtures::sample::<u32>([1; 10], &[295])
+            .using(bounded_solvers::Dynamic::default())
+            .value()
+    );
+
+    //invalid problems are reported rather than raced
+    let mut portfolio = Portfolio::<f64, 1>::new();
+    portfolio.add(bounded_solvers::GeneralizedGreedy);
+    let mut problem = fixtures::sample([1; 10], &[295]);
+    problem.knapsacks.iter_mut().next().unwrap().capacity[0] = f64::INFINITY;
+    assert!(portfolio.try_solve(problem).is_err());
+
+    //an empty portfolio has nothing to race
+    assert_eq!(
+        Portfolio::<f64, 1>::new()
+            .try_solve(fixtures::sample([1; 10], &[295]))
+            .err(),
+        Some(KpError::InvalidParameter("the portfolio has no solvers"))
+    );
+}
@@ -0,0 +1,127 @@
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{bounded_solvers, SolveControl, SolverRegistry};
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+
+//built-in solvers are registered as their features allow
+#[test]
+fn registry_builtin_test() {
+    let registry = SolverRegistry::<f64, 1>::builtin();
+    for name in ["auto", "generalized_greedy", "theoretical_greedy"] {
+        assert!(registry.contains(name));
+    }
+
+    for name in ["simulated_annealing", "tabu_search"] {
+        assert_eq!(registry.contains(name), cfg!(feature = "metaheuristics"));
+    }
+
+    assert_eq!(registry.names().count(), registry.len());
+    assert_eq!(registry.contains("cbc"), cfg!(feature = "cbc"));
+    assert_eq!(registry.contains("highs"), cfg!(feature = "highs"));
+    assert!(registry.get("dynamic").is_none());
+
+    //each type of problem has its own solvers
+    let registry = SolverRegistry::<u32, 2>::builtin();
+    assert_eq!(
+        registry.names().collect::<Vec<_>>(),
+        vec!["auto", "dynamic"]
+    );
+}
+
+selective_tests! {
+    fn registry_generalized_greedy_test(bounded_solvers::GeneralizedGreedy, <f64 as PartialOrd>::ge, 0.5) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::GeneralizedGreedy),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //built-in solvers give the same answers through the registry
+            let registry = SolverRegistry::<f64, 1>::builtin();
+            let solver = registry.get("generalized_greedy").unwrap();
+            let expected = fixtures::sample_copies::<f64>()
+                .using(bounded_solvers::GeneralizedGreedy)
+                .value();
+
+            assert_eq!(fixtures::sample_copies::<f64>().using_dyn(solver).value(), expected);
+            assert_eq!(
+                solver
+                    .dyn_try_solve(fixtures::sample_copies::<f64>())
+                    .unwrap()
+                    .value(),
+                expected
+            );
+        }
+    }
+}
+
+#[cfg(feature = "metaheuristics")]
+selective_tests! {
+    fn registry_tabu_search_test(
+        bounded_solvers::TabuSearch::default(),
+        <f64 as PartialOrd>::ge,
+        0.9
+    ) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::TabuSearch),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let registry = SolverRegistry::<f64, 1>::builtin();
+            let solver = registry.get("tabu_search").unwrap();
+            assert_eq!(
+                solver
+                    .dyn_solve_with(fixtures::sample_copies::<f64>(), &SolveControl::default())
+                    .value(),
+                fixtures::sample_copies::<f64>()
+                    .using(bounded_solvers::TabuSearch::default())
+                    .value()
+            );
+        }
+    }
+}
+
+//solvers can be added, replaced and removed
+#[cfg(feature = "metaheuristics")]
+#[test]
+fn registry_register_test() {
+    let mut registry = SolverRegistry::<f64, 1>::new();
+    registry.register("local", bounded_solvers::TabuSearch::new(10, None, 3));
+    registry.register(
+        "local",
+        bounded_solvers::SimulatedAnnealing::new(10, None, 0),
+    );
+    assert_eq!(registry.len(), 1);
+
+    let solvers: Vec<Box<dyn kpsolver::DynBoundedSolver<f64, 1>>> =
+        vec![registry.remove("local").unwrap()];
+    assert!(registry.get("local").is_none());
+    assert!(
+        solvers[0]
+            .dyn_solve(fixtures::sample_copies::<f64>())
+            .value()
+            > 0.0
+    );
+}
+
+selective_tests! {
+    fn registry_dynamic_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_multi_constraint!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let registry = SolverRegistry::<u32, 2>::builtin();
+            assert_eq!(
+                fixtures::two_dimensions([10, 7])
+                    .using_dyn(registry.get("dynamic").unwrap())
+                    .value(),
+                10.0
+            );
+        }
+    }
+}
@@ -0,0 +1,230 @@
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{binary_solvers, bounded_solvers, bounds, DynamicMode, SolveControl, Status};
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+
+selective_tests! {
+    fn solution_mtm_test(binary_solvers::MTM, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(f64, binary_solvers::MTM),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //exact solvers are their own bound
+            let solution = fixtures::sample_binary::<f64>(&[295]).solution(binary_solvers::MTM);
+            assert_eq!(solution.status, Status::Optimal);
+            assert_eq!(solution.solver, "MTM");
+            assert_eq!(solution.bound, Some(solution.value()));
+            assert_eq!(solution.gap, Some(0.0));
+
+            //but do not claim optimality when stopped before finishing
+            let optimum = solution.value();
+            let control = SolveControl::new(None, Some(1), None);
+            let solution = fixtures::sample_binary::<f64>(&[295])
+                .solution_with(binary_solvers::MTM, &control);
+            assert_eq!(solution.status, Status::Feasible);
+            assert!(solution.value() <= optimum);
+
+            //which across several knapsacks is only once the search is finished
+            let solution =
+                fixtures::sample_binary::<f64>(&[100, 150]).solution(binary_solvers::MTM);
+            assert_eq!(solution.status, Status::Optimal);
+            let optimum = solution.value();
+
+            let solution = fixtures::sample_binary::<f64>(&[100, 150])
+                .solution_with(binary_solvers::MTM, &control);
+            assert_eq!(solution.status, Status::Feasible);
+            assert!(solution.value() <= optimum);
+        }
+    }
+}
+
+selective_tests! {
+    fn solution_minknap_test(binary_solvers::Minknap, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Minknap),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let optimum = fixtures::sample_binary::<f64>(&[295])
+                .using(binary_solvers::MTM)
+                .value();
+
+            let solution = fixtures::sample_binary::<f64>(&[295]).solution(binary_solvers::Minknap);
+            assert_eq!(solution.status, Status::Optimal);
+            assert_eq!(solution.value(), optimum);
+            assert_eq!(solution.knapsacks[0].weights()[0], solution[0].weights()[0]);
+
+            let solution = fixtures::sample_binary::<f64>(&[295]).solution_with(
+                binary_solvers::Minknap,
+                &SolveControl::new(None, Some(1), None),
+            );
+            assert_eq!(solution.status, Status::Feasible);
+            assert!(solution.value() <= optimum);
+        }
+    }
+}
+
+selective_tests! {
+    fn solution_branch_and_bound_test(binary_solvers::BranchAndBound, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::BranchAndBound),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //exact solvers stopped before finishing do not claim optimality
+            let solution = fixtures::sample_binary::<f64>(&[295]).solution_with(
+                binary_solvers::BranchAndBound,
+                &SolveControl::new(None, Some(1), None),
+            );
+            assert_eq!(solution.status, Status::Feasible);
+            assert_eq!(solution.bound, None);
+        }
+    }
+}
+
+selective_tests! {
+    fn solution_generalized_greedy_test(bounded_solvers::GeneralizedGreedy, <f64 as PartialOrd>::ge, 0.5) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::GeneralizedGreedy),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //heuristics have no bound until one is provided
+            let problem = || fixtures::sample::<f64>([1; 10], &[295]);
+            let solution = problem().solution(bounded_solvers::GeneralizedGreedy);
+            assert_eq!(solution.status, Status::Feasible);
+            assert_eq!(solution.bound, None);
+            assert_eq!(solution.gap, None);
+
+            let bound = bounds::linear(&problem()).unwrap();
+            let solution = solution.with_bound(bound);
+            assert_eq!(solution.bound, Some(bound));
+            assert!(solution.gap.is_some_and(|x| (0.0..1.0).contains(&x)));
+            assert_eq!(solution.into_knapsacks().len(), 1);
+        }
+    }
+}
+
+//a search cut short by its time limit says so
+#[cfg(feature = "metaheuristics")]
+#[test]
+fn solution_time_limit_test() {
+    let solution = fixtures::sample::<f64>([1; 10], &[295]).solution(
+        bounded_solvers::SimulatedAnnealing::new(
+            usize::MAX,
+            Some(std::time::Duration::from_millis(10)),
+            0,
+        ),
+    );
+
+    assert_eq!(solution.status, Status::TimeLimit);
+    assert!(solution.time >= std::time::Duration::from_millis(10));
+}
+
+//the pareto list reports the best knapsack of a capacity as optimal
+#[test]
+fn solution_nemhauser_ullmann_test() {
+    let optimum = fixtures::sample_binary::<f64>(&[295])
+        .using(binary_solvers::MTM)
+        .value();
+
+    let pareto_list =
+        fixtures::sample::<f64>([1; 10], &[295]).using(bounded_solvers::NemhauserUllmann);
+    let solution = pareto_list.solution([295.0]);
+    assert_eq!(solution.status, Status::Optimal);
+    assert_eq!(solution.solver, "Nemhauser-Ullmann");
+    assert_eq!(solution.value(), optimum);
+}
+
+selective_tests! {
+    fn solution_dynamic_test(bounded_solvers::Dynamic::DEFAULT, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+            default_multi_constraint!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let problem = || fixtures::small::<u32>([1; 3], &[10, 10]);
+
+            //the default solver fills every knapsack at once
+            let solution = problem().solution(bounded_solvers::Dynamic::DEFAULT);
+            assert_eq!(solution.status, Status::Optimal);
+            assert_eq!(solution.value(), 12.0);
+
+            //the sparse table of both knapsacks fits where the dense memo does not
+            let solver = bounded_solvers::Dynamic {
+                memory_budget: 3000,
+                ..bounded_solvers::Dynamic::DEFAULT
+            };
+            let solution = problem().solution(solver);
+            assert_eq!(solution.status, Status::Optimal);
+            assert_eq!(solution.value(), 12.0);
+
+            //filling the knapsacks one at a time, because no joint table fits, is not proven
+            //optimal
+            let solver = bounded_solvers::Dynamic {
+                memory_budget: 400,
+                ..bounded_solvers::Dynamic::DEFAULT
+            };
+            assert_eq!(problem().solution(solver).status, Status::Feasible);
+
+            let solver = bounded_solvers::Dynamic {
+                mode: DynamicMode::Joint,
+                memory_budget: 3000,
+            };
+            assert_eq!(problem().solution(solver).status, Status::Optimal);
+        }
+    }
+}
+
+selective_tests! {
+    fn solution_dynamic_control_test(bounded_solvers::Dynamic::DEFAULT, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //the control is checked before each row of the tables, and the first knapsack is
+            //packed with the copies of the rows filled
+            let problem = || fixtures::small::<u32>([1; 3], &[10, 10]);
+            let solver = bounded_solvers::Dynamic {
+                mode: DynamicMode::Joint,
+                memory_budget: 3000,
+            };
+
+            let solution = problem().solution_with(
+                bounded_solvers::Dynamic {
+                    mode: DynamicMode::Sequential,
+                    ..solver
+                },
+                &SolveControl::new(None, Some(1), None),
+            );
+            assert_eq!(solution.status, Status::Feasible);
+            assert_eq!(solution.value(), 3.0);
+
+            let solution = problem().solution_with(solver, &SolveControl::new(None, Some(0), None));
+            assert_eq!(solution.status, Status::Feasible);
+            assert_eq!(solution.value(), 0.0);
+        }
+    }
+}
+
+selective_tests! {
+    fn solution_binary_dynamic_test(binary_solvers::Dynamic::DEFAULT, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let solution = fixtures::small_binary(&[10, 10]).solution(binary_solvers::Dynamic::DEFAULT);
+            assert_eq!(solution.status, Status::Optimal);
+            assert_eq!(solution.value(), 12.0);
+        }
+    }
+}
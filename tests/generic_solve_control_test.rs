@@ -0,0 +1,221 @@
+#![cfg(feature = "metaheuristics")]
+
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{binary_solvers, bounded_solvers, DynamicMode, SolveControl, Status};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+
+//stopped searches still return knapsacks that fit
+fn stopped_controls() -> [SolveControl; 2] {
+    [
+        SolveControl::new(None, None, Some(Arc::new(AtomicBool::new(true)))),
+        SolveControl::new(None, Some(1), None),
+    ]
+}
+
+selective_tests! {
+    fn solve_control_mtm_test(binary_solvers::MTM, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(f64, binary_solvers::MTM),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //no limits gives the same answer as solving without a control
+            let problem = || fixtures::sample_binary::<f64>(&[295]);
+            let optimum = problem().using(binary_solvers::MTM).value();
+            assert_eq!(
+                problem()
+                    .using_with(binary_solvers::MTM, &SolveControl::default())
+                    .value(),
+                optimum
+            );
+
+            for control in stopped_controls() {
+                let solution = problem().using_with(binary_solvers::MTM, &control);
+                assert!(solution.value() <= optimum);
+                assert!(solution.weights()[0] <= 295.0);
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn solve_control_branch_and_bound_test(binary_solvers::BranchAndBound, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::BranchAndBound),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let problem = || fixtures::sample_binary::<f64>(&[295]);
+            let optimum = problem().using(binary_solvers::MTM).value();
+            assert_eq!(
+                problem()
+                    .using_with(binary_solvers::BranchAndBound, &SolveControl::default())
+                    .value(),
+                optimum
+            );
+
+            for control in stopped_controls() {
+                let solution = problem().using_with(binary_solvers::BranchAndBound, &control);
+                assert!(solution.value() <= optimum);
+                assert!(solution.weights()[0] <= 295.0);
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn solve_control_chu_beasley_test(binary_solvers::ChuBeasley::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(f64, binary_solvers::ChuBeasley),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let problem = || fixtures::sample_binary::<f64>(&[295]);
+            let optimum = problem().using(binary_solvers::MTM).value();
+            for control in stopped_controls() {
+                let solution =
+                    problem().using_with(binary_solvers::ChuBeasley::default(), &control);
+                assert!(solution.value() <= optimum);
+                assert!(solution.weights()[0] <= 295.0);
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn solve_control_meet_in_the_middle_test(binary_solvers::MeetInTheMiddle, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::MeetInTheMiddle),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let problem = || fixtures::sample_binary::<f64>(&[295]);
+            let optimum = problem().using(binary_solvers::MTM).value();
+            for control in stopped_controls() {
+                let solution = problem().using_with(binary_solvers::MeetInTheMiddle, &control);
+                assert!(solution.value() <= optimum);
+                assert!(solution.weights()[0] <= 295.0);
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn solve_control_minknap_test(binary_solvers::Minknap, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Minknap),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let problem = || fixtures::sample_binary::<f64>(&[295]);
+            let optimum = problem().using(binary_solvers::MTM).value();
+            for control in stopped_controls() {
+                let solution = problem().using_with(binary_solvers::Minknap, &control);
+                assert!(solution.value() <= optimum);
+                assert!(solution.weights()[0] <= 295.0);
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn solve_control_tabu_search_test(
+        bounded_solvers::TabuSearch::default(),
+        <f64 as PartialOrd>::ge,
+        0.9
+    ) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::TabuSearch),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            let problem = || fixtures::sample::<f64>([1; 10], &[295]);
+            let optimum = fixtures::sample_binary::<f64>(&[295])
+                .using(binary_solvers::MTM)
+                .value();
+            for control in stopped_controls() {
+                let solution = problem().using_with(bounded_solvers::TabuSearch::default(), &control);
+                assert!(solution.value() <= optimum);
+                assert!(solution.weights()[0] <= 295.0);
+            }
+        }
+    }
+}
+
+//the deadline ends a search that would otherwise run without end
+#[test]
+fn solve_control_deadline_test() {
+    let optimum = fixtures::sample_binary::<f64>(&[295])
+        .using(binary_solvers::MTM)
+        .value();
+
+    let start = Instant::now();
+    let solution = fixtures::sample::<f64>([1; 10], &[295]).using_with(
+        bounded_solvers::SimulatedAnnealing::new(usize::MAX, None, 0),
+        &SolveControl::time_limit(Duration::from_millis(10)),
+    );
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(solution.value() <= optimum);
+    assert!(solution.weights()[0] <= 295.0);
+}
+
+//the dense, profit indexed and sparse tables each pack the rows filled before the limit
+const TABLES: [(f64, u32, usize); 3] =
+    [(10.0, 10, 1 << 20), (10.0, 1000, 2000), (10.5, 1000, 2000)];
+
+selective_tests! {
+    fn solve_control_dynamic_rows_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            for (value, capacity, memory_budget) in TABLES {
+                let solver = bounded_solvers::Dynamic {
+                    mode: DynamicMode::Auto,
+                    memory_budget: memory_budget,
+                };
+
+                let solution = fixtures::rows(value, capacity)
+                    .solution_with(solver, &SolveControl::new(None, Some(2), None));
+                assert_eq!(solution.status, Status::Feasible);
+                assert_eq!(solution.value(), value + 7.0);
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn solve_control_binary_dynamic_rows_test(binary_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            for (value, capacity, memory_budget) in TABLES {
+                let solver = binary_solvers::Dynamic {
+                    mode: DynamicMode::Auto,
+                    memory_budget: memory_budget,
+                };
+
+                let solution = fixtures::rows_binary(value, capacity)
+                    .solution_with(solver, &SolveControl::new(None, Some(2), None));
+                assert_eq!(solution.status, Status::Feasible);
+                assert_eq!(solution.value(), value + 7.0);
+
+                let solution = fixtures::rows_binary(value, capacity).solution(solver);
+                assert_eq!(solution.status, Status::Optimal);
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{
+    binary_solvers, bounded_solvers, items, items_binary, knapsacks, knapsacks_binary, unbounded,
+    unbounded_solvers,
+};
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+
+selective_tests! {
+    fn stats_dynamic_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //the dense memo has a row per copy of an item and a column per unit of capacity
+            let (knapsacks, stats) =
+                fixtures::copies().using_stats(bounded_solvers::Dynamic::default());
+            assert_eq!(knapsacks.value(), 21.0);
+            assert_eq!(stats.states, 5 * 11);
+            assert_eq!(
+                stats.phases.iter().map(|x| x.0).collect::<Vec<_>>(),
+                vec!["setup", "memo"]
+            );
+
+            assert!(stats.phases.iter().all(|x| x.1 <= stats.time));
+
+            //solutions carry the counts of their solver
+            let solution = fixtures::copies().solution(bounded_solvers::Dynamic::default());
+            assert_eq!(solution.stats.states, 5 * 11);
+            assert_eq!(solution.time, solution.stats.time);
+        }
+    }
+}
+
+selective_tests! {
+    fn stats_unbounded_dynamic_test(unbounded_solvers::Dynamic, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_unbounded!(u32, unbounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            items! {
+                items<u32, 1, unbounded>:
+                    10.0, [5], unbounded;
+                    7.0,  [3], unbounded;
+            }
+
+            knapsacks! {
+                knapsacks<u32, 1>:
+                    [10];
+            }
+
+            let (_, stats) = items
+                .insert_into(knapsacks)
+                .using_stats(unbounded_solvers::Dynamic);
+            assert_eq!(stats.states, 11);
+        }
+    }
+}
+
+selective_tests! {
+    fn stats_theoretical_greedy_test(binary_solvers::TheoreticalGreedy, <f64 as PartialOrd>::ge, 0.5) {
+        DEFAULT: {
+            default_simple_binary!(f64, binary_solvers::TheoreticalGreedy),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //the linear program has a variable per dimension and item and a constraint per item
+            items_binary! {
+                items<f64, 1>:
+                    10.0, 5.0;
+                    7.0,  3.0;
+                    4.0,  2.0;
+            }
+
+            knapsacks_binary! {
+                knapsacks<f64, 1>:
+                    10.0;
+            }
+
+            let (_, stats) = items
+                .insert_into(knapsacks)
+                .using_stats(binary_solvers::TheoreticalGreedy);
+            assert_eq!(stats.variables, 4);
+            assert_eq!(stats.constraints, 3);
+            assert_eq!(stats.rejected, 0);
+        }
+    }
+}
+
+selective_tests! {
+    fn stats_generalized_greedy_test(bounded_solvers::GeneralizedGreedy, <f64 as PartialOrd>::ge, 0.5) {
+        DEFAULT: {
+            default_simple!(f64, bounded_solvers::GeneralizedGreedy),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //copies too heavy for what remains of every knapsack are rejected, which includes
+            //the second copy of 7.0 once 10.0 and 7.0 are packed
+            items! {
+                items<f64, 1>:
+                    10.0, [5.0],  1.0;
+                    7.0,  [3.0],  2.0;
+                    50.0, [20.0], 2.0;
+            }
+
+            knapsacks! {
+                knapsacks<f64, 1>:
+                    [10.0];
+            }
+
+            let (_, stats) = items
+                .insert_into(knapsacks)
+                .using_stats(bounded_solvers::GeneralizedGreedy);
+            assert_eq!(stats.rejected, 3);
+            assert_eq!(stats.states, 0);
+        }
+    }
+}
+
+selective_tests! {
+    fn stats_mtm_test(binary_solvers::MTM, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(f64, binary_solvers::MTM),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //solvers that keep no counts report the time taken
+            items_binary! {
+                items<f64, 1>:
+                    10.0, 5.0;
+                    7.0,  3.0;
+            }
+
+            knapsacks_binary! {
+                knapsacks<f64, 1>:
+                    10.0;
+            }
+
+            let (knapsacks, stats) = items
+                .insert_into(knapsacks)
+                .using_stats(binary_solvers::MTM);
+            assert_eq!(knapsacks.value(), 17.0);
+            assert_eq!(stats.phases.len(), 1);
+            assert_eq!(stats.phases[0].0, "solve");
+        }
+    }
+}
@@ -0,0 +1,178 @@
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{
+    bounded_solvers, items_unbounded, knapsacks, unbounded, unbounded_solvers, Knapsack, KpError,
+    UnboundedItem,
+};
+#[allow(unused_imports)]
+use rand::Rng;
+
+#[macro_use]
+mod generic_data;
+
+selective_tests! {
+    fn unbounded_dynamic_test(unbounded_solvers::Dynamic, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_unbounded!(u32, unbounded_solvers::Dynamic),
+            default_unbounded_multi_constraint!(u32, unbounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //compare against the bounded solver with enough copies of every item
+            for _ in 0..100 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::unbounded_random_test_from_u32::<_, _, _, _, 1>
+                (
+                    unbounded_solvers::Dynamic,
+                    bounded_solvers::Dynamic::default(),
+                    10,
+                    1,
+                    0.0,
+                    100.0,
+                    [1; 1],
+                    [50; 1],
+                    [100; 1],
+                    [200; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+
+            //test 2 dimensions
+            {
+            let (solution, optimal_solution) =
+            generic_data::random_test::unbounded_random_test_from_u32::<_, _, _, _, 2>
+            (
+                unbounded_solvers::Dynamic,
+                bounded_solvers::Dynamic::default(),
+                5,
+                1,
+                0.0,
+                100.0,
+                [1; 2],
+                [20; 2],
+                [20; 2],
+                [40; 2],
+            );
+
+            assert_eq!(solution.value(), optimal_solution.value());
+            }
+        }
+    }
+}
+
+selective_tests! {
+    fn unbounded_mtu2_test(unbounded_solvers::MTU2, <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_unbounded!(u32, unbounded_solvers::MTU2),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            assert_eq!(
+                generic_data::default_unbounded::random_sample_1::<f64, _>(unbounded_solvers::MTU2)
+                    .value(),
+                22.0
+            );
+
+            //an item with value but no weight leaves no best solution, as with Dynamic
+            for value in [1.0, 0.0] {
+                let mut problem_items = items_unbounded!(f64, 1);
+                problem_items.add(UnboundedItem::<f64, 1>::new(10.0, [5.0], unbounded));
+                problem_items.add(UnboundedItem::<f64, 1>::new(value, [0.0], unbounded));
+                let mut problem_knapsacks = knapsacks!(f64, 1);
+                problem_knapsacks.add(Knapsack::<f64, 1>::new([10.0]));
+                let solution = problem_items
+                    .insert_into(problem_knapsacks)
+                    .try_using(unbounded_solvers::MTU2);
+
+                if value > 0.0 {
+                    assert_eq!(solution.err(), Some(KpError::UnboundedItem(1)));
+                } else {
+                    assert_eq!(solution.unwrap().value(), 20.0);
+                }
+            }
+
+            //a core of the most efficient items that all dominate each other leaves a single
+            //item, while copies of the less efficient items outside it are worth more
+            let mut rng = rand::thread_rng();
+            for _ in 0..100 {
+                let base = rng.gen_range(2..10);
+                let mut items: Vec<(f64, u32)> = Vec::new();
+                for k in 1..=100 {
+                    items.push(((11 * base * k) as f64, base * k));
+                }
+
+                for _ in 0..100 {
+                    let weight = rng.gen_range(1..50);
+                    items.push((rng.gen_range(1..11 * weight) as f64, weight));
+                }
+
+                let capacity = rng.gen_range(10..1000);
+                let problem = || {
+                    let mut problem_items = items_unbounded!(u32, 1);
+                    for (value, weight) in items.iter() {
+                        problem_items.add(UnboundedItem::<u32, 1>::new(
+                            *value,
+                            [*weight],
+                            unbounded,
+                        ));
+                    }
+
+                    let mut problem_knapsacks = knapsacks!(u32, 1);
+                    problem_knapsacks.add(Knapsack::<u32, 1>::new([capacity]));
+                    problem_items.insert_into(problem_knapsacks)
+                };
+
+                assert_eq!(
+                    problem().using(unbounded_solvers::MTU2).value(),
+                    problem().using(unbounded_solvers::Dynamic).value()
+                );
+            }
+
+            //lots of small tests
+            for _ in 0..100 {
+                {
+                let (solution, optimal_solution) =
+                generic_data::random_test::unbounded_random_test_from_u32::<u32, u32, _, _, 1>
+                (
+                    unbounded_solvers::MTU2,
+                    unbounded_solvers::Dynamic,
+                    50,
+                    1,
+                    0.0,
+                    100.0,
+                    [1; 1],
+                    [100; 1],
+                    [1000; 1],
+                    [2000; 1],
+                );
+
+                assert_eq!(solution.value(), optimal_solution.value());
+                }
+            }
+
+            //large number of item types, f64
+            {
+            let (solution, optimal_solution) =
+            generic_data::random_test::unbounded_random_test_from_u32::<f64, u32, _, _, 1>
+            (
+                unbounded_solvers::MTU2,
+                unbounded_solvers::Dynamic,
+                5000,
+                1,
+                0.0,
+                1000.0,
+                [100; 1],
+                [1000; 1],
+                [5000; 1],
+                [10000; 1],
+            );
+
+            assert_eq!(solution.value(), optimal_solution.value());
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use kpsolver::{items, items_binary, knapsacks, knapsacks_binary, unbounded, Issue};
+
+#[test]
+fn validation_test() {
+    //every issue is reported, not just the first
+    items! {
+        items<f64, 2>:
+            10.0,     [5.0, 1.0],  1.0;
+            f64::NAN, [5.0, 1.0],  1.0;
+            8.0,      [-1.0, 1.0], 1.0;
+            6.0,      [2.0, 1.0],  -1.0;
+            0.0,      [0.0, 0.0],  1.0;
+            9.0,      [20.0, 1.0], 1.0;
+    }
+
+    knapsacks! {
+        knapsacks<f64, 2>:
+            [10.0, 0.0];
+            [15.0, 5.0];
+    }
+
+    assert_eq!(
+        items.insert_into(knapsacks).validate(),
+        vec![
+            Issue::ZeroCapacity {
+                knapsack: 0,
+                dimension: 1
+            },
+            Issue::NonFiniteItem(1),
+            Issue::NegativeWeight {
+                item: 2,
+                dimension: 0
+            },
+            Issue::NegativeQuantity(3),
+            Issue::EmptyItem(4),
+            Issue::FitsNowhere(5),
+        ]
+    );
+
+    //the weight of every copy must fit in the type of the problem
+    items! {
+        items<u32, 1>:
+            10.0, [100000], 100000;
+            10.0, [100], 2;
+    }
+
+    knapsacks! {
+        knapsacks<u32, 1>:
+            [200];
+    }
+
+    let issues = items.insert_into(knapsacks).validate();
+    assert!(issues.contains(&Issue::Overflow {
+        item: 0,
+        dimension: 0
+    }));
+
+    assert!(!issues
+        .iter()
+        .any(|x| matches!(x, Issue::Overflow { item: 1, .. })));
+
+    //binary and unbounded problems are checked the same way
+    items_binary! {
+        items<f64, 1>:
+            10.0, 5.0;
+            10.0, f64::INFINITY;
+    }
+
+    knapsacks_binary! {
+        knapsacks<f64, 1>:
+            10.0;
+    }
+
+    assert_eq!(
+        items.insert_into(knapsacks).validate(),
+        vec![Issue::NonFiniteItem(1)]
+    );
+
+    items! {
+        items<u32, 1, unbounded>:
+            1.0, [1];
+            6.0, [8];
+            2.0, [0];
+    }
+
+    knapsacks! {
+        knapsacks<u32, 1>:
+            [7];
+    }
+
+    assert_eq!(
+        items.insert_into(knapsacks).validate(),
+        vec![Issue::FitsNowhere(1), Issue::UnboundedItem(2)]
+    );
+}
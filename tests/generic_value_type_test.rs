@@ -0,0 +1,209 @@
+#[allow(unused_imports)]
+use generic_data::Problems;
+#[allow(unused_imports)]
+use kpsolver::{
+    binary_solvers, bounded_solvers, BinaryKnapsack, DynamicMode, Item, Knapsack, KpError,
+    ProblemItems, ProblemKnapsacks,
+};
+
+#[macro_use]
+mod generic_data;
+
+use generic_data::fixtures;
+use generic_data::fixtures::BIG;
+
+selective_tests! {
+    fn value_type_dynamic_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+            default_multi_constraint!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //values that are equal as f64 are kept apart
+            assert_eq!(fixtures::exact_u64().items.len(), 3);
+            assert_eq!((BIG + 1) as f64, BIG as f64);
+
+            //and summed exactly by the knapsacks
+            let solution = fixtures::exact_u64().using(bounded_solvers::Dynamic::default());
+            assert_eq!(solution.value(), 2 * BIG + 2);
+            assert_eq!(
+                solution.iter().map(|x| x.value()).sum::<u64>(),
+                solution.value()
+            );
+
+            assert_eq!(solution.weights(), [5]);
+            assert!(solution
+                .iter()
+                .any(|x| x.get_item((BIG + 1, [2])).is_some()));
+
+            //the best knapsack for one copy takes the larger of the two values
+            let mut items = ProblemItems::<u32, 1, u32, (), u64>::new();
+            items.add(Item::new(BIG, [2], 1));
+            items.add(Item::new(BIG + 1, [2], 1));
+            let mut knapsacks = ProblemKnapsacks::<u32, 1, (), u64>::new();
+            knapsacks.add(Knapsack::new([3]));
+            let solution = items
+                .insert_into(knapsacks)
+                .using(bounded_solvers::Dynamic::new(DynamicMode::Joint));
+
+            assert_eq!(solution.value(), BIG + 1);
+            assert_eq!(solution[0][0].value, BIG + 1);
+        }
+    }
+}
+
+selective_tests! {
+    fn value_type_dynamic_memo_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //the dense, profit indexed and sparse memos give the same answer. with weights scaled
+            //by 1000 the dense memo takes 10 * 8001 * 8 bytes and the profit indexed one
+            //10 * 34 * 8, while with values scaled by 1000 only the sparse memo fits
+            let memos = [(usize::MAX, 1, 1), (4096, 1000, 1), (4096, 10, 1000)];
+            for (memory_budget, weight, value) in memos {
+                let solver = bounded_solvers::Dynamic {
+                    memory_budget,
+                    ..Default::default()
+                };
+
+                let solution = fixtures::scaled_i64(weight, value).using(solver);
+                assert_eq!(solution.value(), 19 * value);
+            }
+
+            //none of them fit in no memory at all
+            let solver = bounded_solvers::Dynamic {
+                memory_budget: 0,
+                ..Default::default()
+            };
+            assert_eq!(
+                fixtures::scaled_i64(10, 1000).try_using(solver).err(),
+                Some(KpError::MemoryBudget)
+            );
+        }
+    }
+}
+
+selective_tests! {
+    fn value_type_binary_dynamic_test(binary_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple_binary!(u32, binary_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //the binary solver keeps integer values exact as well, and through the blanket impls
+            //so does the bounded solver given a binary problem
+            let solution = fixtures::exact_i64_binary().using(binary_solvers::Dynamic::default());
+            assert_eq!(solution.value(), 2 * BIG as i64 + 1);
+
+            let solution = fixtures::exact_i64_binary().using(bounded_solvers::Dynamic::default());
+            assert_eq!(solution.value(), 2 * BIG as i64 + 1);
+        }
+    }
+}
+
+selective_tests! {
+    fn value_type_dynamic_error_test(bounded_solvers::Dynamic::default(), <f64 as PartialEq>::eq, 1.0) {
+        DEFAULT: {
+            default_simple!(u32, bounded_solvers::Dynamic),
+        }
+        IGNORE: {}
+        CUSTOM: {
+            //sums of the values the solver may place are checked for overflow
+            let mut items = ProblemItems::<u32, 1, u32, (), i64>::new();
+            items.add(Item::new(i64::MAX / 4, [1], 5));
+            let mut knapsacks = ProblemKnapsacks::<u32, 1, (), i64>::new();
+            knapsacks.add(Knapsack::new([10]));
+            assert_eq!(
+                items
+                    .insert_into(knapsacks)
+                    .try_using(bounded_solvers::Dynamic::default())
+                    .err(),
+                Some(KpError::Overflow)
+            );
+
+            //f64 is still the default and is checked for non-finite values
+            let mut items = ProblemItems::<u32, 1>::new();
+            items.add(Item::new(f64::NAN, [1], 1));
+            let mut knapsacks = ProblemKnapsacks::<u32, 1>::new();
+            knapsacks.add(Knapsack::new([1]));
+            assert_eq!(
+                items
+                    .insert_into(knapsacks)
+                    .try_using(bounded_solvers::Dynamic::default())
+                    .err(),
+                Some(KpError::NonFiniteItem(0))
+            );
+        }
+    }
+}
+
+//copies whose value would overflow are not added by try_add, while add saturates
+#[test]
+fn value_type_knapsack_test() {
+    //taking items back out leaves no rounding behind
+    let mut knapsack = Knapsack::<u32, 1, (), i64>::new([10]);
+    knapsack.add(Item::new(-3, [1], 2));
+    knapsack.add(Item::new(i64::MAX / 4, [2], 1));
+    knapsack.take(Item::new(i64::MAX / 4, [2], 1));
+    assert_eq!(knapsack.value(), -6);
+
+    assert_eq!(
+        knapsack.try_add(Item::new(i64::MAX / 4, [1], 5)),
+        Err(KpError::Overflow)
+    );
+    assert_eq!((knapsack.value(), knapsack.len()), (-6, 2));
+
+    let mut item = Item::new(i64::MAX / 4, [1], 5);
+    assert_eq!(knapsack.try_add_mut(&mut item, 5), Err(KpError::Overflow));
+    assert_eq!(
+        (knapsack.value(), knapsack.len(), item.quantity),
+        (-6, 2, 5)
+    );
+    assert_eq!(knapsack.try_add_mut(&mut item, 1), Ok(true));
+    assert_eq!(knapsack.value(), i64::MAX / 4 - 6);
+
+    assert!(knapsack.add(Item::new(i64::MAX / 4, [1], 4)));
+    assert_eq!(knapsack.value(), i64::MAX);
+    assert!(knapsack.add(Item::new(-1, [1], 1)));
+    assert_eq!(knapsack.value(), i64::MAX - 1);
+}
+
+//binary knapsacks saturate their value the same way
+#[test]
+fn value_type_binary_knapsack_test() {
+    let mut binary = BinaryKnapsack::<u32, 1, (), u32>::new([10]);
+    assert!(binary.add(Item::binary(u32::MAX - 1, [1])));
+    assert_eq!(binary.try_add(Item::binary(2, [1])), Err(KpError::Overflow));
+    assert_eq!((binary.value(), binary.len()), (u32::MAX - 1, 1));
+    assert!(binary.add(Item::binary(2, [1])));
+    assert_eq!((binary.value(), binary.len()), (u32::MAX, 2));
+}
+
+//fractions of a copy of an integer value are not added
+#[test]
+fn value_type_fraction_test() {
+    let mut knapsack = Knapsack::<f64, 1, (), i64>::new([10.0]);
+    assert!(!knapsack.add(Item::new(3, [1.0], 0.5)));
+    assert_eq!(knapsack.try_add(Item::new(3, [1.0], 0.5)), Ok(false));
+    assert!(knapsack.add(Item::new(3, [1.0], 2.0)));
+    assert_eq!(knapsack.value(), 6);
+}
+
+//the total of the knapsacks saturates, or is checked by try_value
+#[test]
+fn value_type_knapsacks_test() {
+    let mut knapsacks = ProblemKnapsacks::<u32, 1, (), i64>::new();
+    for _ in 0..2 {
+        let mut knapsack = Knapsack::new([10]);
+        assert!(knapsack.add(Item::new(i64::MAX / 4, [1], 3)));
+        knapsacks.add(knapsack);
+    }
+
+    assert_eq!(knapsacks[0].value(), i64::MAX / 4 * 3);
+    assert_eq!(knapsacks.value(), i64::MAX);
+    assert_eq!(knapsacks.try_value(), Err(KpError::Overflow));
+}